@@ -0,0 +1,305 @@
+use crm_core::{
+    component::{BAccessLevel, BSchemaPlan, BusinessComponent},
+    components::{BusinessAuthentication, BusinessComponents},
+};
+use std::env;
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+const USAGE: &str = "Usage: crm-cli <command> [arguments]
+
+Commands:
+  tables                      list the tables
+  describe <table>            show the columns of a table
+  export <table> <file>       write the rows of a table to a CSV file
+  import <table> <file>       add the rows of a CSV file with a header line to a table
+  apply-schema <file> [--yes] show the changes a schema file makes, --yes applies them
+  sql [statements]            run SQL, read from standard input when no statements are given
+
+The database is read from DATABASE_URL, the user from CRM_USER and CRM_PASSWORD.";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Tables,
+    Describe(String),
+    Export(String, String),
+    Import(String, String),
+    ApplySchema(String, bool),
+    Sql(Option<String>),
+}
+
+fn parse_command(arguments: &[String]) -> Option<Command> {
+    let arguments: Vec<&str> = arguments.iter().map(String::as_str).collect();
+    match arguments.as_slice() {
+        ["tables"] => Some(Command::Tables),
+        ["describe", table_name] => Some(Command::Describe(table_name.to_string())),
+        ["export", table_name, path] => {
+            Some(Command::Export(table_name.to_string(), path.to_string()))
+        }
+        ["import", table_name, path] => {
+            Some(Command::Import(table_name.to_string(), path.to_string()))
+        }
+        ["apply-schema", path] if *path != "--yes" => {
+            Some(Command::ApplySchema(path.to_string(), false))
+        }
+        ["apply-schema", path, "--yes"] => Some(Command::ApplySchema(path.to_string(), true)),
+        ["sql"] => Some(Command::Sql(None)),
+        ["sql", statements @ ..] => Some(Command::Sql(Some(statements.join(" ")))),
+        _ => None,
+    }
+}
+
+fn schema_plan_lines(schema_plan: &BSchemaPlan) -> Vec<String> {
+    let mut lines = vec![];
+    for table_in in &schema_plan.create_tables {
+        lines.push(format!("Create table \"{}\"", table_in.table_name));
+    }
+    for table_alteration in &schema_plan.alter_tables {
+        lines.push(format!("Alter table \"{}\"", table_alteration.table_name));
+        for table_change_event in &table_alteration.table_change_events {
+            lines.push(format!("  {}", table_change_event));
+        }
+    }
+    lines
+}
+
+/* the same login as the app, so roles and access rules apply to scripts too */
+async fn log_in() -> Result<BusinessComponents, String> {
+    let authentication = Arc::new(BusinessAuthentication::connect().await);
+    authentication.initialize_component().await;
+    let user_name = env::var("CRM_USER").map_err(|_| String::from("CRM_USER must be set"))?;
+    let password =
+        env::var("CRM_PASSWORD").map_err(|_| String::from("CRM_PASSWORD must be set"))?;
+    authentication.log_in(user_name, password).await?;
+    let business_components = BusinessComponents::new(authentication).await?;
+    business_components.tables.initialize_component().await;
+    Ok(business_components)
+}
+
+async fn run(command: Command) -> Result<(), String> {
+    let business_components = log_in().await?;
+    match command {
+        Command::Tables => {
+            let session = business_components.session.clone();
+            let tables_general_info = business_components.tables.tables_general_info.lock().await;
+            for table_general_info in tables_general_info.iter().filter(|table_general_info| {
+                session.table_access_level(&table_general_info.table_name) != BAccessLevel::Hidden
+            }) {
+                println!("{}", table_general_info.table_name);
+            }
+        }
+        Command::Describe(table_name) => {
+            let session = business_components.session.clone();
+            // hidden tables are reported like missing ones
+            let is_table = session.table_access_level(&table_name) != BAccessLevel::Hidden
+                && business_components
+                    .tables
+                    .tables_general_info
+                    .lock()
+                    .await
+                    .iter()
+                    .any(|table_general_info| table_general_info.table_name == table_name);
+            if !is_table {
+                return Err(format!("There is no table \"{}\"", table_name));
+            }
+            let table_info = business_components.tables.table_info.clone();
+            table_info.set_table_info(table_name.clone()).await;
+            for column in table_info
+                .columns_info
+                .lock()
+                .await
+                .iter()
+                .filter(|column| {
+                    session.column_access_level(&table_name, &column.name) != BAccessLevel::Hidden
+                })
+            {
+                let constraints: Vec<String> = column
+                    .constraints
+                    .iter()
+                    .map(|constraint| constraint.to_string())
+                    .collect();
+                println!(
+                    "{}\t{}\t{}",
+                    column.name,
+                    column.datatype,
+                    constraints.join(" ")
+                );
+            }
+        }
+        Command::Export(table_name, path) => {
+            business_components
+                .data_transfer
+                .export_csv(table_name.clone(), path.clone())
+                .await?;
+            println!("Exported \"{}\" to \"{}\"", table_name, path);
+        }
+        Command::Import(table_name, path) => {
+            let rows_count = business_components
+                .data_transfer
+                .import_csv(table_name.clone(), path)
+                .await?;
+            println!("Imported {} rows into \"{}\"", rows_count, table_name);
+        }
+        Command::ApplySchema(path, apply) => {
+            let schema_file = business_components.schema_file.clone();
+            schema_file.load_schema_file(path).await?;
+            let Some(schema_plan) = schema_file.schema_plan.lock().await.clone() else {
+                return Ok(());
+            };
+            if schema_plan.is_empty() {
+                println!("The database already matches the schema file");
+                return Ok(());
+            }
+            for line in schema_plan_lines(&schema_plan) {
+                println!("{}", line);
+            }
+            // without --yes the plan is only printed
+            if !apply {
+                println!("Nothing was changed, run again with --yes to apply the schema file");
+                return Ok(());
+            }
+            schema_file.apply_schema_plan().await?;
+        }
+        Command::Sql(statements) => {
+            let query = match statements {
+                Some(statements) => statements,
+                None => {
+                    let mut query = String::new();
+                    io::stdin()
+                        .read_to_string(&mut query)
+                        .map_err(|error| format!("Could not read the statements: {}", error))?;
+                    query
+                }
+            };
+            let (column_names, rows) = business_components.data_transfer.run_sql(query).await?;
+            // tab separated, NULL is printed as nothing
+            if !column_names.is_empty() {
+                println!("{}", column_names.join("\t"));
+            }
+            for row in rows {
+                let values: Vec<String> = row.into_iter().map(Option::unwrap_or_default).collect();
+                println!("{}", values.join("\t"));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let arguments: Vec<String> = env::args().skip(1).collect();
+    let Some(command) = parse_command(&arguments) else {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    };
+    match run(command).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crm_core::component::{BDataType, BTableAlteration, BTableChangeEvents, BTableIn};
+
+    fn arguments(arguments: &[&str]) -> Vec<String> {
+        arguments
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            parse_command(&arguments(&["tables"])),
+            Some(Command::Tables)
+        );
+        assert_eq!(
+            parse_command(&arguments(&["describe", "users"])),
+            Some(Command::Describe(String::from("users")))
+        );
+        assert_eq!(
+            parse_command(&arguments(&["export", "users", "users.csv"])),
+            Some(Command::Export(
+                String::from("users"),
+                String::from("users.csv")
+            ))
+        );
+        assert_eq!(
+            parse_command(&arguments(&["import", "users", "users.csv"])),
+            Some(Command::Import(
+                String::from("users"),
+                String::from("users.csv")
+            ))
+        );
+        let path = env::temp_dir().join("schema.toml");
+        let path = path.to_str().unwrap();
+        assert_eq!(
+            parse_command(&arguments(&["apply-schema", path])),
+            Some(Command::ApplySchema(path.to_string(), false))
+        );
+        assert_eq!(
+            parse_command(&arguments(&["apply-schema", path, "--yes"])),
+            Some(Command::ApplySchema(path.to_string(), true))
+        );
+        assert_eq!(
+            parse_command(&arguments(&["sql"])),
+            Some(Command::Sql(None))
+        );
+        // the statements may be split over several arguments by the shell
+        assert_eq!(
+            parse_command(&arguments(&["sql", "SELECT", "1"])),
+            Some(Command::Sql(Some(String::from("SELECT 1"))))
+        );
+    }
+
+    #[test]
+    fn test_parse_command_rejects_bad_arguments() {
+        assert_eq!(parse_command(&arguments(&[])), None);
+        assert_eq!(parse_command(&arguments(&["describe"])), None);
+        assert_eq!(parse_command(&arguments(&["tables", "users"])), None);
+        assert_eq!(parse_command(&arguments(&["export", "users"])), None);
+        assert_eq!(parse_command(&arguments(&["drop", "users"])), None);
+        assert_eq!(parse_command(&arguments(&["apply-schema", "--yes"])), None);
+        assert_eq!(
+            parse_command(&arguments(&["apply-schema", "--yes", "schema.toml"])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_schema_plan_lines() {
+        let schema_plan = BSchemaPlan {
+            create_tables: vec![BTableIn {
+                table_name: String::from("orders"),
+                columns: vec![],
+            }],
+            alter_tables: vec![BTableAlteration {
+                table_name: String::from("users"),
+                table_change_events: vec![BTableChangeEvents::AddColumn(
+                    String::from("email"),
+                    BDataType::TEXT,
+                )],
+                primary_key_column_names: vec![String::from("id")],
+            }],
+            untouched_tables: vec![String::from("notes")],
+        };
+        assert_eq!(
+            schema_plan_lines(&schema_plan),
+            vec![
+                String::from("Create table \"orders\""),
+                String::from("Alter table \"users\""),
+                format!(
+                    "  {}",
+                    BTableChangeEvents::AddColumn(String::from("email"), BDataType::TEXT)
+                ),
+            ]
+        );
+    }
+}
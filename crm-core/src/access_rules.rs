@@ -0,0 +1,88 @@
+use crate::component::{
+    repository_module::BRepository, BAccessRule, BPermission, BSession, BTableGeneral,
+    BusinessComponent,
+};
+use crate::components::BusinessConsole;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+#[derive(Debug, Clone)]
+pub struct AccessRules {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    session: Arc<BSession>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    pub access_rules: Arc<AsyncMutex<Vec<BAccessRule>>>,
+}
+
+impl BusinessComponent for AccessRules {
+    async fn initialize_component(&self) {
+        self.repository.create_access_rules_table().await.unwrap();
+        self.set_access_rules().await;
+    }
+}
+
+impl AccessRules {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            tables_general_info,
+            access_rules: Arc::new(AsyncMutex::new(vec![])),
+        }
+    }
+
+    // every role loads the rules, only managing them is restricted
+    async fn set_access_rules(&self) {
+        let access_rules: Vec<BAccessRule> = self
+            .repository
+            .get_access_rules()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(BAccessRule::to_access_rule)
+            .collect();
+        self.session.set_access_rules(access_rules.clone());
+        *self.access_rules.lock().await = access_rules;
+    }
+
+    pub async fn save_access_rule(&self, access_rule: BAccessRule) {
+        if let Err(message) = self.session.authorize(BPermission::ManageUsers) {
+            self.write_to_console(message).await;
+            return;
+        }
+        self.repository
+            .upsert_access_rule(&access_rule)
+            .await
+            .unwrap();
+        self.set_access_rules().await;
+    }
+
+    pub async fn delete_access_rule(&self, access_rule_id: i32) {
+        if let Err(message) = self.session.authorize(BPermission::ManageUsers) {
+            self.write_to_console(message).await;
+            return;
+        }
+        self.repository
+            .delete_access_rule(access_rule_id)
+            .await
+            .unwrap();
+        self.set_access_rules().await;
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
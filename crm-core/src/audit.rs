@@ -0,0 +1,552 @@
+use crate::component::{
+    repository_module::BRepository, BAccessLevel, BAuditEntry, BAuditFilter, BAuditOperation,
+    BCondition, BDataType, BRowColumnValue, BRowInsertData, BSession, BTableDataChangeEvents,
+    BTableGeneral, BusinessComponent,
+};
+use crate::components::BusinessConsole;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+const AUDIT_ENTRIES_LIMIT: i64 = 200;
+
+#[derive(Debug, Clone)]
+pub struct Audit {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    session: Arc<BSession>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    pub audit_filter: Arc<AsyncMutex<BAuditFilter>>,
+    pub audit_entries: Arc<AsyncMutex<Vec<BAuditEntry>>>,
+}
+
+impl BusinessComponent for Audit {
+    async fn initialize_component(&self) {
+        self.repository.create_audit_log_table().await.unwrap();
+        self.set_audit_entries().await;
+    }
+}
+
+impl Audit {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            tables_general_info,
+            audit_filter: Arc::new(AsyncMutex::new(BAuditFilter::default())),
+            audit_entries: Arc::new(AsyncMutex::new(vec![])),
+        }
+    }
+
+    pub async fn set_audit_filter(&self, audit_filter: BAuditFilter) {
+        *self.audit_filter.lock().await = audit_filter;
+        self.set_audit_entries().await;
+    }
+
+    // changes of hidden tables are left out, values of hidden columns are removed
+    pub async fn set_audit_entries(&self) {
+        let audit_filter = { self.audit_filter.lock().await.clone() };
+        let audit_entries = self
+            .repository
+            .get_audit_entries(&audit_filter, AUDIT_ENTRIES_LIMIT)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(BAuditEntry::to_audit_entry)
+            .filter(|audit_entry| {
+                self.session.table_access_level(&audit_entry.table_name) != BAccessLevel::Hidden
+            })
+            .map(|audit_entry| self.without_hidden_columns(audit_entry))
+            .collect();
+        *self.audit_entries.lock().await = audit_entries;
+    }
+
+    fn without_hidden_columns(&self, mut audit_entry: BAuditEntry) -> BAuditEntry {
+        let is_visible: Vec<bool> = audit_entry
+            .column_names
+            .iter()
+            .map(|column_name| {
+                audit_entry.primary_key_column_names.contains(column_name)
+                    || self
+                        .session
+                        .column_access_level(&audit_entry.table_name, column_name)
+                        != BAccessLevel::Hidden
+            })
+            .collect();
+        audit_entry.column_names = visible_values(audit_entry.column_names, &is_visible);
+        audit_entry.old_values = audit_entry
+            .old_values
+            .map(|old_values| visible_values(old_values, &is_visible));
+        audit_entry.new_values = audit_entry
+            .new_values
+            .map(|new_values| visible_values(new_values, &is_visible));
+        audit_entry
+    }
+
+    /* applies the opposite change through update_table_data, so the revert is audited too;
+     * the shown entry may lack hidden columns, the stored one is reverted */
+    pub async fn revert_audit_entry(&self, audit_entry_id: i32) {
+        let is_shown = self
+            .audit_entries
+            .lock()
+            .await
+            .iter()
+            .any(|audit_entry| audit_entry.id == audit_entry_id);
+        if !is_shown {
+            return;
+        }
+        let Some(audit_entry) = self
+            .repository
+            .get_audit_entry(audit_entry_id)
+            .await
+            .unwrap()
+            .map(BAuditEntry::to_audit_entry)
+        else {
+            return;
+        };
+        let table_general_info = {
+            self.tables_general_info
+                .lock()
+                .await
+                .iter()
+                .find(|table_general_info| table_general_info.table_name == audit_entry.table_name)
+                .cloned()
+        };
+        let Some(table_general_info) = table_general_info else {
+            self.write_to_console(format!(
+                "Cannot revert change {}, table \"{}\" no longer exists",
+                audit_entry.id, audit_entry.table_name
+            ))
+            .await;
+            return;
+        };
+
+        let always_generated_column_names = self
+            .repository
+            .get_always_generated_column_names(&audit_entry.table_name)
+            .await
+            .unwrap();
+        let revert_events = vec![revert_event(
+            &audit_entry,
+            &table_general_info,
+            &always_generated_column_names,
+        )];
+        if let Err(message) = self
+            .session
+            .authorize_table_data_change_events(&audit_entry.table_name, &revert_events)
+        {
+            self.write_to_console(message).await;
+            return;
+        }
+        if let Err(error) = self
+            .repository
+            .update_table_data(&audit_entry.table_name, &revert_events)
+            .await
+        {
+            self.write_to_console(format!(
+                "Failed to revert change {}: {}",
+                audit_entry.id, error
+            ))
+            .await;
+        }
+        self.set_audit_entries().await;
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+fn visible_values<T>(values: Vec<T>, is_visible: &Vec<bool>) -> Vec<T> {
+    values
+        .into_iter()
+        .zip(is_visible)
+        .filter(|(_, is_visible)| **is_visible)
+        .map(|(value, _)| value)
+        .collect()
+}
+
+// generated columns still identify a row but are left to postgres when values are written
+fn revert_event(
+    audit_entry: &BAuditEntry,
+    table_general_info: &BTableGeneral,
+    always_generated_column_names: &Vec<String>,
+) -> BTableDataChangeEvents {
+    // columns dropped since the change was recorded are left out
+    let data_types: HashMap<&String, &BDataType> = table_general_info
+        .column_names
+        .iter()
+        .zip(&table_general_info.data_types)
+        .collect();
+    let existing_columns = || {
+        audit_entry
+            .column_names
+            .iter()
+            .enumerate()
+            .filter_map(|(index, column_name)| {
+                data_types
+                    .get(column_name)
+                    .map(|data_type| (index, column_name.clone(), (*data_type).clone()))
+            })
+    };
+    let written_columns = || {
+        existing_columns()
+            .filter(|(_, column_name, _)| !always_generated_column_names.contains(column_name))
+    };
+    // tables without a primary key are matched on every column
+    let key_column_names = if audit_entry.primary_key_column_names.is_empty() {
+        audit_entry.column_names.clone()
+    } else {
+        audit_entry.primary_key_column_names.clone()
+    };
+    let conditions = |values: &Vec<Option<String>>| -> Vec<BCondition> {
+        existing_columns()
+            .filter(|(_, column_name, _)| key_column_names.contains(column_name))
+            .map(|(index, column_name, data_type)| BCondition {
+                column_name,
+                data_type,
+                value: values[index].clone(),
+            })
+            .collect()
+    };
+
+    match audit_entry.operation {
+        BAuditOperation::Insert => {
+            BTableDataChangeEvents::DeleteRow(conditions(audit_entry.new_values.as_ref().unwrap()))
+        }
+        BAuditOperation::Delete => {
+            let old_values = audit_entry.old_values.as_ref().unwrap();
+            // inserts store an empty value as NULL
+            let (column_names, (data_types, values)) = written_columns()
+                .map(|(index, column_name, data_type)| {
+                    (
+                        column_name,
+                        (data_type, old_values[index].clone().unwrap_or_default()),
+                    )
+                })
+                .unzip();
+            BTableDataChangeEvents::InsertRow(BRowInsertData {
+                column_names,
+                data_types,
+                values,
+            })
+        }
+        BAuditOperation::Update => {
+            let old_values = audit_entry.old_values.as_ref().unwrap();
+            let new_values = audit_entry.new_values.as_ref().unwrap();
+            BTableDataChangeEvents::ModifyRowColumnValue(BRowColumnValue {
+                conditions: conditions(new_values),
+                column_values: written_columns()
+                    .filter(|(index, _, _)| old_values[*index] != new_values[*index])
+                    .map(|(index, column_name, data_type)| {
+                        (column_name, (data_type, old_values[index].clone()))
+                    })
+                    .collect(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{BAccessRule, BRole};
+    use crate::tables::test_utils::{
+        create_repository_table_and_console, create_session, default_table_in,
+    };
+    use crate::tables::utils::set_tables_general_info;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_audit_and_revert(pool: PgPool) {
+        let table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool.clone(), &table_in).await;
+        let (column_names, data_types): (Vec<String>, Vec<BDataType>) = table_in
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.datatype.clone()))
+            .unzip();
+        let id_condition = vec![BCondition {
+            column_name: String::from("id"),
+            data_type: BDataType::INTEGER,
+            value: Some(String::from("1")),
+        }];
+        repository
+            .update_table_data(
+                &table_in.table_name,
+                &vec![
+                    BTableDataChangeEvents::InsertRow(BRowInsertData {
+                        column_names: column_names.clone(),
+                        data_types: data_types.clone(),
+                        values: vec![String::from("1"), String::from("Alice")],
+                    }),
+                    BTableDataChangeEvents::ModifyRowColumnValue(BRowColumnValue {
+                        conditions: id_condition.clone(),
+                        column_values: HashMap::from([(
+                            String::from("name"),
+                            (BDataType::TEXT, Some(String::from("Alicia"))),
+                        )]),
+                    }),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let audit = Audit::new(
+            repository.clone(),
+            console,
+            create_session(BRole::Admin),
+            tables_general_info,
+        );
+        audit.initialize_component().await;
+
+        let audit_entries = audit.audit_entries.lock().await.clone();
+        assert_eq!(audit_entries.len(), 2);
+        // newest first
+        assert_eq!(audit_entries[0].operation, BAuditOperation::Update);
+        assert_eq!(
+            audit_entries[0].primary_key_values,
+            vec![Some(String::from("1"))]
+        );
+        assert_eq!(
+            audit_entries[0].old_values,
+            Some(vec![Some(String::from("1")), Some(String::from("Alice"))])
+        );
+        assert_eq!(
+            audit_entries[0].new_values,
+            Some(vec![Some(String::from("1")), Some(String::from("Alicia"))])
+        );
+        assert_eq!(audit_entries[1].operation, BAuditOperation::Insert);
+        assert_eq!(audit_entries[1].old_values, None);
+
+        audit.revert_audit_entry(audit_entries[0].id).await;
+        let names: Vec<(String,)> = sqlx::query_as("SELECT name FROM users")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(names, vec![(String::from("Alice"),)]);
+
+        repository
+            .update_table_data(
+                &table_in.table_name,
+                &vec![BTableDataChangeEvents::DeleteRow(id_condition)],
+            )
+            .await
+            .unwrap();
+        audit
+            .set_audit_filter(BAuditFilter {
+                table_name: Some(table_in.table_name.clone()),
+                primary_key_value: Some(String::from("1")),
+                user_name: None,
+            })
+            .await;
+        let audit_entries = audit.audit_entries.lock().await.clone();
+        assert_eq!(audit_entries.len(), 4);
+        assert_eq!(audit_entries[0].operation, BAuditOperation::Delete);
+
+        audit.revert_audit_entry(audit_entries[0].id).await;
+        let names: Vec<(String,)> = sqlx::query_as("SELECT name FROM users")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(names, vec![(String::from("Alice"),)]);
+    }
+
+    #[sqlx::test]
+    async fn test_revert_keeps_nulls_and_skips_generated_columns(pool: PgPool) {
+        let (repository, console) =
+            create_repository_table_and_console(pool.clone(), &default_table_in()).await;
+        // no primary key, so reverts match the rows on every column
+        sqlx::query(
+            "CREATE TABLE notes (title TEXT, body TEXT,
+             title_length INTEGER GENERATED ALWAYS AS (length(title)) STORED)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let condition = |column_name: &str, data_type: BDataType, value: Option<&str>| BCondition {
+            column_name: column_name.to_string(),
+            data_type,
+            value: value.map(String::from),
+        };
+        repository
+            .update_table_data(
+                "notes",
+                &vec![
+                    BTableDataChangeEvents::InsertRow(BRowInsertData {
+                        column_names: vec![String::from("title"), String::from("body")],
+                        data_types: vec![BDataType::TEXT, BDataType::TEXT],
+                        values: vec![String::from("Hi"), String::new()],
+                    }),
+                    BTableDataChangeEvents::ModifyRowColumnValue(BRowColumnValue {
+                        conditions: vec![
+                            condition("title", BDataType::TEXT, Some("Hi")),
+                            condition("body", BDataType::TEXT, None),
+                        ],
+                        column_values: HashMap::from([(
+                            String::from("body"),
+                            (BDataType::TEXT, Some(String::from("Hello"))),
+                        )]),
+                    }),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let audit = Audit::new(
+            repository.clone(),
+            console,
+            create_session(BRole::Admin),
+            tables_general_info,
+        );
+        audit.initialize_component().await;
+        let audit_entries = audit.audit_entries.lock().await.clone();
+        assert_eq!(audit_entries[0].operation, BAuditOperation::Update);
+
+        // the body was NULL before the update, not an empty text
+        audit.revert_audit_entry(audit_entries[0].id).await;
+        let notes: Vec<(String, Option<String>, i32)> =
+            sqlx::query_as("SELECT title, body, title_length FROM notes")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(notes, vec![(String::from("Hi"), None, 2)]);
+
+        repository
+            .update_table_data(
+                "notes",
+                &vec![BTableDataChangeEvents::DeleteRow(vec![
+                    condition("title", BDataType::TEXT, Some("Hi")),
+                    condition("body", BDataType::TEXT, None),
+                ])],
+            )
+            .await
+            .unwrap();
+        audit.set_audit_entries().await;
+        let audit_entries = audit.audit_entries.lock().await.clone();
+        assert_eq!(audit_entries[0].operation, BAuditOperation::Delete);
+
+        // postgres computes the generated column of the restored row again
+        audit.revert_audit_entry(audit_entries[0].id).await;
+        let notes: Vec<(String, Option<String>, i32)> =
+            sqlx::query_as("SELECT title, body, title_length FROM notes")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(notes, vec![(String::from("Hi"), None, 2)]);
+    }
+
+    #[sqlx::test]
+    async fn test_audit_insert_in_other_column_order(pool: PgPool) {
+        let table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool.clone(), &table_in).await;
+        repository
+            .update_table_data(
+                &table_in.table_name,
+                &vec![BTableDataChangeEvents::InsertRow(BRowInsertData {
+                    column_names: vec![String::from("name"), String::from("id")],
+                    data_types: vec![BDataType::TEXT, BDataType::INTEGER],
+                    values: vec![String::from("Alice"), String::from("7")],
+                })],
+            )
+            .await
+            .unwrap();
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let audit = Audit::new(
+            repository,
+            console,
+            create_session(BRole::Admin),
+            tables_general_info,
+        );
+        audit.initialize_component().await;
+
+        let audit_entries = audit.audit_entries.lock().await.clone();
+        assert_eq!(audit_entries.len(), 1);
+        assert_eq!(audit_entries[0].column_names, vec!["id", "name"]);
+        assert_eq!(
+            audit_entries[0].primary_key_values,
+            vec![Some(String::from("7"))]
+        );
+
+        // the revert finds the row by its key
+        audit.revert_audit_entry(audit_entries[0].id).await;
+        let user_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(user_count, 0);
+    }
+
+    #[sqlx::test]
+    async fn test_audit_access_rules(pool: PgPool) {
+        let table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool.clone(), &table_in).await;
+        repository
+            .update_table_data(
+                &table_in.table_name,
+                &vec![BTableDataChangeEvents::InsertRow(BRowInsertData {
+                    column_names: vec![String::from("id"), String::from("name")],
+                    data_types: vec![BDataType::INTEGER, BDataType::TEXT],
+                    values: vec![String::from("1"), String::from("Alice")],
+                })],
+            )
+            .await
+            .unwrap();
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let session = create_session(BRole::Editor);
+        let access_rule = |column_name: Option<&str>, access_level| BAccessRule {
+            id: None,
+            role: BRole::Editor,
+            table_name: table_in.table_name.clone(),
+            column_name: column_name.map(String::from),
+            access_level,
+        };
+        session.set_access_rules(vec![access_rule(Some("name"), BAccessLevel::Hidden)]);
+        let audit = Audit::new(repository, console, session.clone(), tables_general_info);
+        audit.initialize_component().await;
+
+        // hidden values aren't shown, the key still identifies the row
+        let audit_entries = audit.audit_entries.lock().await.clone();
+        assert_eq!(audit_entries[0].column_names, vec!["id"]);
+        assert_eq!(
+            audit_entries[0].new_values,
+            Some(vec![Some(String::from("1"))])
+        );
+
+        // read-only tables can't be reverted
+        session.set_access_rules(vec![access_rule(None, BAccessLevel::Read)]);
+        audit.revert_audit_entry(audit_entries[0].id).await;
+        let user_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(user_count, 1);
+
+        session.set_access_rules(vec![access_rule(None, BAccessLevel::Hidden)]);
+        audit.set_audit_entries().await;
+        assert!(audit.audit_entries.lock().await.is_empty());
+    }
+}
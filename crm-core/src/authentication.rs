@@ -0,0 +1,159 @@
+use crate::component::{
+    repository_module::{BRepository, BRepositoryConsole},
+    BRole, BUser, BusinessComponent,
+};
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+#[derive(Debug, Clone)]
+pub struct Authentication {
+    pub(super) repository: Arc<BRepository>,
+    pub(super) repository_console: Arc<BRepositoryConsole>,
+    pub has_users: Arc<AsyncMutex<bool>>,
+    pub current_user: Arc<AsyncMutex<Option<BUser>>>,
+}
+
+impl BusinessComponent for Authentication {
+    async fn initialize_component(&self) {
+        self.repository.create_users_table().await.unwrap();
+        let has_users = !self.repository.get_users().await.unwrap().is_empty();
+        *self.has_users.lock().await = has_users;
+    }
+}
+
+impl Authentication {
+    pub fn new(repository: Arc<BRepository>, repository_console: Arc<BRepositoryConsole>) -> Self {
+        Self {
+            repository,
+            repository_console,
+            has_users: Arc::new(AsyncMutex::new(false)),
+            current_user: Arc::new(AsyncMutex::new(None)),
+        }
+    }
+
+    /* creates the repository, the rest of the components are built after login */
+    pub async fn connect() -> Self {
+        let repository_console = Arc::new(BRepositoryConsole::new());
+        let repository = Arc::new(BRepository::new(None, repository_console.clone()).await);
+        Self::new(repository, repository_console)
+    }
+
+    pub async fn log_in(&self, user_name: String, password: String) -> Result<(), String> {
+        let user_info = self
+            .repository
+            .get_user(&user_name)
+            .await
+            .map_err(|error| error.to_string())?;
+        // the same message for unknown users and wrong passwords
+        let Some(user_info) = user_info else {
+            return Err(String::from("Invalid user name or password"));
+        };
+        let password_hash = user_info.password_hash.clone();
+        let is_valid_password =
+            task::spawn_blocking(move || verify_password(&password, &password_hash))
+                .await
+                .unwrap();
+        if !is_valid_password {
+            return Err(String::from("Invalid user name or password"));
+        }
+        let user = BUser::to_user(user_info);
+        self.repository.set_user_name(user.user_name.clone());
+        *self.current_user.lock().await = Some(user);
+        Ok(())
+    }
+
+    // only possible while no account exists, the first user administers the rest
+    pub async fn create_first_admin(
+        &self,
+        user_name: String,
+        password: String,
+    ) -> Result<(), String> {
+        if user_name.is_empty() || password.is_empty() {
+            return Err(String::from("User name and password are required"));
+        }
+        if !self
+            .repository
+            .get_users()
+            .await
+            .map_err(|error| error.to_string())?
+            .is_empty()
+        {
+            return Err(String::from("An administrator already exists"));
+        }
+        let password_to_hash = password.clone();
+        let password_hash = task::spawn_blocking(move || hash_password(&password_to_hash))
+            .await
+            .unwrap();
+        self.repository
+            .insert_user(&user_name, &password_hash, &BRole::Admin)
+            .await
+            .map_err(|error| error.to_string())?;
+        *self.has_users.lock().await = true;
+        self.log_in(user_name, password).await
+    }
+}
+
+pub(super) fn hash_password(password: &str) -> String {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .unwrap()
+        .to_string()
+}
+
+fn verify_password(password: &str, password_hash: &str) -> bool {
+    PasswordHash::new(password_hash).is_ok_and(|password_hash| {
+        Argon2::default()
+            .verify_password(password.as_bytes(), &password_hash)
+            .is_ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::test_utils::{create_database_console, create_repository};
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_create_first_admin_and_log_in(pool: PgPool) {
+        let repository_console = create_database_console();
+        let repository = create_repository(pool, repository_console.clone()).await;
+        let authentication = Authentication::new(repository, repository_console);
+        authentication.initialize_component().await;
+        assert!(!*authentication.has_users.lock().await);
+
+        authentication
+            .create_first_admin(String::from("admin"), String::from("secret"))
+            .await
+            .unwrap();
+        assert_eq!(
+            authentication
+                .current_user
+                .lock()
+                .await
+                .as_ref()
+                .unwrap()
+                .role,
+            BRole::Admin
+        );
+        assert!(authentication
+            .create_first_admin(String::from("other"), String::from("secret"))
+            .await
+            .is_err());
+
+        assert!(authentication
+            .log_in(String::from("admin"), String::from("wrong"))
+            .await
+            .is_err());
+        assert!(authentication
+            .log_in(String::from("admin"), String::from("secret"))
+            .await
+            .is_ok());
+    }
+}
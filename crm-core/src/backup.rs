@@ -0,0 +1,452 @@
+use crate::component::{
+    repository_module::BRepository, BBackupArchive, BBackupProgress, BBackupTable, BPermission,
+    BSession, BTableGeneral,
+};
+use crate::components::BusinessConsole;
+use crate::schema_file::sort_by_foreign_keys;
+use crate::tables::utils::{get_tables_in, set_tables_general_info};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use futures::channel::mpsc::UnboundedSender;
+use regex::Regex;
+use std::io::{Read, Write};
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::{fs, task};
+
+const ARCHIVE_HEADER: &str = "-- CRM backup";
+const COPY_DATA_END: &str = "\\.";
+
+#[derive(Debug, Clone)]
+pub struct Backup {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    session: Arc<BSession>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+}
+
+impl Backup {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            tables_general_info,
+        }
+    }
+
+    pub async fn create_backup(
+        &self,
+        path: String,
+        progress: UnboundedSender<BBackupProgress>,
+    ) -> Result<(), String> {
+        let backup_archive = self.create_backup_archive(&progress).await?;
+        let content = write_backup_archive(&backup_archive)?;
+        fs::write(&path, content)
+            .await
+            .map_err(|error| format!("Could not write \"{}\": {}", path, error))?;
+        self.write_to_console(format!(
+            "Backed up {} tables to \"{}\"",
+            backup_archive.tables.len(),
+            path
+        ))
+        .await;
+        Ok(())
+    }
+
+    /* every table of the public schema with its rows, the CREATE TABLE statements are read
+     * from the catalog; the crm_metadata schema (users, access rules, audit log, views,
+     * layouts and charts) is not included */
+    pub async fn create_backup_archive(
+        &self,
+        progress: &UnboundedSender<BBackupProgress>,
+    ) -> Result<BBackupArchive, String> {
+        self.session.authorize(BPermission::ManageBackups)?;
+        let tables = get_tables_in(self.repository.clone()).await?;
+        let tables = sort_by_foreign_keys(tables);
+
+        let mut backup_archive = BBackupArchive::default();
+        for (table_index, table_in) in tables.iter().enumerate() {
+            let _ = progress.unbounded_send(BBackupProgress {
+                table_name: table_in.table_name.clone(),
+                completed_tables: table_index,
+                total_tables: tables.len(),
+            });
+            // postgres fills generated columns again when the rows are restored
+            let generated_column_names = self
+                .repository
+                .get_generated_column_names(&table_in.table_name)
+                .await
+                .map_err(|error| error.to_string())?;
+            let column_names: Vec<String> = table_in
+                .columns
+                .iter()
+                .map(|column| column.name.clone())
+                .filter(|column_name| !generated_column_names.contains(column_name))
+                .collect();
+            let reading_error = |error: sqlx::Error| {
+                format!("Reading \"{}\" failed: {}", table_in.table_name, error)
+            };
+            let (create_table_query, sequence_column_names) = self
+                .repository
+                .get_table_definition(&table_in.table_name)
+                .await
+                .map_err(reading_error)?;
+            let data = self
+                .repository
+                .copy_table_out(&table_in.table_name, &column_names)
+                .await
+                .map_err(reading_error)?;
+            backup_archive.tables.push(BBackupTable {
+                table_name: table_in.table_name.clone(),
+                create_table_query,
+                column_names,
+                data,
+                sequence_column_names,
+            });
+        }
+        Ok(backup_archive)
+    }
+
+    pub async fn restore_backup(
+        &self,
+        path: String,
+        progress: UnboundedSender<BBackupProgress>,
+    ) -> Result<(), String> {
+        let content = fs::read(&path)
+            .await
+            .map_err(|error| format!("Could not read \"{}\": {}", path, error))?;
+        let backup_archive = read_backup_archive(&content)?;
+        self.restore_backup_archive(&backup_archive, &progress)
+            .await?;
+        self.write_to_console(format!(
+            "Restored {} tables from \"{}\"",
+            backup_archive.tables.len(),
+            path
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn restore_backup_archive(
+        &self,
+        backup_archive: &BBackupArchive,
+        progress: &UnboundedSender<BBackupProgress>,
+    ) -> Result<(), String> {
+        self.session.authorize(BPermission::ManageBackups)?;
+        // existing rows are never merged with the backup
+        let live_tables = self
+            .repository
+            .get_general_tables_info()
+            .await
+            .map_err(|error| error.to_string())?;
+        if let Some(backup_table) = backup_archive.tables.iter().find(|backup_table| {
+            live_tables
+                .iter()
+                .any(|live_table| live_table.table_name == backup_table.table_name)
+        }) {
+            return Err(format!(
+                "\"{}\" already exists, backups are restored into an empty database",
+                backup_table.table_name
+            ));
+        }
+
+        self.repository
+            .restore_backup(backup_archive, progress)
+            .await
+            .map_err(|error| format!("Restore failed, nothing was changed: {}", error))?;
+        set_tables_general_info(self.repository.clone(), self.tables_general_info.clone()).await;
+        Ok(())
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+/* the archive is a gzipped SQL script, the CREATE TABLE statements followed by
+ * one COPY block per table and the sequence resets, so it can also be restored with psql */
+pub fn write_backup_archive(backup_archive: &BBackupArchive) -> Result<Vec<u8>, String> {
+    let mut script = format!("{}\n", ARCHIVE_HEADER);
+    for backup_table in &backup_archive.tables {
+        script.push_str(&format!("{};\n", backup_table.create_table_query));
+    }
+    for backup_table in &backup_archive.tables {
+        script.push_str(&format!(
+            "\n{};\n{}{}\n",
+            BRepository::copy_query(
+                &backup_table.table_name,
+                &backup_table.column_names,
+                "FROM STDIN"
+            ),
+            backup_table.data,
+            COPY_DATA_END
+        ));
+    }
+    for backup_table in &backup_archive.tables {
+        for column_name in &backup_table.sequence_column_names {
+            script.push_str(&format!(
+                "{};\n",
+                BRepository::reset_sequence_query(&backup_table.table_name, column_name)
+            ));
+        }
+    }
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(script.as_bytes())
+        .and_then(|_| encoder.finish())
+        .map_err(|error| error.to_string())
+}
+
+pub fn read_backup_archive(content: &[u8]) -> Result<BBackupArchive, String> {
+    let mut script = String::new();
+    GzDecoder::new(content)
+        .read_to_string(&mut script)
+        .map_err(|error| format!("Invalid backup archive: {}", error))?;
+    let mut lines = script.lines();
+    if lines.next() != Some(ARCHIVE_HEADER) {
+        return Err(String::from("Invalid backup archive: missing header"));
+    }
+
+    let create_table_regex = Regex::new(r#"^CREATE TABLE "([^"]+)""#).unwrap();
+    let copy_regex = Regex::new(r#"^COPY "([^"]+)" \((.*)\) FROM STDIN;$"#).unwrap();
+    let reset_sequence_regex =
+        Regex::new(r#"^SELECT setval\(pg_get_serial_sequence\('"([^"]+)"', '((?:[^']|'')+)'\)"#)
+            .unwrap();
+    let mut backup_archive = BBackupArchive::default();
+    while let Some(line) = lines.next() {
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(captures) = create_table_regex.captures(line) {
+            backup_archive.tables.push(BBackupTable {
+                table_name: captures[1].to_string(),
+                create_table_query: line.trim_end_matches(';').to_string(),
+                column_names: vec![],
+                data: String::new(),
+                sequence_column_names: vec![],
+            });
+        } else if let Some(captures) = copy_regex.captures(line) {
+            let Some(backup_table) = backup_archive
+                .tables
+                .iter_mut()
+                .find(|backup_table| backup_table.table_name == captures[1])
+            else {
+                return Err(format!(
+                    "Invalid backup archive: unknown table \"{}\"",
+                    &captures[1]
+                ));
+            };
+            backup_table.column_names = parse_column_list(&captures[2]);
+            loop {
+                match lines.next() {
+                    Some(COPY_DATA_END) => break,
+                    Some(data_line) => {
+                        backup_table.data.push_str(data_line);
+                        backup_table.data.push('\n');
+                    }
+                    None => {
+                        return Err(format!(
+                            "Invalid backup archive: data of \"{}\" is cut off",
+                            backup_table.table_name
+                        ))
+                    }
+                }
+            }
+        } else if let Some(captures) = reset_sequence_regex.captures(line) {
+            let Some(backup_table) = backup_archive
+                .tables
+                .iter_mut()
+                .find(|backup_table| backup_table.table_name == captures[1])
+            else {
+                return Err(format!(
+                    "Invalid backup archive: unknown table \"{}\"",
+                    &captures[1]
+                ));
+            };
+            backup_table
+                .sequence_column_names
+                .push(captures[2].replace("''", "'"));
+        } else {
+            return Err(format!(
+                "Invalid backup archive: unexpected line \"{}\"",
+                line
+            ));
+        }
+    }
+    Ok(backup_archive)
+}
+
+// the quoted names of a COPY column list, quotes inside a name are doubled
+fn parse_column_list(column_list: &str) -> Vec<String> {
+    let mut column_names = vec![];
+    let mut column_name = String::new();
+    let mut is_quoted = false;
+    let mut characters = column_list.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '"' if is_quoted && characters.peek() == Some(&'"') => {
+                column_name.push('"');
+                characters.next();
+            }
+            '"' => {
+                is_quoted = !is_quoted;
+                if !is_quoted {
+                    column_names.push(std::mem::take(&mut column_name));
+                }
+            }
+            character if is_quoted => column_name.push(character),
+            _ => {}
+        }
+    }
+    column_names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{BColumn, BConstraint, BDataType, BRole, BTableIn};
+    use crate::tables::test_utils::{
+        create_repository_table_and_console, create_session, default_table_in,
+    };
+    use futures::channel::mpsc;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_backup_and_restore(pool: PgPool) {
+        let users_table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool.clone(), &users_table_in).await;
+        repository
+            .create_table(&BTableIn {
+                table_name: String::from("orders"),
+                columns: vec![
+                    BColumn {
+                        name: String::from("id"),
+                        datatype: BDataType::INTEGER,
+                        constraints: vec![BConstraint::PrimaryKey],
+                    },
+                    BColumn {
+                        name: String::from("user_id"),
+                        datatype: BDataType::INTEGER,
+                        constraints: vec![BConstraint::ForeignKey(
+                            String::from("users"),
+                            String::from("id"),
+                        )],
+                    },
+                ],
+            })
+            .await;
+        sqlx::query("INSERT INTO users VALUES (1, E'Smith,\\tJohn\\nJr.'), (2, NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO orders VALUES (1, 1), (2, 2)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        // what the table editor can't express has to survive the restore as well
+        for query in [
+            "CREATE TABLE notes (
+                id SERIAL PRIMARY KEY,
+                title TEXT NOT NULL DEFAULT 'untitled' UNIQUE,
+                title_length INTEGER GENERATED ALWAYS AS (length(title)) STORED,
+                \"to, from\" TEXT
+            )",
+            "INSERT INTO notes (title, \"to, from\") VALUES ('first', 'a'), ('second', 'b')",
+        ] {
+            sqlx::query(query).execute(&pool).await.unwrap();
+        }
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        let backup = Backup::new(
+            repository.clone(),
+            console,
+            create_session(BRole::Admin),
+            tables_general_info.clone(),
+        );
+        let (sender, _receiver) = mpsc::unbounded();
+        let backup_archive = backup.create_backup_archive(&sender).await.unwrap();
+        let table_names: Vec<&str> = backup_archive
+            .tables
+            .iter()
+            .map(|backup_table| backup_table.table_name.as_str())
+            .collect();
+        assert_eq!(table_names, vec!["notes", "users", "orders"]);
+
+        let content = write_backup_archive(&backup_archive).unwrap();
+        let backup_archive = read_backup_archive(&content).unwrap();
+
+        // a restore never touches tables that still exist
+        assert!(backup
+            .restore_backup_archive(&backup_archive, &sender)
+            .await
+            .is_err());
+
+        sqlx::query("DROP TABLE notes, orders, users")
+            .execute(&pool)
+            .await
+            .unwrap();
+        backup
+            .restore_backup_archive(&backup_archive, &sender)
+            .await
+            .unwrap();
+
+        let names: Vec<Option<String>> = sqlx::query_scalar("SELECT name FROM users ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(names, vec![Some(String::from("Smith,\tJohn\nJr.")), None]);
+        let order_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM orders")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(order_count, 2);
+        assert_eq!(tables_general_info.lock().await.len(), 3);
+
+        // the sequence continues after the restored rows and the defaults still apply
+        let note: (i32, String, i32, Option<String>) = sqlx::query_as(
+            "INSERT INTO notes DEFAULT VALUES RETURNING id, title, title_length, \"to, from\"",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(note, (3, String::from("untitled"), 8, None));
+        let notes_to: Vec<Option<String>> =
+            sqlx::query_scalar("SELECT \"to, from\" FROM notes WHERE id < 3 ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            notes_to,
+            vec![Some(String::from("a")), Some(String::from("b"))]
+        );
+        assert!(sqlx::query("INSERT INTO notes (title) VALUES ('first')")
+            .execute(&pool)
+            .await
+            .is_err());
+        assert!(sqlx::query("INSERT INTO notes (title) VALUES (NULL)")
+            .execute(&pool)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_column_list() {
+        assert_eq!(
+            parse_column_list(r#""id", "to, from", "say ""hi""""#),
+            vec!["id", "to, from", r#"say "hi""#]
+        );
+        assert!(parse_column_list("").is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::database::schemas::{
+    AccessLevel, AccessRule, Aggregate, AuditEntry, AuditFilter, AuditOperation, BackupArchive,
+    BackupProgress, BackupTable, ChangeStatus, Chart, ChartDefinition, ChartPoint, ChartType,
+    ChildRecords, Column, ColumnConversion, ColumnForeignKey, ColumnLayout, ComputedColumn,
+    Condition, Constraint, ConversionPreview, DataType, DiagramTable, DuplicateGroup, DuplicateKey,
+    FilterOperator, ForeignKeyReference, MatchType, NodePosition, Permission, Role, RowColumnValue,
+    RowInsertData, RowMerge, SchemaDefinition, SchemaDiff, SchemaPlan, SearchResult,
+    SearchResultRow, SortDirection, TableAlteration, TableChange, TableChangeEvents,
+    TableDataChangeEvents, TableDataChangeStatus, TableGeneral, TableIn, TableInsertedData,
+    TableView, TimeBucket, User, ViewFilter, ViewSort,
+};
+use crate::session::Session;
+use crate::tables::{
+    table_data::table_data::{DetailTableData, TableData},
+    table_info::table_info::TableInfo,
+    table_views::table_views::TableViews,
+};
+
+pub type BColumn = Column;
+pub type BDataType = DataType;
+pub type BTableIn = TableIn;
+pub type BTableChangeEvents = TableChangeEvents;
+pub type BTableDataChangeEvents = TableDataChangeEvents;
+pub type BTableInfo = TableInfo;
+pub type BTableData = TableData;
+pub type BDetailTableData = DetailTableData;
+pub type BTableViews = TableViews;
+pub type BTableGeneral = TableGeneral;
+pub type BConstraint = Constraint;
+pub type BColumnForeignKey = ColumnForeignKey;
+pub type BCondition = Condition;
+pub type BTableInsertedData = TableInsertedData;
+pub type BRowColumnValue = RowColumnValue;
+pub type BRowInsertData = RowInsertData;
+pub type BChart = Chart;
+pub type BChartDefinition = ChartDefinition;
+pub type BChartPoint = ChartPoint;
+pub type BChartType = ChartType;
+pub type BAggregate = Aggregate;
+pub type BTimeBucket = TimeBucket;
+pub type BTableView = TableView;
+pub type BViewFilter = ViewFilter;
+pub type BViewSort = ViewSort;
+pub type BFilterOperator = FilterOperator;
+pub type BSortDirection = SortDirection;
+pub type BSearchResult = SearchResult;
+pub type BSearchResultRow = SearchResultRow;
+pub type BMatchType = MatchType;
+pub type BDuplicateKey = DuplicateKey;
+pub type BDuplicateGroup = DuplicateGroup;
+pub type BForeignKeyReference = ForeignKeyReference;
+pub type BRowMerge = RowMerge;
+pub type BAuditEntry = AuditEntry;
+pub type BAuditFilter = AuditFilter;
+pub type BAuditOperation = AuditOperation;
+pub type BRole = Role;
+pub type BPermission = Permission;
+pub type BUser = User;
+pub type BSession = Session;
+pub type BAccessLevel = AccessLevel;
+pub type BAccessRule = AccessRule;
+pub type BTableChange = TableChange;
+pub type BChangeStatus = ChangeStatus;
+pub type BTableDataChangeStatus = TableDataChangeStatus;
+pub type BDiagramTable = DiagramTable;
+pub type BNodePosition = NodePosition;
+pub type BSchemaDefinition = SchemaDefinition;
+pub type BSchemaPlan = SchemaPlan;
+pub type BSchemaDiff = SchemaDiff;
+pub type BBackupArchive = BackupArchive;
+pub type BBackupTable = BackupTable;
+pub type BBackupProgress = BackupProgress;
+pub type BTableAlteration = TableAlteration;
+pub type BColumnConversion = ColumnConversion;
+pub type BConversionPreview = ConversionPreview;
+pub type BColumnLayout = ColumnLayout;
+pub type BComputedColumn = ComputedColumn;
+pub type BChildRecords = ChildRecords;
+
+pub trait BusinessComponent {
+    async fn initialize_component(&self) {}
+}
+
+pub mod repository_module {
+    use crate::database::console::RepositoryConsole;
+    use crate::database::repository::Repository;
+
+    pub type BRepository = Repository;
+    pub type BRepositoryConsole = RepositoryConsole;
+}
@@ -0,0 +1,148 @@
+use crate::component::{BSession, BusinessComponent};
+use crate::{
+    access_rules::AccessRules, audit::Audit, authentication::Authentication, backup::Backup,
+    console::Console, dashboard::Dashboard, data_transfer::DataTransfer, dedupe::Dedupe,
+    home::Home, schema_comparison::SchemaComparison, schema_diagram::SchemaDiagram,
+    schema_file::SchemaFile, search::Search, session::Session, tables::tables::Tables,
+    users::Users,
+};
+use std::sync::Arc;
+
+pub type BusinessHome = Home;
+pub type BusinessTables = Tables;
+pub type BusinessConsole = Console;
+pub type BusinessDashboard = Dashboard;
+pub type BusinessSearch = Search;
+pub type BusinessDedupe = Dedupe;
+pub type BusinessAudit = Audit;
+pub type BusinessAuthentication = Authentication;
+pub type BusinessSession = Session;
+pub type BusinessUsers = Users;
+pub type BusinessAccessRules = AccessRules;
+pub type BusinessSchemaDiagram = SchemaDiagram;
+pub type BusinessSchemaFile = SchemaFile;
+pub type BusinessSchemaComparison = SchemaComparison;
+pub type BusinessBackup = Backup;
+pub type BusinessDataTransfer = DataTransfer;
+
+#[derive(Debug, Clone)]
+pub struct BusinessComponents {
+    pub home: Arc<BusinessHome>,
+    pub tables: Arc<BusinessTables>,
+    pub console: Arc<Console>,
+    pub dashboard: Arc<BusinessDashboard>,
+    pub search: Arc<BusinessSearch>,
+    pub dedupe: Arc<BusinessDedupe>,
+    pub audit: Arc<BusinessAudit>,
+    pub users: Arc<BusinessUsers>,
+    pub access_rules: Arc<BusinessAccessRules>,
+    pub schema_diagram: Arc<BusinessSchemaDiagram>,
+    pub schema_file: Arc<BusinessSchemaFile>,
+    pub schema_comparison: Arc<BusinessSchemaComparison>,
+    pub backup: Arc<BusinessBackup>,
+    pub data_transfer: Arc<BusinessDataTransfer>,
+    pub session: Arc<BusinessSession>,
+}
+
+impl BusinessComponents {
+    /* built once the user has logged in, every component shares the session */
+    pub async fn new(authentication: Arc<BusinessAuthentication>) -> Result<Self, String> {
+        let repository_console = authentication.repository_console.clone();
+        let repository = authentication.repository.clone();
+        let current_user = authentication
+            .current_user
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| String::from("No user is logged in"))?;
+        let session = Arc::new(BSession::new(current_user));
+        let console = Arc::new(Console::new(repository_console.clone()));
+        let tables = Arc::new(BusinessTables::new(
+            repository.clone(),
+            console.clone(),
+            session.clone(),
+        ));
+        // the rules are in the session before any table data can be read
+        let access_rules = Arc::new(BusinessAccessRules::new(
+            repository.clone(),
+            console.clone(),
+            session.clone(),
+            tables.tables_general_info.clone(),
+        ));
+        access_rules.initialize_component().await;
+        // every page that lists tables orders their columns by the layouts
+        repository
+            .create_column_layouts_table()
+            .await
+            .map_err(|error| format!("Could not create the column layouts table: {}", error))?;
+        repository
+            .create_computed_columns_table()
+            .await
+            .map_err(|error| format!("Could not create the computed columns table: {}", error))?;
+        Ok(Self {
+            home: Arc::new(BusinessHome::new(console.clone())),
+            dashboard: Arc::new(BusinessDashboard::new(
+                repository.clone(),
+                console.clone(),
+                session.clone(),
+                tables.tables_general_info.clone(),
+            )),
+            search: Arc::new(BusinessSearch::new(
+                repository.clone(),
+                console.clone(),
+                session.clone(),
+                tables.tables_general_info.clone(),
+            )),
+            dedupe: Arc::new(BusinessDedupe::new(
+                repository.clone(),
+                console.clone(),
+                session.clone(),
+                tables.tables_general_info.clone(),
+            )),
+            audit: Arc::new(BusinessAudit::new(
+                repository.clone(),
+                console.clone(),
+                session.clone(),
+                tables.tables_general_info.clone(),
+            )),
+            schema_diagram: Arc::new(BusinessSchemaDiagram::new(
+                repository.clone(),
+                console.clone(),
+                tables.tables_general_info.clone(),
+            )),
+            schema_file: Arc::new(BusinessSchemaFile::new(
+                repository.clone(),
+                console.clone(),
+                session.clone(),
+                tables.tables_general_info.clone(),
+            )),
+            schema_comparison: Arc::new(BusinessSchemaComparison::new(
+                repository.clone(),
+                repository_console.clone(),
+                console.clone(),
+                session.clone(),
+            )),
+            backup: Arc::new(BusinessBackup::new(
+                repository.clone(),
+                console.clone(),
+                session.clone(),
+                tables.tables_general_info.clone(),
+            )),
+            data_transfer: Arc::new(BusinessDataTransfer::new(
+                repository.clone(),
+                console.clone(),
+                session.clone(),
+                tables.tables_general_info.clone(),
+            )),
+            access_rules,
+            users: Arc::new(BusinessUsers::new(
+                repository.clone(),
+                console.clone(),
+                session.clone(),
+            )),
+            tables,
+            session,
+            console: console.clone(),
+        })
+    }
+}
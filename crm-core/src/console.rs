@@ -1,5 +1,5 @@
-use crate::components::business_components::component::repository_module::BRepositoryConsole;
-use std::sync::{Arc, Mutex};
+use crate::component::repository_module::BRepositoryConsole;
+use std::sync::Arc;
 use tokio::sync::Mutex as AsyncMutex;
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,224 @@
+use crate::component::{
+    repository_module::BRepository, BAccessLevel, BChart, BChartDefinition, BPermission, BSession,
+    BTableGeneral, BusinessComponent,
+};
+use crate::components::BusinessConsole;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+#[derive(Debug, Clone)]
+pub struct Dashboard {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    session: Arc<BSession>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    pub charts: Arc<AsyncMutex<Vec<BChart>>>,
+}
+
+impl BusinessComponent for Dashboard {
+    async fn initialize_component(&self) {
+        self.repository.create_charts_table().await.unwrap();
+        self.set_charts().await;
+    }
+}
+
+impl Dashboard {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            tables_general_info,
+            charts: Arc::new(AsyncMutex::new(vec![])),
+        }
+    }
+
+    pub async fn set_charts(&self) {
+        let chart_definitions: Vec<BChartDefinition> = self
+            .repository
+            .get_chart_definitions()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(BChartDefinition::to_chart_definition)
+            .collect();
+
+        let mut charts = vec![];
+        for chart_definition in chart_definitions {
+            // a chart whose table or columns were altered away, or are hidden from the user,
+            // is kept with no points so it can still be deleted from the dashboard
+            let points = match self.authorize_chart_read(&chart_definition) {
+                Ok(()) => match self.repository.get_chart_points(&chart_definition).await {
+                    Ok(points) => points,
+                    Err(error) => {
+                        self.write_to_console(format!(
+                            "Chart \"{}\" failed to load: {}",
+                            chart_definition.title, error
+                        ))
+                        .await;
+                        vec![]
+                    }
+                },
+                Err(message) => {
+                    self.write_to_console(message).await;
+                    vec![]
+                }
+            };
+            charts.push(BChart {
+                definition: chart_definition,
+                points,
+            });
+        }
+        *self.charts.lock().await = charts;
+    }
+
+    fn authorize_chart_read(&self, chart_definition: &BChartDefinition) -> Result<(), String> {
+        let table_name = &chart_definition.table_name;
+        let is_hidden = self.session.table_access_level(table_name) == BAccessLevel::Hidden
+            || std::iter::once(&chart_definition.group_by_column)
+                .chain(chart_definition.aggregate_column.as_ref())
+                .any(|column_name| {
+                    self.session.column_access_level(table_name, column_name)
+                        == BAccessLevel::Hidden
+                });
+        if is_hidden {
+            Err(format!(
+                "Chart \"{}\" uses columns hidden from {}",
+                chart_definition.title, self.session.current_user.user_name
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub async fn add_chart(&self, chart_definition: BChartDefinition) {
+        if let Err(message) = self.session.authorize(BPermission::EditRows) {
+            self.write_to_console(message).await;
+            return;
+        }
+        self.repository
+            .insert_chart_definition(&chart_definition)
+            .await
+            .unwrap();
+        self.set_charts().await;
+    }
+
+    pub async fn delete_chart(&self, chart_id: i32) {
+        if let Err(message) = self.session.authorize(BPermission::EditRows) {
+            self.write_to_console(message).await;
+            return;
+        }
+        self.repository
+            .delete_chart_definition(chart_id)
+            .await
+            .unwrap();
+        self.set_charts().await;
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{BAccessRule, BAggregate, BChartPoint, BChartType, BRole};
+    use crate::tables::test_utils::{
+        create_insert_row_events, create_repository_table_and_console, create_session,
+        default_table_in,
+    };
+    use crate::tables::utils::set_tables_general_info;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_add_and_delete_chart(pool: PgPool) {
+        let table_in = default_table_in();
+        let (repository, console) = create_repository_table_and_console(pool, &table_in).await;
+        let insert_row_events = create_insert_row_events(
+            &table_in,
+            vec![vec!["1", "Alice"], vec!["2", "Bob"], vec!["3", "Alice"]],
+        );
+        repository
+            .update_table_data(&table_in.table_name, &insert_row_events)
+            .await
+            .unwrap();
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let viewer_dashboard = Dashboard::new(
+            repository.clone(),
+            console.clone(),
+            create_session(BRole::Viewer),
+            tables_general_info.clone(),
+        );
+        viewer_dashboard.initialize_component().await;
+        let session = create_session(BRole::Editor);
+        let dashboard = Dashboard::new(repository, console, session.clone(), tables_general_info);
+        dashboard.initialize_component().await;
+
+        let chart_definition = BChartDefinition {
+            id: None,
+            title: String::from("Users per name"),
+            chart_type: BChartType::Bar,
+            table_name: table_in.table_name.clone(),
+            group_by_column: String::from("name"),
+            aggregate: BAggregate::Count,
+            aggregate_column: None,
+            time_bucket: None,
+        };
+        viewer_dashboard.add_chart(chart_definition.clone()).await;
+        assert!(viewer_dashboard.charts.lock().await.is_empty());
+        dashboard.add_chart(chart_definition.clone()).await;
+
+        let charts = dashboard.charts.lock().await.clone();
+        assert_eq!(charts.len(), 1);
+        assert_eq!(charts[0].definition.title, chart_definition.title);
+        assert_eq!(
+            charts[0].points,
+            vec![
+                BChartPoint {
+                    label: String::from("Alice"),
+                    value: 2.0,
+                },
+                BChartPoint {
+                    label: String::from("Bob"),
+                    value: 1.0,
+                },
+            ]
+        );
+
+        // a chart grouped by a hidden column has no points
+        session.set_access_rules(vec![BAccessRule {
+            id: None,
+            role: BRole::Editor,
+            table_name: table_in.table_name.clone(),
+            column_name: Some(String::from("name")),
+            access_level: BAccessLevel::Hidden,
+        }]);
+        dashboard.set_charts().await;
+        assert!(dashboard.charts.lock().await[0].points.is_empty());
+
+        viewer_dashboard
+            .delete_chart(charts[0].definition.id.unwrap())
+            .await;
+        dashboard.set_charts().await;
+        assert_eq!(dashboard.charts.lock().await.len(), 1);
+
+        dashboard
+            .delete_chart(charts[0].definition.id.unwrap())
+            .await;
+        assert!(dashboard.charts.lock().await.is_empty());
+    }
+}
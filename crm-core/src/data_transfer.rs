@@ -0,0 +1,287 @@
+use crate::component::{
+    repository_module::BRepository, BAccessLevel, BPermission, BSession, BTableGeneral,
+};
+use crate::components::BusinessConsole;
+use crate::tables::utils::set_tables_general_info;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::{fs, task};
+
+/* moving rows in and out of the database without the tables page,
+ * e.g. nightly imports run from a script */
+#[derive(Debug, Clone)]
+pub struct DataTransfer {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    session: Arc<BSession>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+}
+
+impl DataTransfer {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            tables_general_info,
+        }
+    }
+
+    async fn get_table_general_info(&self, table_name: &str) -> Result<BTableGeneral, String> {
+        self.tables_general_info
+            .lock()
+            .await
+            .iter()
+            .find(|table_general_info| table_general_info.table_name == table_name)
+            .filter(|_| self.session.table_access_level(table_name) != BAccessLevel::Hidden)
+            .cloned()
+            .ok_or(format!("There is no table \"{}\"", table_name))
+    }
+
+    async fn get_generated_column_names(&self, table_name: &str) -> Result<Vec<String>, String> {
+        self.repository
+            .get_generated_column_names(table_name)
+            .await
+            .map_err(|error| error.to_string())
+    }
+
+    // hidden and generated columns are left out of the file, COPY can't read generated ones
+    pub async fn export_csv(&self, table_name: String, path: String) -> Result<(), String> {
+        let table_general_info = self.get_table_general_info(&table_name).await?;
+        let generated_column_names = self.get_generated_column_names(&table_name).await?;
+        let column_names: Vec<String> = table_general_info
+            .column_names
+            .into_iter()
+            .filter(|column_name| {
+                self.session.column_access_level(&table_name, column_name) != BAccessLevel::Hidden
+                    && !generated_column_names.contains(column_name)
+            })
+            .collect();
+        let content = self
+            .repository
+            .copy_table_csv_out(&table_name, &column_names)
+            .await
+            .map_err(|error| format!("Reading \"{}\" failed: {}", table_name, error))?;
+        fs::write(&path, content)
+            .await
+            .map_err(|error| format!("Could not write \"{}\": {}", path, error))?;
+        self.write_to_console(format!("Exported \"{}\" to \"{}\"", table_name, path))
+            .await;
+        Ok(())
+    }
+
+    // the header line names the columns, columns missing from it get their defaults
+    pub async fn import_csv(&self, table_name: String, path: String) -> Result<u64, String> {
+        self.session.authorize(BPermission::EditRows)?;
+        self.session.authorize_table_write(&table_name)?;
+        let table_general_info = self.get_table_general_info(&table_name).await?;
+        let content = fs::read_to_string(&path)
+            .await
+            .map_err(|error| format!("Could not read \"{}\": {}", path, error))?;
+        let column_names = parse_csv_header(&content);
+        if column_names.is_empty() {
+            return Err(format!("\"{}\" has no header line", path));
+        }
+        let generated_column_names = self.get_generated_column_names(&table_name).await?;
+        for column_name in &column_names {
+            if !table_general_info.column_names.contains(column_name) {
+                return Err(format!(
+                    "\"{}\" has no column \"{}\"",
+                    table_name, column_name
+                ));
+            }
+            if generated_column_names.contains(column_name) {
+                return Err(format!(
+                    "\"{}\" is generated and can't be imported",
+                    column_name
+                ));
+            }
+            self.session
+                .authorize_column_write(&table_name, column_name)?;
+        }
+        let rows_count = self
+            .repository
+            .copy_table_csv_in(&table_name, &column_names, &content)
+            .await
+            .map_err(|error| {
+                format!(
+                    "Importing into \"{}\" failed, nothing was imported: {}",
+                    table_name, error
+                )
+            })?;
+        self.write_to_console(format!(
+            "Imported {} rows from \"{}\" into \"{}\"",
+            rows_count, path, table_name
+        ))
+        .await;
+        Ok(rows_count)
+    }
+
+    // bypasses the access rules, so it is reserved to roles that may do anything
+    pub async fn run_sql(
+        &self,
+        query: String,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>), String> {
+        self.session.authorize(BPermission::RunSql)?;
+        let result = self
+            .repository
+            .run_sql(&query)
+            .await
+            .map_err(|error| error.to_string())?;
+        // the statements may have created or altered tables
+        set_tables_general_info(self.repository.clone(), self.tables_general_info.clone()).await;
+        Ok(result)
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+// column names of the first line, quoted names may hold commas and doubled quotes
+pub fn parse_csv_header(content: &str) -> Vec<String> {
+    let header = content.lines().next().unwrap_or_default();
+    let mut column_names = vec![];
+    let mut column_name = String::new();
+    let mut is_quoted = false;
+    let mut characters = header.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '"' if is_quoted && characters.peek() == Some(&'"') => {
+                column_name.push('"');
+                characters.next();
+            }
+            '"' => is_quoted = !is_quoted,
+            ',' if !is_quoted => column_names.push(std::mem::take(&mut column_name)),
+            _ => column_name.push(character),
+        }
+    }
+    if !header.is_empty() {
+        column_names.push(column_name);
+    }
+    column_names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::BRole;
+    use crate::tables::test_utils::{
+        create_repository_table_and_console, create_session, default_table_in,
+    };
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_export_and_import_csv(pool: PgPool) {
+        let table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool.clone(), &table_in).await;
+        sqlx::query("INSERT INTO users VALUES (1, 'Smith, \"JJ\"'), (2, NULL), (3, '')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let tables_general_info = Arc::new(AsyncMutex::new(vec![]));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let data_transfer = DataTransfer::new(
+            repository.clone(),
+            console.clone(),
+            create_session(BRole::Admin),
+            tables_general_info.clone(),
+        );
+        let path = std::env::temp_dir()
+            .join(format!("crm_users_{}.csv", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+
+        data_transfer
+            .export_csv(String::from("users"), path.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).await.unwrap(),
+            "id,name\n1,\"Smith, \"\"JJ\"\"\"\n2,\n3,\"\"\n"
+        );
+
+        data_transfer
+            .run_sql(String::from("DELETE FROM users"))
+            .await
+            .unwrap();
+        assert_eq!(
+            data_transfer
+                .import_csv(String::from("users"), path.clone())
+                .await
+                .unwrap(),
+            3
+        );
+        let (column_names, rows) = data_transfer
+            .run_sql(String::from("SELECT id, name FROM users ORDER BY id"))
+            .await
+            .unwrap();
+        assert_eq!(column_names, vec!["id", "name"]);
+        assert_eq!(
+            rows,
+            vec![
+                vec![Some(String::from("1")), Some(String::from("Smith, \"JJ\""))],
+                vec![Some(String::from("2")), None],
+                vec![Some(String::from("3")), Some(String::new())],
+            ]
+        );
+
+        // a row that doesn't fit leaves the table as it was
+        fs::write(&path, "id,name\n4,Dan\nfive,Eve\n")
+            .await
+            .unwrap();
+        assert!(data_transfer
+            .import_csv(String::from("users"), path.clone())
+            .await
+            .is_err());
+        let viewer_data_transfer = DataTransfer::new(
+            repository,
+            console,
+            create_session(BRole::Viewer),
+            tables_general_info,
+        );
+        assert!(viewer_data_transfer
+            .import_csv(String::from("users"), path.clone())
+            .await
+            .is_err());
+        assert!(viewer_data_transfer
+            .run_sql(String::from("SELECT 1"))
+            .await
+            .is_err());
+        let (_, rows) = data_transfer
+            .run_sql(String::from("SELECT COUNT(*) FROM users"))
+            .await
+            .unwrap();
+        assert_eq!(rows, vec![vec![Some(String::from("3"))]]);
+        fs::remove_file(&path).await.unwrap();
+    }
+
+    #[test]
+    fn test_parse_csv_header() {
+        assert_eq!(
+            parse_csv_header("id,name\r\n1,John\r\n"),
+            vec![String::from("id"), String::from("name")]
+        );
+        assert_eq!(
+            parse_csv_header("\"id\",\"last, first\",\"say \"\"hi\"\"\",\n"),
+            vec![
+                String::from("id"),
+                String::from("last, first"),
+                String::from("say \"hi\""),
+                String::new(),
+            ]
+        );
+        assert!(parse_csv_header("").is_empty());
+    }
+}
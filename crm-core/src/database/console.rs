@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use tokio::sync::Mutex as AsyncMutex;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct RepositoryConsole {
     pub messages: Arc<AsyncMutex<Vec<String>>>,
 }
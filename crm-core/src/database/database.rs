@@ -5,9 +5,12 @@ use std::env;
 pub async fn create_database_pool() -> PgPool {
     dotenv().ok();
     let database_url = env::var("DATABASE_URL").expect("Env variable: DATABASE_URL must be set");
+    connect_database_pool(database_url.as_str()).await.unwrap()
+}
+
+pub async fn connect_database_pool(database_url: &str) -> Result<PgPool, sqlx::Error> {
     PgPoolOptions::new()
         .max_connections(5)
-        .connect(database_url.as_str())
+        .connect(database_url)
         .await
-        .unwrap()
 }
@@ -0,0 +1,124 @@
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct ColumnsInfo {
+    pub column_name: String,
+    pub data_type: String,
+    pub constraint_types: Vec<Option<String>>,
+    pub referenced_tables: Vec<Option<String>>,
+    pub referenced_columns: Vec<Option<String>>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct TableGeneralInfo {
+    pub table_name: String,
+    pub column_names: Vec<String>,
+    pub data_types: Vec<String>,
+    pub is_unique: Vec<bool>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct PrimaryKeyConstraint {
+    pub conname: String,
+}
+
+// a column as the catalog stores it, generated and identity are pg_attribute's codes
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct ColumnDefinitionInfo {
+    pub column_name: String,
+    pub data_type: String,
+    pub is_not_null: bool,
+    pub default_expression: Option<String>,
+    pub generated: String,
+    pub identity: String,
+    pub has_sequence: bool,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct ConstraintDefinitionInfo {
+    pub conname: String,
+    pub definition: String,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct ForeignKeyConstraint {
+    pub column_name: String,
+    pub conname: String,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct ChartDefinitionInfo {
+    pub id: i32,
+    pub title: String,
+    pub chart_type: String,
+    pub table_name: String,
+    pub group_by_column: String,
+    pub aggregate: String,
+    pub aggregate_column: Option<String>,
+    pub time_bucket: Option<String>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct ColumnLayoutInfo {
+    pub table_name: String,
+    pub column_names: Vec<String>,
+    pub hidden_column_names: Vec<String>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct ComputedColumnInfo {
+    pub table_name: String,
+    pub column_name: String,
+    pub expression: String,
+    pub data_type: String,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct NodePositionInfo {
+    pub table_name: String,
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct TableViewInfo {
+    pub id: i32,
+    pub table_name: String,
+    pub view_name: String,
+    pub column_names: Vec<String>,
+    pub filter_columns: Vec<String>,
+    pub filter_operators: Vec<String>,
+    pub filter_values: Vec<String>,
+    pub sort_columns: Vec<String>,
+    pub sort_directions: Vec<String>,
+    pub is_default: bool,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct AuditEntryInfo {
+    pub id: i32,
+    pub user_name: String,
+    pub changed_at: String,
+    pub table_name: String,
+    pub operation: String,
+    pub primary_key_column_names: Vec<String>,
+    pub primary_key_values: Vec<Option<String>>,
+    pub column_names: Vec<String>,
+    pub old_values: Option<Vec<Option<String>>>,
+    pub new_values: Option<Vec<Option<String>>>,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct UserInfo {
+    pub id: i32,
+    pub user_name: String,
+    pub password_hash: String,
+    pub role: String,
+}
+
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct AccessRuleInfo {
+    pub id: i32,
+    pub role: String,
+    pub table_name: String,
+    pub column_name: Option<String>,
+    pub access_level: String,
+}
@@ -0,0 +1,2322 @@
+use crate::database::{
+    console::RepositoryConsole,
+    database::{connect_database_pool, create_database_pool},
+    models::{
+        AccessRuleInfo, AuditEntryInfo, ChartDefinitionInfo, ColumnDefinitionInfo,
+        ColumnLayoutInfo, ColumnsInfo, ComputedColumnInfo, ConstraintDefinitionInfo,
+        ForeignKeyConstraint, NodePositionInfo, PrimaryKeyConstraint, TableGeneralInfo,
+        TableViewInfo, UserInfo,
+    },
+    schemas::{
+        AccessRule, Aggregate, AuditFilter, AuditOperation, BackupArchive, BackupProgress,
+        ChartDefinition, ChartPoint, ColumnConversion, ColumnLayout, ComputedColumn, Condition,
+        Constraint, DataType, FilterOperator, ForeignKeyReference, NodePosition, Role, RowMerge,
+        SchemaPlan, TableChange, TableChangeEvents, TableDataChangeEvents, TableIn, TableView,
+        ViewFilter, ViewSort,
+    },
+};
+use futures::{channel::mpsc::UnboundedSender, StreamExt};
+use sqlx::{
+    postgres::{PgListener, PgRow},
+    Column as _, PgPool, Postgres, Row, Transaction,
+};
+use std::collections::HashMap;
+use std::iter::zip;
+use std::sync::{Arc, Mutex};
+use tokio::task;
+
+// notification channels, the row channel's payload is the changed table's name
+const TABLE_ROWS_CHANNEL: &str = "crm_table_rows";
+const SCHEMA_CHANNEL: &str = "crm_schema";
+
+#[derive(Debug, Clone)]
+pub struct Repository {
+    pool: PgPool,
+    console: Arc<RepositoryConsole>,
+    // logged in user, recorded in the audit log
+    user_name: Arc<Mutex<Option<String>>>,
+}
+
+impl Repository {
+    pub async fn new(existing_pool: Option<PgPool>, console: Arc<RepositoryConsole>) -> Self {
+        let pool = if let Some(pool) = existing_pool {
+            pool
+        } else {
+            create_database_pool().await
+        };
+        Self {
+            pool,
+            console,
+            user_name: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    // a second database, e.g. staging when comparing schemas
+    pub async fn connect(
+        database_url: &str,
+        console: Arc<RepositoryConsole>,
+    ) -> Result<Self, sqlx::Error> {
+        let pool = connect_database_pool(database_url).await?;
+        Ok(Self::new(Some(pool), console).await)
+    }
+
+    pub async fn close(&self) {
+        self.pool.close().await;
+    }
+
+    pub fn set_user_name(&self, user_name: String) {
+        *self.user_name.lock().unwrap() = Some(user_name);
+    }
+
+    async fn log_query(&self, query: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(query);
+        })
+        .await
+        .unwrap();
+    }
+
+    pub async fn get_primary_key_column_names(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        let query = r#"
+                            SELECT kcu.column_name
+                            FROM information_schema.table_constraints AS tc
+                            JOIN information_schema.key_column_usage AS kcu
+                            ON tc.constraint_name = kcu.constraint_name
+                            AND tc.table_name = kcu.table_name
+                            WHERE tc.constraint_type = 'PRIMARY KEY'
+                            AND tc.table_schema = 'public'
+                            AND tc.table_name = $1
+                         "#;
+
+        let primary_key_column_names: Vec<String> = sqlx::query(query)
+            .bind(table_name)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|row| row.get("column_name"))
+            .collect();
+        Ok(primary_key_column_names)
+    }
+
+    // GENERATED ALWAYS columns, postgres computes them and rejects written values
+    pub async fn get_generated_column_names(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        let query = "SELECT column_name::TEXT
+                     FROM information_schema.columns
+                     WHERE table_name = $1 AND table_schema = 'public' AND is_generated = 'ALWAYS'
+                     ORDER BY ordinal_position";
+        sqlx::query_scalar(query)
+            .bind(table_name)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    // generated and GENERATED ALWAYS identity columns, inserts can't set either of them
+    pub async fn get_always_generated_column_names(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        let query = "SELECT column_name::TEXT
+                     FROM information_schema.columns
+                     WHERE table_name = $1 AND table_schema = 'public'
+                     AND (is_generated = 'ALWAYS' OR identity_generation = 'ALWAYS')
+                     ORDER BY ordinal_position";
+        sqlx::query_scalar(query)
+            .bind(table_name)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn get_general_tables_info(&self) -> Result<Vec<TableGeneralInfo>, sqlx::Error> {
+        let query = "
+        SELECT
+            t.table_name,
+            array_agg(c.column_name::TEXT ORDER BY c.ordinal_position) AS column_names,
+            array_agg(c.data_type::TEXT ORDER BY c.ordinal_position) AS data_types,
+            array_agg(
+                CASE 
+                    WHEN u.column_name IS NOT NULL THEN true 
+                    ELSE false 
+                END
+                ORDER BY c.ordinal_position
+            ) AS is_unique
+        FROM
+            information_schema.tables t
+        INNER JOIN
+            information_schema.columns c
+        ON
+            t.table_name = c.table_name AND t.table_schema = c.table_schema
+        LEFT JOIN (
+            SELECT
+                tc.table_name,
+                kcu.column_name
+            FROM
+                information_schema.table_constraints tc
+            INNER JOIN
+                information_schema.key_column_usage kcu
+            ON
+                tc.constraint_name = kcu.constraint_name
+                AND tc.table_name = kcu.table_name
+            WHERE
+                tc.constraint_type IN ('UNIQUE', 'PRIMARY KEY')
+        ) u
+        ON
+            c.table_name = u.table_name AND c.column_name = u.column_name
+        WHERE
+            t.table_schema = 'public'
+            AND t.table_type = 'BASE TABLE'
+        GROUP BY
+            t.table_name";
+        let res = sqlx::query_as::<_, TableGeneralInfo>(query)
+            .fetch_all(&self.pool)
+            .await;
+        res
+    }
+
+    pub async fn get_columns_info(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<ColumnsInfo>, sqlx::Error> {
+        let query = "SELECT
+                            c.column_name,
+                            c.data_type,
+                            ARRAY_AGG(tc.constraint_type::TEXT) AS constraint_types,
+                            ARRAY_AGG(ccu.table_name::TEXT) AS referenced_tables,
+                            ARRAY_AGG(ccu.column_name::TEXT) AS referenced_columns
+                        FROM
+                            information_schema.columns AS c
+                        LEFT JOIN
+                            information_schema.key_column_usage AS kcu
+                            ON c.table_name = kcu.table_name
+                            AND c.column_name = kcu.column_name
+                        LEFT JOIN
+                            information_schema.table_constraints AS tc
+                            ON tc.constraint_name = kcu.constraint_name
+                            AND tc.table_name = c.table_name
+                        LEFT JOIN
+                            information_schema.referential_constraints AS rc
+                            ON rc.constraint_name = tc.constraint_name
+                        LEFT JOIN
+                            information_schema.constraint_column_usage AS ccu
+                            ON ccu.constraint_name = rc.unique_constraint_name
+                        WHERE
+                            c.table_name = $1
+                            AND c.table_schema = 'public'
+                        GROUP BY c.column_name, c.data_type, c.ordinal_position
+                        ORDER BY c.ordinal_position";
+        let parameters = (table_name,);
+
+        let res = sqlx::query_as::<_, ColumnsInfo>(query)
+            .bind(parameters.0)
+            .fetch_all(&self.pool)
+            .await;
+        res
+    }
+
+    pub async fn get_primary_key_constraint(
+        &self,
+        table_name: &str,
+    ) -> Result<Option<PrimaryKeyConstraint>, sqlx::Error> {
+        let query = "SELECT c.conname
+                FROM pg_catalog.pg_constraint c
+                JOIN pg_class t ON t.oid = c.conrelid
+                JOIN pg_namespace n ON n.oid = t.relnamespace
+                WHERE t.relname = $1 AND n.nspname = 'public' AND c.contype ='p'";
+        let res = sqlx::query_as::<_, PrimaryKeyConstraint>(query)
+            .bind(table_name)
+            .fetch_optional(&self.pool)
+            .await;
+        res
+    }
+
+    // the names postgres gave the single column foreign keys, e.g. orders_user_id_fkey
+    pub async fn get_foreign_key_constraints(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<ForeignKeyConstraint>, sqlx::Error> {
+        let query = "SELECT a.attname::TEXT AS column_name, c.conname::TEXT AS conname
+                FROM pg_catalog.pg_constraint c
+                JOIN pg_class t ON t.oid = c.conrelid
+                JOIN pg_namespace n ON n.oid = t.relnamespace
+                JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = c.conkey[1]
+                WHERE t.relname = $1 AND n.nspname = 'public' AND c.contype = 'f'
+                ORDER BY c.conname";
+        sqlx::query_as::<_, ForeignKeyConstraint>(query)
+            .bind(table_name)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn create_table(&self, table_in: &TableIn) {
+        let query = Self::create_table_query(table_in);
+
+        // Execute the query
+        sqlx::query(&query).execute(&self.pool).await.unwrap();
+        self.log_query(query).await;
+        self.install_table_change_trigger(&table_in.table_name)
+            .await
+            .unwrap();
+        self.notify_schema_change().await.unwrap();
+    }
+
+    pub fn create_table_query(table_in: &TableIn) -> String {
+        let mut primary_key_columns = vec![];
+
+        let columns_query_list: Vec<String> = table_in
+            .columns
+            .iter()
+            .map(|column| {
+                let mut column_configuration =
+                    vec![format!("\"{}\" {}", column.name, column.datatype)];
+                for constraint in &column.constraints {
+                    match constraint {
+                        Constraint::ForeignKey(referenced_table, referenced_column) => {
+                            column_configuration.push(format!(
+                                "REFERENCES \"{}\"(\"{}\")",
+                                referenced_table, referenced_column
+                            ));
+                        }
+                        Constraint::PrimaryKey => {
+                            primary_key_columns.push(column.name.clone());
+                        }
+                    }
+                }
+                column_configuration.join(" ")
+            })
+            .collect();
+
+        // If there are primary keys, append the PRIMARY KEY constraint
+        let mut full_query_list = columns_query_list.clone();
+        if !primary_key_columns.is_empty() {
+            full_query_list.push(format!(
+                "PRIMARY KEY ({})",
+                primary_key_columns
+                    .iter()
+                    .map(|col| format!("\"{}\"", col))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        let columns_query_joined = format!("({})", full_query_list.join(", "));
+
+        // Construct the full SQL query
+        format!(
+            "CREATE TABLE \"{}\" {}",
+            table_in.table_name, columns_query_joined
+        )
+    }
+
+    pub async fn delete_table(&self, table_name: &str) {
+        let query = Self::delete_table_query(table_name);
+        sqlx::query(&query).execute(&self.pool).await.unwrap();
+        self.log_query(query).await;
+        self.notify_schema_change().await.unwrap();
+    }
+
+    pub fn delete_table_query(table_name: &str) -> String {
+        format!("DROP TABLE \"{}\"", table_name)
+    }
+
+    /* LIKE copies the columns, defaults, NOT NULL and the primary key, the
+     * foreign keys are added from the definitions of the source table */
+    pub async fn duplicate_table(
+        &self,
+        source_table_name: &str,
+        table_name: &str,
+        with_data: bool,
+    ) -> Result<(), sqlx::Error> {
+        let foreign_key_definitions: Vec<String> = sqlx::query_scalar(
+            "SELECT pg_get_constraintdef(c.oid)
+                FROM pg_catalog.pg_constraint c
+                JOIN pg_class t ON t.oid = c.conrelid
+                JOIN pg_namespace n ON n.oid = t.relnamespace
+                WHERE t.relname = $1 AND n.nspname = 'public' AND c.contype = 'f'",
+        )
+        .bind(source_table_name)
+        .fetch_all(&self.pool)
+        .await?;
+        let serial_column_names: Vec<String> = sqlx::query_scalar(
+            "SELECT column_name::TEXT
+                FROM information_schema.columns
+                WHERE table_schema = 'public' AND table_name = $1
+                AND column_default LIKE 'nextval(%'",
+        )
+        .bind(source_table_name)
+        .fetch_all(&self.pool)
+        .await?;
+        let identity_column_names: Vec<String> = sqlx::query_scalar(
+            "SELECT column_name::TEXT
+                FROM information_schema.columns
+                WHERE table_schema = 'public' AND table_name = $1 AND is_identity = 'YES'",
+        )
+        .bind(source_table_name)
+        .fetch_all(&self.pool)
+        .await?;
+        let generated_column_names = self.get_generated_column_names(source_table_name).await?;
+        let inserted_column_names: Vec<String> = self
+            .get_column_names(source_table_name)
+            .await?
+            .into_iter()
+            .filter(|column_name| !generated_column_names.contains(column_name))
+            .map(|column_name| format!("\"{}\"", column_name))
+            .collect();
+
+        let mut queries = vec![format!(
+            "CREATE TABLE \"{}\" (LIKE \"{}\" INCLUDING DEFAULTS INCLUDING CONSTRAINTS \
+                INCLUDING INDEXES INCLUDING GENERATED INCLUDING IDENTITY)",
+            table_name, source_table_name
+        )];
+        queries.extend(
+            foreign_key_definitions
+                .iter()
+                .map(|definition| format!("ALTER TABLE \"{}\" ADD {}", table_name, definition)),
+        );
+        // copied serial defaults would keep drawing from the sequence of the source
+        for column_name in &serial_column_names {
+            let sequence_name = self
+                .get_free_relation_name(&format!("{}_{}_seq", table_name, column_name))
+                .await?;
+            queries.push(format!(
+                "CREATE SEQUENCE \"{}\" OWNED BY \"{}\".\"{}\"",
+                sequence_name, table_name, column_name
+            ));
+            queries.push(format!(
+                "ALTER TABLE \"{}\" ALTER COLUMN \"{}\" SET DEFAULT nextval('\"{}\"')",
+                table_name, column_name, sequence_name
+            ));
+        }
+        if with_data {
+            queries.push(format!(
+                "INSERT INTO \"{}\" ({}) OVERRIDING SYSTEM VALUE SELECT {} FROM \"{}\"",
+                table_name,
+                inserted_column_names.join(", "),
+                inserted_column_names.join(", "),
+                source_table_name
+            ));
+            queries.extend(
+                serial_column_names
+                    .iter()
+                    .chain(&identity_column_names)
+                    .map(|column_name| Self::reset_sequence_query(table_name, column_name)),
+            );
+        }
+
+        let mut transaction = self.pool.begin().await?;
+        for query in &queries {
+            sqlx::query(query).execute(&mut *transaction).await?;
+        }
+        transaction.commit().await?;
+        for query in queries {
+            self.log_query(query).await;
+        }
+        self.install_table_change_trigger(table_name).await?;
+        self.notify_schema_change().await?;
+        Ok(())
+    }
+
+    // a taken name gets a number appended, the way postgres names the sequence of a serial column
+    async fn get_free_relation_name(&self, name: &str) -> Result<String, sqlx::Error> {
+        let taken_names: Vec<String> = sqlx::query_scalar(
+            "SELECT c.relname::TEXT
+                FROM pg_class c
+                JOIN pg_namespace n ON n.oid = c.relnamespace
+                WHERE n.nspname = 'public' AND starts_with(c.relname, $1)",
+        )
+        .bind(name)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok((0..)
+            .map(|suffix| match suffix {
+                0 => name.to_string(),
+                _ => format!("{}{}", name, suffix),
+            })
+            .find(|candidate| !taken_names.contains(candidate))
+            .unwrap())
+    }
+
+    pub fn copy_query(table_name: &str, column_names: &Vec<String>, direction: &str) -> String {
+        format!(
+            "COPY \"{}\" ({}) {}",
+            table_name,
+            column_names
+                .iter()
+                .map(|column_name| format!("\"{}\"", column_name))
+                .collect::<Vec<String>>()
+                .join(", "),
+            direction
+        )
+    }
+
+    // the rows of a table in COPY text format
+    pub async fn copy_table_out(
+        &self,
+        table_name: &str,
+        column_names: &Vec<String>,
+    ) -> Result<String, sqlx::Error> {
+        self.copy_out(&Self::copy_query(table_name, column_names, "TO STDOUT"))
+            .await
+    }
+
+    // the rows of a table as CSV with a header line, NULL is unquoted and empty text quoted
+    pub async fn copy_table_csv_out(
+        &self,
+        table_name: &str,
+        column_names: &Vec<String>,
+    ) -> Result<String, sqlx::Error> {
+        self.copy_out(&Self::copy_query(
+            table_name,
+            column_names,
+            "TO STDOUT WITH (FORMAT csv, HEADER)",
+        ))
+        .await
+    }
+
+    async fn copy_out(&self, query: &str) -> Result<String, sqlx::Error> {
+        let mut connection = self.pool.acquire().await?;
+        let mut copy_stream = connection.copy_out_raw(query).await?;
+        let mut data = vec![];
+        while let Some(chunk) = copy_stream.next().await {
+            data.extend_from_slice(&chunk?);
+        }
+        Ok(String::from_utf8_lossy(&data).into_owned())
+    }
+
+    // one COPY statement, a row that doesn't fit its columns imports nothing
+    pub async fn copy_table_csv_in(
+        &self,
+        table_name: &str,
+        column_names: &Vec<String>,
+        data: &str,
+    ) -> Result<u64, sqlx::Error> {
+        let query = Self::copy_query(
+            table_name,
+            column_names,
+            "FROM STDIN WITH (FORMAT csv, HEADER)",
+        );
+        let mut connection = self.pool.acquire().await?;
+        let mut copy_in = connection.copy_in_raw(&query).await?;
+        copy_in.send(data.as_bytes()).await?;
+        let rows_count = copy_in.finish().await?;
+        self.log_query(query).await;
+        Ok(rows_count)
+    }
+
+    /* statements typed by the user, sent unprepared so several can be run at once
+     * and every value arrives as text */
+    pub async fn run_sql(
+        &self,
+        query: &str,
+    ) -> Result<(Vec<String>, Vec<Vec<Option<String>>>), sqlx::Error> {
+        let rows: Vec<PgRow> = sqlx::raw_sql(query).fetch_all(&self.pool).await?;
+        self.log_query(query.to_string()).await;
+        let column_names = rows
+            .first()
+            .map(|row| {
+                row.columns()
+                    .iter()
+                    .map(|column| column.name().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let rows = rows
+            .iter()
+            .map(|row| {
+                (0..row.len())
+                    .map(|column_index| row.try_get_unchecked::<Option<String>, _>(column_index))
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<Option<String>>>, sqlx::Error>>()?;
+        Ok((column_names, rows))
+    }
+
+    /* the CREATE TABLE statement of a live table read from the catalog, with its defaults,
+     * NOT NULL, keys, UNIQUE and CHECK constraints and generated columns; also returns the
+     * columns fed by a sequence, serial ones are declared SERIAL so it is created again */
+    pub async fn get_table_definition(
+        &self,
+        table_name: &str,
+    ) -> Result<(String, Vec<String>), sqlx::Error> {
+        let columns_query = "SELECT a.attname::TEXT AS column_name,
+                    format_type(a.atttypid, a.atttypmod) AS data_type,
+                    a.attnotnull AS is_not_null,
+                    pg_get_expr(d.adbin, d.adrelid) AS default_expression,
+                    a.attgenerated::TEXT AS generated,
+                    a.attidentity::TEXT AS identity,
+                    pg_get_serial_sequence(format('%I.%I', n.nspname, t.relname), a.attname)
+                        IS NOT NULL AS has_sequence
+                FROM pg_attribute a
+                JOIN pg_class t ON t.oid = a.attrelid
+                JOIN pg_namespace n ON n.oid = t.relnamespace
+                LEFT JOIN pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+                WHERE t.relname = $1 AND n.nspname = 'public'
+                    AND a.attnum > 0 AND NOT a.attisdropped
+                ORDER BY a.attnum";
+        let columns = sqlx::query_as::<_, ColumnDefinitionInfo>(columns_query)
+            .bind(table_name)
+            .fetch_all(&self.pool)
+            .await?;
+        let constraints_query = "SELECT c.conname::TEXT AS conname,
+                    pg_get_constraintdef(c.oid) AS definition
+                FROM pg_catalog.pg_constraint c
+                JOIN pg_class t ON t.oid = c.conrelid
+                JOIN pg_namespace n ON n.oid = t.relnamespace
+                WHERE t.relname = $1 AND n.nspname = 'public' AND c.contype IN ('p', 'u', 'f', 'c')
+                ORDER BY c.contype DESC, c.conname";
+        let constraints = sqlx::query_as::<_, ConstraintDefinitionInfo>(constraints_query)
+            .bind(table_name)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let column_definitions = columns.iter().map(|column| {
+            let mut definition = format!("\"{}\" ", column.column_name);
+            match (column.identity.as_str(), column.generated.as_str()) {
+                ("a", _) => definition.push_str(&format!(
+                    "{} GENERATED ALWAYS AS IDENTITY",
+                    column.data_type
+                )),
+                ("d", _) => definition.push_str(&format!(
+                    "{} GENERATED BY DEFAULT AS IDENTITY",
+                    column.data_type
+                )),
+                (_, "s") => definition.push_str(&format!(
+                    "{} GENERATED ALWAYS AS ({}) STORED",
+                    column.data_type,
+                    column.default_expression.clone().unwrap_or_default()
+                )),
+                _ if column.has_sequence => {
+                    definition.push_str(match column.data_type.as_str() {
+                        "bigint" => "BIGSERIAL",
+                        "smallint" => "SMALLSERIAL",
+                        _ => "SERIAL",
+                    });
+                }
+                _ => {
+                    definition.push_str(&column.data_type);
+                    if let Some(default_expression) = &column.default_expression {
+                        definition.push_str(&format!(" DEFAULT {}", default_expression));
+                    }
+                    if column.is_not_null {
+                        definition.push_str(" NOT NULL");
+                    }
+                }
+            }
+            definition
+        });
+        let constraint_definitions = constraints.iter().map(|constraint| {
+            format!(
+                "CONSTRAINT \"{}\" {}",
+                constraint.conname, constraint.definition
+            )
+        });
+        let create_table_query = format!(
+            "CREATE TABLE \"{}\" ({})",
+            table_name,
+            column_definitions
+                .chain(constraint_definitions)
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        let sequence_column_names = columns
+            .into_iter()
+            .filter(|column| column.has_sequence)
+            .map(|column| column.column_name)
+            .collect();
+        Ok((create_table_query, sequence_column_names))
+    }
+
+    // moves the sequence past the restored rows, the same statement is written to backups
+    pub fn reset_sequence_query(table_name: &str, column_name: &str) -> String {
+        format!(
+            "SELECT setval(pg_get_serial_sequence('\"{}\"', '{}'), \
+                COALESCE(MAX(\"{}\"), 0) + 1, false) FROM \"{}\"",
+            table_name,
+            column_name.replace('\'', "''"),
+            column_name,
+            table_name
+        )
+    }
+
+    /* every table is created and loaded in one transaction, a failing table
+     * leaves the database as it was */
+    pub async fn restore_backup(
+        &self,
+        backup_archive: &BackupArchive,
+        progress: &UnboundedSender<BackupProgress>,
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        for backup_table in &backup_archive.tables {
+            sqlx::query(&backup_table.create_table_query)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        for (table_index, backup_table) in backup_archive.tables.iter().enumerate() {
+            let _ = progress.unbounded_send(BackupProgress {
+                table_name: backup_table.table_name.clone(),
+                completed_tables: table_index,
+                total_tables: backup_archive.tables.len(),
+            });
+            if backup_table.data.is_empty() {
+                continue;
+            }
+            let mut copy_in = transaction
+                .copy_in_raw(&Self::copy_query(
+                    &backup_table.table_name,
+                    &backup_table.column_names,
+                    "FROM STDIN",
+                ))
+                .await?;
+            copy_in.send(backup_table.data.as_bytes()).await?;
+            copy_in.finish().await?;
+        }
+        for backup_table in &backup_archive.tables {
+            for column_name in &backup_table.sequence_column_names {
+                sqlx::query(&Self::reset_sequence_query(
+                    &backup_table.table_name,
+                    column_name,
+                ))
+                .execute(&mut *transaction)
+                .await?;
+            }
+        }
+        transaction.commit().await?;
+
+        for backup_table in &backup_archive.tables {
+            self.log_query(backup_table.create_table_query.clone())
+                .await;
+            self.install_table_change_trigger(&backup_table.table_name)
+                .await?;
+        }
+        self.log_query(format!(
+            "COPY {} tables FROM STDIN",
+            backup_archive.tables.len()
+        ))
+        .await;
+        self.notify_schema_change().await?;
+        Ok(())
+    }
+
+    // pushes the value to be bound and returns its placeholder cast to the column type
+    fn bind_value(
+        values: &mut Vec<Option<String>>,
+        value: Option<String>,
+        data_type: &DataType,
+    ) -> String {
+        let cast_type = match data_type {
+            DataType::SERIAL => DataType::INTEGER,
+            data_type => data_type.clone(),
+        };
+        values.push(value);
+        format!("${}::{}", values.len(), cast_type)
+    }
+
+    fn get_filter_condition(
+        &self,
+        conditions: &Vec<Condition>,
+        values: &mut Vec<Option<String>>,
+    ) -> String {
+        conditions
+            .iter()
+            .map(|condition| match &condition.value {
+                Some(value) => format!(
+                    "\"{}\" = {}",
+                    condition.column_name,
+                    Self::bind_value(values, Some(value.clone()), &condition.data_type)
+                ),
+                None => format!("\"{}\" IS NULL", condition.column_name),
+            })
+            .collect::<Vec<String>>()
+            .join(" AND ")
+    }
+
+    fn get_updates(
+        &self,
+        updated_column_values: &HashMap<String, (DataType, Option<String>)>,
+        values: &mut Vec<Option<String>>,
+    ) -> String {
+        updated_column_values
+            .iter()
+            .map(|(column_name, (data_type, new_value))| {
+                // matches inserts, an emptied non text cell becomes NULL
+                let value = new_value
+                    .clone()
+                    .filter(|new_value| !new_value.is_empty() || *data_type == DataType::TEXT);
+                format!(
+                    "\"{}\" = {}",
+                    column_name,
+                    Self::bind_value(values, value, data_type)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    // every column as text so the audit log can store the whole row
+    fn row_values(column_names: &Vec<String>) -> String {
+        format!(
+            "ARRAY[{}]::TEXT[]",
+            column_names
+                .iter()
+                .map(|column_name| format!("\"{}\"::TEXT", column_name))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    pub async fn update_table_data(
+        &self,
+        table_name: &str,
+        table_data_change_events: &Vec<TableDataChangeEvents>,
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        let primary_key_column_names = self.get_primary_key_column_names(table_name).await?;
+        let column_names = self.get_column_names(table_name).await?;
+        let row_values = Self::row_values(&column_names);
+
+        for event in table_data_change_events {
+            match event {
+                TableDataChangeEvents::ModifyRowColumnValue(row_column_value) => {
+                    let mut values = vec![];
+                    let filter_condition =
+                        self.get_filter_condition(&row_column_value.conditions, &mut values);
+                    let condition_value_count = values.len();
+                    let updates = self.get_updates(&row_column_value.column_values, &mut values);
+                    let query = format!(
+                        "UPDATE \"{}\" SET {} WHERE {}",
+                        table_name, // Table for the update
+                        updates,
+                        filter_condition
+                    );
+
+                    let select_query = format!(
+                        "SELECT {} FROM \"{}\" WHERE {}",
+                        row_values, table_name, filter_condition
+                    );
+                    let mut old_rows_query = sqlx::query_scalar(&select_query);
+                    for value in &values[..condition_value_count] {
+                        old_rows_query = old_rows_query.bind(value);
+                    }
+                    let old_rows: Vec<Vec<Option<String>>> =
+                        old_rows_query.fetch_all(&mut *transaction).await?;
+                    let returning_query = format!("{} RETURNING {}", query, row_values);
+                    let mut new_rows_query = sqlx::query_scalar(&returning_query);
+                    for value in &values {
+                        new_rows_query = new_rows_query.bind(value);
+                    }
+                    let new_rows: Vec<Vec<Option<String>>> =
+                        new_rows_query.fetch_all(&mut *transaction).await?;
+                    self.log_query(query).await;
+                    // conditions address a single row, so old and new rows pair up
+                    for (old_row, new_row) in zip(old_rows, new_rows) {
+                        self.insert_audit_entry(
+                            &mut transaction,
+                            table_name,
+                            AuditOperation::Update,
+                            &primary_key_column_names,
+                            &column_names,
+                            Some(old_row),
+                            Some(new_row),
+                        )
+                        .await?;
+                    }
+                }
+
+                TableDataChangeEvents::DeleteRow(conditions) => {
+                    let mut values = vec![];
+                    let filter_condition = self.get_filter_condition(conditions, &mut values);
+                    let query =
+                        format!("DELETE FROM \"{}\" WHERE {}", table_name, filter_condition);
+                    let returning_query = format!("{} RETURNING {}", query, row_values);
+                    let mut old_rows_query = sqlx::query_scalar(&returning_query);
+                    for value in &values {
+                        old_rows_query = old_rows_query.bind(value);
+                    }
+                    let old_rows: Vec<Vec<Option<String>>> =
+                        old_rows_query.fetch_all(&mut *transaction).await?;
+                    self.log_query(query).await;
+                    for old_row in old_rows {
+                        self.insert_audit_entry(
+                            &mut transaction,
+                            table_name,
+                            AuditOperation::Delete,
+                            &primary_key_column_names,
+                            &column_names,
+                            Some(old_row),
+                            None,
+                        )
+                        .await?;
+                    }
+                }
+
+                TableDataChangeEvents::InsertRow(row_insert_data) => {
+                    let mut values = vec![];
+                    let (inserted_column_names, placeholders): (Vec<String>, Vec<String>) =
+                        row_insert_data
+                            .column_names
+                            .iter()
+                            .zip(
+                                row_insert_data
+                                    .values
+                                    .iter()
+                                    .zip(row_insert_data.data_types.iter()),
+                            )
+                            .map(|(column_name, (value, data_type))| {
+                                // Map the filtered columns to (column_name, value) pairs
+                                if value.is_empty()
+                                    && primary_key_column_names.contains(column_name)
+                                {
+                                    // Generate values for primary key columns
+                                    let generated_value = if *data_type == DataType::INTEGER {
+                                        format!(
+                                            "(SELECT COALESCE(MAX(\"{}\"), 0) + 1 FROM \"{}\")",
+                                            column_name, table_name
+                                        )
+                                    } else if *data_type == DataType::TEXT {
+                                        "gen_random_uuid()::TEXT".to_string()
+                                    } else {
+                                        "NULL".to_string() // Fallback for unsupported types
+                                    };
+
+                                    (column_name.to_string(), generated_value)
+                                } else {
+                                    (
+                                        column_name.to_string(),
+                                        Self::bind_value(
+                                            &mut values,
+                                            (!value.is_empty()).then(|| value.to_string()),
+                                            data_type,
+                                        ),
+                                    )
+                                }
+                            })
+                            .unzip();
+                    let query = format!(
+                        "INSERT INTO \"{}\" ({}) VALUES ({})",
+                        table_name,
+                        inserted_column_names.join(", "),
+                        placeholders.join(", ")
+                    );
+
+                    let returning_query = format!("{} RETURNING {}", query, row_values);
+                    let mut new_rows_query = sqlx::query_scalar(&returning_query);
+                    for value in &values {
+                        new_rows_query = new_rows_query.bind(value);
+                    }
+                    let new_rows: Vec<Vec<Option<String>>> =
+                        new_rows_query.fetch_all(&mut *transaction).await?;
+                    self.log_query(query).await;
+                    for new_row in new_rows {
+                        self.insert_audit_entry(
+                            &mut transaction,
+                            table_name,
+                            AuditOperation::Insert,
+                            &primary_key_column_names,
+                            &column_names,
+                            None,
+                            Some(new_row),
+                        )
+                        .await?;
+                    }
+                }
+            }
+        }
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn get_column_names(&self, table_name: &str) -> Result<Vec<String>, sqlx::Error> {
+        let query = "SELECT column_name::TEXT
+                     FROM information_schema.columns
+                     WHERE table_name = $1 AND table_schema = 'public'
+                     ORDER BY ordinal_position";
+        sqlx::query_scalar(query)
+            .bind(table_name)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    async fn insert_audit_entry(
+        &self,
+        transaction: &mut Transaction<'_, Postgres>,
+        table_name: &str,
+        operation: AuditOperation,
+        primary_key_column_names: &Vec<String>,
+        column_names: &Vec<String>,
+        old_values: Option<Vec<Option<String>>>,
+        new_values: Option<Vec<Option<String>>>,
+    ) -> Result<(), sqlx::Error> {
+        // the row is identified by its current key, the old one only after a delete
+        let identifying_values = new_values.as_ref().or(old_values.as_ref()).unwrap();
+        let primary_key_values: Vec<Option<String>> = primary_key_column_names
+            .iter()
+            .filter_map(|primary_key_column_name| {
+                column_names
+                    .iter()
+                    .position(|column_name| column_name == primary_key_column_name)
+                    .map(|index| identifying_values[index].clone())
+            })
+            .collect();
+        let query = "INSERT INTO crm_metadata.audit_log
+                        (user_name, table_name, operation, primary_key_column_names,
+                         primary_key_values, column_names, old_values, new_values)
+                     VALUES (COALESCE($1, CURRENT_USER), $2, $3, $4, $5, $6, $7, $8)";
+        let user_name = self.user_name.lock().unwrap().clone();
+        sqlx::query(query)
+            .bind(user_name)
+            .bind(table_name)
+            .bind(operation.to_string())
+            .bind(primary_key_column_names)
+            .bind(primary_key_values)
+            .bind(column_names)
+            .bind(old_values)
+            .bind(new_values)
+            .execute(&mut **transaction)
+            .await?;
+        Ok(())
+    }
+
+    fn get_view_filter_condition(
+        &self,
+        column_names: &Vec<String>,
+        data_types: &Vec<DataType>,
+        filters: &Vec<ViewFilter>,
+        filter_values: &mut Vec<String>,
+    ) -> Option<String> {
+        let conditions: Vec<String> = filters
+            .iter()
+            .filter_map(|filter| {
+                // filters on columns that no longer exist are ignored
+                let column_index = column_names
+                    .iter()
+                    .position(|column_name| *column_name == filter.column_name)?;
+                let cast_type = match data_types[column_index] {
+                    DataType::SERIAL => DataType::INTEGER,
+                    ref data_type => data_type.clone(),
+                };
+                let mut bind_value = |value: &str| {
+                    filter_values.push(value.to_string());
+                    format!("${}", filter_values.len())
+                };
+                let condition = match filter.operator {
+                    FilterOperator::Equals => format!(
+                        "\"{}\" = {}::{}",
+                        filter.column_name,
+                        bind_value(&filter.value),
+                        cast_type
+                    ),
+                    FilterOperator::NotEquals => format!(
+                        "\"{}\" IS DISTINCT FROM {}::{}",
+                        filter.column_name,
+                        bind_value(&filter.value),
+                        cast_type
+                    ),
+                    FilterOperator::Contains => format!(
+                        "\"{}\"::TEXT ILIKE '%' || {} || '%'",
+                        filter.column_name,
+                        bind_value(&filter.value)
+                    ),
+                    FilterOperator::GreaterThan => format!(
+                        "\"{}\" > {}::{}",
+                        filter.column_name,
+                        bind_value(&filter.value),
+                        cast_type
+                    ),
+                    FilterOperator::LessThan => format!(
+                        "\"{}\" < {}::{}",
+                        filter.column_name,
+                        bind_value(&filter.value),
+                        cast_type
+                    ),
+                    FilterOperator::IsEmpty => format!(
+                        "(\"{}\" IS NULL OR \"{}\"::TEXT = '')",
+                        filter.column_name, filter.column_name
+                    ),
+                };
+                Some(condition)
+            })
+            .collect();
+        if conditions.is_empty() {
+            None
+        } else {
+            Some(conditions.join(" AND "))
+        }
+    }
+
+    pub async fn get_table_data_rows(
+        &self,
+        table_name: &str,
+        computed_columns: &Vec<ComputedColumn>,
+        column_names: &Vec<String>,
+        data_types: &Vec<DataType>,
+        order_by_column_names: &Vec<String>,
+        filters: &Vec<ViewFilter>,
+        sorts: &Vec<ViewSort>,
+    ) -> Result<Vec<PgRow>, sqlx::Error> {
+        let select_column_names: Vec<String> = column_names
+            .iter()
+            .map(|column_name| {
+                format!(
+                    "COALESCE(\"{}\"::TEXT, '') AS \"{}\"",
+                    column_name, column_name
+                )
+            })
+            .collect();
+        // view sorts take precedence, primary keys keep the order stable
+        let order_by_columns: Vec<String> = sorts
+            .iter()
+            .filter(|sort| column_names.contains(&sort.column_name))
+            .map(|sort| format!("\"{}\" {}", sort.column_name, sort.direction))
+            .chain(
+                order_by_column_names
+                    .iter()
+                    .map(|column_name| format!("\"{}\"", column_name)),
+            )
+            .collect();
+        let mut filter_values = vec![];
+        // computed columns are named in a subquery so filters and sorts treat them as columns
+        let source = if computed_columns.is_empty() {
+            format!("\"{}\"", table_name)
+        } else {
+            let computed_select_expressions: Vec<String> = computed_columns
+                .iter()
+                .map(|computed_column| computed_column.select_expression())
+                .collect();
+            format!(
+                "(SELECT *, {} FROM \"{}\") AS \"{}\"",
+                computed_select_expressions.join(", "),
+                table_name,
+                table_name
+            )
+        };
+        let mut query = format!("SELECT {} FROM {}", select_column_names.join(", "), source);
+        if let Some(filter_condition) =
+            self.get_view_filter_condition(column_names, data_types, filters, &mut filter_values)
+        {
+            query = format!("{} WHERE {}", query, filter_condition);
+        }
+        if !order_by_columns.is_empty() {
+            query = format!("{} ORDER BY {}", query, order_by_columns.join(", "));
+        }
+        let mut table_data_query = sqlx::query(&query);
+        for filter_value in filter_values {
+            table_data_query = table_data_query.bind(filter_value);
+        }
+        let table_data_rows = table_data_query.fetch_all(&self.pool).await;
+        table_data_rows
+    }
+
+    // ILIKE on every searched column so pg_trgm GIN indexes are used where they exist
+    pub async fn search_table_data_rows(
+        &self,
+        table_name: &str,
+        column_names: &Vec<String>,
+        searched_column_names: &Vec<String>,
+        search_query: &str,
+        limit: i64,
+    ) -> Result<Vec<PgRow>, sqlx::Error> {
+        let select_column_names: Vec<String> = column_names
+            .iter()
+            .map(|column_name| {
+                format!(
+                    "COALESCE(\"{}\"::TEXT, '') AS \"{}\"",
+                    column_name, column_name
+                )
+            })
+            .collect();
+        let search_conditions: Vec<String> = searched_column_names
+            .iter()
+            .map(|column_name| format!("\"{}\" ILIKE $1", column_name))
+            .collect();
+        let query = format!(
+            "SELECT {} FROM \"{}\" WHERE {} LIMIT $2",
+            select_column_names.join(", "),
+            table_name,
+            search_conditions.join(" OR "),
+        );
+        // the search text is matched literally, not as a LIKE pattern
+        let escaped_search_query = search_query
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        sqlx::query(&query)
+            .bind(format!("%{}%", escaped_search_query))
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    // rewrites references to the losing rows, deletes them and then writes the
+    // merged values, so unique values taken from a loser no longer collide
+    pub async fn merge_rows(
+        &self,
+        row_merge: &RowMerge,
+        foreign_key_references: &Vec<ForeignKeyReference>,
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        let mut queries = vec![];
+
+        for losing_row_conditions in &row_merge.losing_rows_conditions {
+            for foreign_key_reference in foreign_key_references {
+                let mut values = vec![];
+                let updates = format!(
+                    "\"{}\" = (SELECT \"{}\" FROM \"{}\" WHERE {})",
+                    foreign_key_reference.column_name,
+                    foreign_key_reference.referenced_column,
+                    row_merge.table_name,
+                    self.get_filter_condition(&row_merge.surviving_row_conditions, &mut values),
+                );
+                let condition = format!(
+                    "\"{}\" IN (SELECT \"{}\" FROM \"{}\" WHERE {})",
+                    foreign_key_reference.column_name,
+                    foreign_key_reference.referenced_column,
+                    row_merge.table_name,
+                    self.get_filter_condition(losing_row_conditions, &mut values),
+                );
+                queries.push(
+                    self.execute_audited(
+                        &mut transaction,
+                        &foreign_key_reference.table_name,
+                        Some(&updates),
+                        &condition,
+                        &values,
+                    )
+                    .await?,
+                );
+            }
+            let mut values = vec![];
+            let condition = self.get_filter_condition(losing_row_conditions, &mut values);
+            queries.push(
+                self.execute_audited(
+                    &mut transaction,
+                    &row_merge.table_name,
+                    None,
+                    &condition,
+                    &values,
+                )
+                .await?,
+            );
+        }
+        if !row_merge.merged_column_values.is_empty() {
+            let mut values = vec![];
+            let updates = self.get_updates(&row_merge.merged_column_values, &mut values);
+            let condition =
+                self.get_filter_condition(&row_merge.surviving_row_conditions, &mut values);
+            queries.push(
+                self.execute_audited(
+                    &mut transaction,
+                    &row_merge.table_name,
+                    Some(&updates),
+                    &condition,
+                    &values,
+                )
+                .await?,
+            );
+        }
+        transaction.commit().await?;
+        for query in queries {
+            self.log_query(query).await;
+        }
+        Ok(())
+    }
+
+    // updates, or deletes without updates, the matching rows and audits each of them
+    async fn execute_audited(
+        &self,
+        transaction: &mut Transaction<'_, Postgres>,
+        table_name: &str,
+        updates: Option<&str>,
+        condition: &str,
+        values: &Vec<Option<String>>,
+    ) -> Result<String, sqlx::Error> {
+        let primary_key_column_names = self.get_primary_key_column_names(table_name).await?;
+        let column_names = self.get_column_names(table_name).await?;
+        let row_values = Self::row_values(&column_names);
+        match updates {
+            Some(updates) => {
+                let query = format!(
+                    "UPDATE \"{}\" SET {} WHERE {}",
+                    table_name, updates, condition
+                );
+                // old and new values pair up by ctid, a merge can repoint many referencing rows
+                let audited_query = format!(
+                    "WITH old_rows AS (SELECT ctid, {} AS old_values FROM \"{}\" WHERE {}) \
+                     UPDATE \"{}\" SET {} FROM old_rows WHERE \"{}\".ctid = old_rows.ctid \
+                     RETURNING old_rows.old_values, {}",
+                    row_values, table_name, condition, table_name, updates, table_name, row_values
+                );
+                let mut rows_query = sqlx::query(&audited_query);
+                for value in values {
+                    rows_query = rows_query.bind(value);
+                }
+                let rows = rows_query.fetch_all(&mut **transaction).await?;
+                for row in rows {
+                    let old_row: Vec<Option<String>> = row.get(0);
+                    let new_row: Vec<Option<String>> = row.get(1);
+                    self.insert_audit_entry(
+                        transaction,
+                        table_name,
+                        AuditOperation::Update,
+                        &primary_key_column_names,
+                        &column_names,
+                        Some(old_row),
+                        Some(new_row),
+                    )
+                    .await?;
+                }
+                Ok(query)
+            }
+            None => {
+                let query = format!("DELETE FROM \"{}\" WHERE {}", table_name, condition);
+                let returning_query = format!("{} RETURNING {}", query, row_values);
+                let mut old_rows_query = sqlx::query_scalar(&returning_query);
+                for value in values {
+                    old_rows_query = old_rows_query.bind(value);
+                }
+                let old_rows: Vec<Vec<Option<String>>> =
+                    old_rows_query.fetch_all(&mut **transaction).await?;
+                for old_row in old_rows {
+                    self.insert_audit_entry(
+                        transaction,
+                        table_name,
+                        AuditOperation::Delete,
+                        &primary_key_column_names,
+                        &column_names,
+                        Some(old_row),
+                        None,
+                    )
+                    .await?;
+                }
+                Ok(query)
+            }
+        }
+    }
+
+    pub async fn alter_table(
+        &self,
+        table_name: &str,
+        table_change_events: &Vec<TableChangeEvents>,
+        initial_primary_key_column_names: &Vec<String>,
+        column_conversions: &Vec<ColumnConversion>,
+    ) -> Result<(), sqlx::Error> {
+        if !column_conversions.is_empty() {
+            self.create_conversion_function().await?;
+        }
+        let primary_key_constraint = self.get_primary_key_constraint(table_name).await?;
+        let foreign_key_constraints = self.get_foreign_key_constraints(table_name).await?;
+        let queries = Self::alter_table_queries(
+            table_name,
+            table_change_events,
+            initial_primary_key_column_names,
+            primary_key_constraint.as_ref(),
+            &foreign_key_constraints,
+            column_conversions,
+        );
+
+        // Begin a transaction
+        let mut transaction: Transaction<'_, Postgres> = self.pool.begin().await?;
+
+        // Execute each query in the transaction
+        for query in queries {
+            sqlx::query(&query).execute(&mut *transaction).await?;
+            self.log_query(query).await;
+        }
+
+        // Commit the transaction
+        transaction.commit().await?;
+        self.notify_schema_change().await?;
+
+        Ok(())
+    }
+
+    // the whole plan runs in one transaction, a failing statement leaves the schema unchanged
+    pub async fn apply_schema_plan(&self, schema_plan: &SchemaPlan) -> Result<(), sqlx::Error> {
+        let mut queries: Vec<String> = schema_plan
+            .create_tables
+            .iter()
+            .map(Self::create_table_query)
+            .collect();
+        for table_alteration in &schema_plan.alter_tables {
+            let primary_key_constraint = self
+                .get_primary_key_constraint(&table_alteration.table_name)
+                .await?;
+            let foreign_key_constraints = self
+                .get_foreign_key_constraints(&table_alteration.table_name)
+                .await?;
+            queries.extend(Self::alter_table_queries(
+                &table_alteration.table_name,
+                &table_alteration.table_change_events,
+                &table_alteration.primary_key_column_names,
+                primary_key_constraint.as_ref(),
+                &foreign_key_constraints,
+                &vec![],
+            ));
+        }
+        let mut transaction = self.pool.begin().await?;
+        for query in &queries {
+            sqlx::query(query).execute(&mut *transaction).await?;
+        }
+        transaction.commit().await?;
+        for query in queries {
+            self.log_query(query).await;
+        }
+        for table_in in &schema_plan.create_tables {
+            self.install_table_change_trigger(&table_in.table_name)
+                .await?;
+        }
+        self.notify_schema_change().await?;
+        Ok(())
+    }
+
+    // the statements alter_table runs, also used to render migration scripts
+    pub fn alter_table_queries(
+        table_name: &str,
+        table_change_events: &Vec<TableChangeEvents>,
+        initial_primary_key_column_names: &Vec<String>,
+        primary_key_constraint: Option<&PrimaryKeyConstraint>,
+        foreign_key_constraints: &Vec<ForeignKeyConstraint>,
+        column_conversions: &Vec<ColumnConversion>,
+    ) -> Vec<String> {
+        let mut current_table_name = table_name.to_string();
+
+        let mut primary_key_columns = initial_primary_key_column_names.clone();
+        let mut run_drop_primary_constraint_query = true;
+        let mut queries = Vec::new();
+
+        for event in table_change_events {
+            match event {
+                TableChangeEvents::ChangeTableName(new_name) => {
+                    queries.push(format!(
+                        "ALTER TABLE \"{}\" RENAME TO \"{}\"",
+                        current_table_name, new_name
+                    ));
+                    current_table_name = new_name.clone();
+                }
+                TableChangeEvents::ChangeColumnDataType(column_name, new_data_type) => {
+                    let column_conversion = column_conversions
+                        .iter()
+                        .find(|column_conversion| {
+                            column_conversion.column_name == *column_name
+                                && column_conversion.data_type == *new_data_type
+                        })
+                        .cloned()
+                        .unwrap_or_else(|| {
+                            ColumnConversion::new(column_name.clone(), new_data_type.clone())
+                        });
+                    queries.push(format!(
+                        "ALTER TABLE \"{}\" ALTER COLUMN \"{}\" TYPE {} USING {}",
+                        current_table_name,
+                        column_name,
+                        new_data_type,
+                        column_conversion.using_expression()
+                    ));
+                }
+                TableChangeEvents::ChangeColumnName(old_name, new_name) => {
+                    queries.push(format!(
+                        "ALTER TABLE \"{}\" RENAME COLUMN \"{}\" TO \"{}\"",
+                        current_table_name, old_name, new_name
+                    ));
+                }
+                TableChangeEvents::AddColumn(column_name, data_type) => {
+                    queries.push(format!(
+                        "ALTER TABLE \"{}\" ADD COLUMN \"{}\" {}",
+                        current_table_name, column_name, data_type
+                    ));
+                }
+                TableChangeEvents::RemoveColumn(column_name) => {
+                    if let Some(existing_index) = primary_key_columns
+                        .iter()
+                        .position(|primary_key_column_name| primary_key_column_name == column_name)
+                    {
+                        // implicitly runs drop constraint query if primary key column is dropped
+                        run_drop_primary_constraint_query = false;
+                        primary_key_columns.remove(existing_index);
+                    }
+                    queries.push(format!(
+                        "ALTER TABLE \"{}\" DROP COLUMN \"{}\"",
+                        current_table_name, column_name
+                    ));
+                }
+                TableChangeEvents::AddForeignKey(column_foreign_key) => {
+                    queries.push(format!(
+                    "ALTER TABLE \"{}\" ADD CONSTRAINT fk_{}_{} FOREIGN KEY (\"{}\") REFERENCES \"{}\" (\"{}\")",
+                    current_table_name, current_table_name, column_foreign_key.column_name,
+                    column_foreign_key.column_name, column_foreign_key.referenced_table,
+                    column_foreign_key.referenced_column
+                ));
+                }
+                TableChangeEvents::RemoveForeignKey(column_name) => {
+                    for foreign_key_constraint in
+                        foreign_key_constraints
+                            .iter()
+                            .filter(|foreign_key_constraint| {
+                                foreign_key_constraint.column_name == *column_name
+                            })
+                    {
+                        queries.push(format!(
+                            "ALTER TABLE \"{}\" DROP CONSTRAINT \"{}\"",
+                            current_table_name, foreign_key_constraint.conname
+                        ));
+                    }
+                }
+                TableChangeEvents::AddPrimaryKey(column_name) => {
+                    primary_key_columns.push(column_name.clone());
+                }
+                TableChangeEvents::RemovePrimaryKey(column_name) => {
+                    if let Some(existing_index) = primary_key_columns
+                        .iter()
+                        .position(|existing_column_name| existing_column_name == column_name)
+                    {
+                        primary_key_columns.remove(existing_index);
+                    }
+                }
+                TableChangeEvents::AddGeneratedColumn(column_name, data_type, expression) => {
+                    queries.push(format!(
+                        "ALTER TABLE \"{}\" ADD COLUMN \"{}\" {} \
+                         GENERATED ALWAYS AS (({})::{}) STORED",
+                        current_table_name, column_name, data_type, expression, data_type
+                    ));
+                }
+            }
+        }
+
+        // Handle primary key changes separately
+        if *initial_primary_key_column_names != primary_key_columns {
+            if run_drop_primary_constraint_query {
+                if let Some(primary_key_constraint) = primary_key_constraint {
+                    let drop_query = format!(
+                        "ALTER TABLE \"{}\" DROP CONSTRAINT \"{}\"",
+                        current_table_name, primary_key_constraint.conname
+                    );
+                    queries.push(drop_query);
+                }
+            }
+            if !primary_key_columns.is_empty() {
+                let add_query = format!(
+                    "ALTER TABLE \"{}\" ADD CONSTRAINT pk_{} PRIMARY KEY ({})",
+                    current_table_name,
+                    current_table_name,
+                    primary_key_columns.join(", ")
+                );
+                queries.push(add_query);
+            }
+        }
+
+        queries
+    }
+
+    // true when the query of a column conversion accepts the value
+    async fn create_conversion_function(&self) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        sqlx::query(
+            "CREATE OR REPLACE FUNCTION crm_metadata.converts(
+                    value ANYELEMENT,
+                    conversion_query TEXT
+                ) RETURNS BOOLEAN AS $$
+                BEGIN
+                    EXECUTE conversion_query USING value;
+                    RETURN true;
+                EXCEPTION WHEN OTHERS THEN
+                    RETURN false;
+                END;
+                $$ LANGUAGE plpgsql",
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    // counts the rows a type change would fail on and samples their values
+    pub async fn preview_column_conversion(
+        &self,
+        table_name: &str,
+        database_column_name: &str,
+        column_conversion: &ColumnConversion,
+        sample_size: i64,
+    ) -> Result<(i64, Vec<Option<String>>), sqlx::Error> {
+        self.create_conversion_function().await?;
+        let query = format!(
+            "SELECT \"{}\"::TEXT AS value, COUNT(*) OVER () AS failed_row_count
+                FROM \"{}\"
+                WHERE NOT crm_metadata.converts(\"{}\", $1)
+                LIMIT $2",
+            database_column_name, table_name, database_column_name
+        );
+        let rows = sqlx::query(&query)
+            .bind(column_conversion.preview_query())
+            .bind(sample_size)
+            .fetch_all(&self.pool)
+            .await?;
+        let failed_row_count = rows.first().map_or(0, |row| row.get("failed_row_count"));
+        Ok((
+            failed_row_count,
+            rows.iter().map(|row| row.get("value")).collect(),
+        ))
+    }
+
+    // app metadata lives outside of the public schema so it never shows up
+    // in tables_general_info
+    async fn create_metadata_schema(&self) -> Result<(), sqlx::Error> {
+        sqlx::query("CREATE SCHEMA IF NOT EXISTS crm_metadata")
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn create_charts_table(&self) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        let query = "CREATE TABLE IF NOT EXISTS crm_metadata.charts (
+                        id SERIAL PRIMARY KEY,
+                        title TEXT NOT NULL,
+                        chart_type TEXT NOT NULL,
+                        table_name TEXT NOT NULL,
+                        group_by_column TEXT NOT NULL,
+                        aggregate TEXT NOT NULL,
+                        aggregate_column TEXT,
+                        time_bucket TEXT
+                    )";
+        sqlx::query(query).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    pub async fn get_chart_definitions(&self) -> Result<Vec<ChartDefinitionInfo>, sqlx::Error> {
+        let query = "SELECT id, title, chart_type, table_name, group_by_column, aggregate,
+                            aggregate_column, time_bucket
+                     FROM crm_metadata.charts
+                     ORDER BY id";
+        sqlx::query_as::<_, ChartDefinitionInfo>(query)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn insert_chart_definition(
+        &self,
+        chart_definition: &ChartDefinition,
+    ) -> Result<(), sqlx::Error> {
+        let query = "INSERT INTO crm_metadata.charts
+                        (title, chart_type, table_name, group_by_column, aggregate,
+                         aggregate_column, time_bucket)
+                     VALUES ($1, $2, $3, $4, $5, $6, $7)";
+        sqlx::query(query)
+            .bind(&chart_definition.title)
+            .bind(chart_definition.chart_type.to_string())
+            .bind(&chart_definition.table_name)
+            .bind(&chart_definition.group_by_column)
+            .bind(chart_definition.aggregate.to_string())
+            .bind(&chart_definition.aggregate_column)
+            .bind(
+                chart_definition
+                    .time_bucket
+                    .as_ref()
+                    .map(|time_bucket| time_bucket.to_string()),
+            )
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "INSERT INTO crm_metadata.charts chart \"{}\"",
+            chart_definition.title
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn delete_chart_definition(&self, chart_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM crm_metadata.charts WHERE id = $1")
+            .bind(chart_id)
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "DELETE FROM crm_metadata.charts WHERE id = {}",
+            chart_id
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn create_diagram_positions_table(&self) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        let query = "CREATE TABLE IF NOT EXISTS crm_metadata.diagram_positions (
+                        table_name TEXT PRIMARY KEY,
+                        x REAL NOT NULL,
+                        y REAL NOT NULL
+                    )";
+        sqlx::query(query).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    pub async fn get_node_positions(&self) -> Result<Vec<NodePositionInfo>, sqlx::Error> {
+        let query = "SELECT table_name, x, y
+                     FROM crm_metadata.diagram_positions
+                     ORDER BY table_name";
+        sqlx::query_as::<_, NodePositionInfo>(query)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn save_node_positions(
+        &self,
+        node_positions: &Vec<NodePosition>,
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        for node_position in node_positions {
+            sqlx::query(
+                "INSERT INTO crm_metadata.diagram_positions (table_name, x, y)
+                 VALUES ($1, $2, $3)
+                 ON CONFLICT (table_name) DO UPDATE SET x = EXCLUDED.x, y = EXCLUDED.y",
+            )
+            .bind(&node_position.table_name)
+            .bind(node_position.x)
+            .bind(node_position.y)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        self.log_query(format!(
+            "INSERT INTO crm_metadata.diagram_positions {} positions",
+            node_positions.len()
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn create_column_layouts_table(&self) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        let query = "CREATE TABLE IF NOT EXISTS crm_metadata.column_layouts (
+                        table_name TEXT PRIMARY KEY,
+                        column_names TEXT[] NOT NULL DEFAULT '{}',
+                        hidden_column_names TEXT[] NOT NULL DEFAULT '{}'
+                    )";
+        sqlx::query(query).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    pub async fn get_column_layouts(&self) -> Result<Vec<ColumnLayoutInfo>, sqlx::Error> {
+        let query = "SELECT table_name, column_names, hidden_column_names
+                     FROM crm_metadata.column_layouts
+                     ORDER BY table_name";
+        sqlx::query_as::<_, ColumnLayoutInfo>(query)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn get_column_layout(
+        &self,
+        table_name: &str,
+    ) -> Result<Option<ColumnLayoutInfo>, sqlx::Error> {
+        let query = "SELECT table_name, column_names, hidden_column_names
+                     FROM crm_metadata.column_layouts
+                     WHERE table_name = $1";
+        sqlx::query_as::<_, ColumnLayoutInfo>(query)
+            .bind(table_name)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn save_column_layout(
+        &self,
+        column_layout: &ColumnLayout,
+    ) -> Result<(), sqlx::Error> {
+        let query = "INSERT INTO crm_metadata.column_layouts
+                        (table_name, column_names, hidden_column_names)
+                     VALUES ($1, $2, $3)
+                     ON CONFLICT (table_name) DO UPDATE SET
+                        column_names = EXCLUDED.column_names,
+                        hidden_column_names = EXCLUDED.hidden_column_names";
+        sqlx::query(query)
+            .bind(&column_layout.table_name)
+            .bind(&column_layout.column_names)
+            .bind(&column_layout.hidden_column_names)
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "INSERT INTO crm_metadata.column_layouts {}",
+            column_layout.table_name
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn delete_column_layout(&self, table_name: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM crm_metadata.column_layouts WHERE table_name = $1")
+            .bind(table_name)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn create_computed_columns_table(&self) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        // regtype turns the type names the app writes into the ones information_schema uses
+        let query = "CREATE TABLE IF NOT EXISTS crm_metadata.computed_columns (
+                        table_name TEXT NOT NULL,
+                        column_name TEXT NOT NULL,
+                        expression TEXT NOT NULL,
+                        data_type REGTYPE NOT NULL,
+                        PRIMARY KEY (table_name, column_name)
+                    )";
+        sqlx::query(query).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    pub async fn get_computed_columns(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<ComputedColumnInfo>, sqlx::Error> {
+        let query = "SELECT table_name, column_name, expression, data_type::TEXT AS data_type
+                     FROM crm_metadata.computed_columns
+                     WHERE table_name = $1
+                     ORDER BY column_name";
+        sqlx::query_as::<_, ComputedColumnInfo>(query)
+            .bind(table_name)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    // the expression is planned against the table, so unknown columns or types fail here
+    pub async fn save_computed_column(
+        &self,
+        computed_column: &ComputedColumn,
+    ) -> Result<(), sqlx::Error> {
+        let check_query = format!(
+            "SELECT {} FROM \"{}\" LIMIT 0",
+            computed_column.select_expression(),
+            computed_column.table_name
+        );
+        sqlx::query(&check_query).execute(&self.pool).await?;
+        let query = "INSERT INTO crm_metadata.computed_columns
+                        (table_name, column_name, expression, data_type)
+                     VALUES ($1, $2, $3, $4::REGTYPE)
+                     ON CONFLICT (table_name, column_name) DO UPDATE SET
+                        expression = EXCLUDED.expression,
+                        data_type = EXCLUDED.data_type";
+        sqlx::query(query)
+            .bind(&computed_column.table_name)
+            .bind(&computed_column.column_name)
+            .bind(&computed_column.expression)
+            .bind(computed_column.data_type.to_string())
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "INSERT INTO crm_metadata.computed_columns {}.{} AS {}",
+            computed_column.table_name, computed_column.column_name, computed_column.expression
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn delete_computed_column(
+        &self,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "DELETE FROM crm_metadata.computed_columns WHERE table_name = $1 AND column_name = $2",
+        )
+        .bind(table_name)
+        .bind(column_name)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_computed_columns(&self, table_name: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM crm_metadata.computed_columns WHERE table_name = $1")
+            .bind(table_name)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn rename_computed_columns_table(
+        &self,
+        table_name: &str,
+        new_table_name: &str,
+    ) -> Result<(), sqlx::Error> {
+        let query =
+            "UPDATE crm_metadata.computed_columns SET table_name = $2 WHERE table_name = $1";
+        sqlx::query(query)
+            .bind(table_name)
+            .bind(new_table_name)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_chart_points(
+        &self,
+        chart_definition: &ChartDefinition,
+    ) -> Result<Vec<ChartPoint>, sqlx::Error> {
+        let group_by_expression = if let Some(time_bucket) = &chart_definition.time_bucket {
+            format!(
+                "date_trunc('{}', \"{}\")",
+                time_bucket, chart_definition.group_by_column
+            )
+        } else {
+            format!("\"{}\"", chart_definition.group_by_column)
+        };
+        let aggregate_expression = match (
+            &chart_definition.aggregate,
+            &chart_definition.aggregate_column,
+        ) {
+            (Aggregate::Count, _) | (_, None) => String::from("COUNT(*)"),
+            (aggregate, Some(aggregate_column)) => {
+                format!("{}(\"{}\")", aggregate, aggregate_column)
+            }
+        };
+        let query = format!(
+            "SELECT COALESCE({}::TEXT, '') AS label, COALESCE({}, 0)::FLOAT8 AS value
+             FROM \"{}\"
+             GROUP BY {}
+             ORDER BY {}",
+            group_by_expression,
+            aggregate_expression,
+            chart_definition.table_name,
+            group_by_expression,
+            group_by_expression
+        );
+        let rows = sqlx::query(&query).fetch_all(&self.pool).await?;
+        self.log_query(query).await;
+        Ok(rows
+            .into_iter()
+            .map(|row| ChartPoint {
+                label: row.get("label"),
+                value: row.get("value"),
+            })
+            .collect())
+    }
+
+    pub async fn create_table_views_table(&self) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        let query = "CREATE TABLE IF NOT EXISTS crm_metadata.table_views (
+                        id SERIAL PRIMARY KEY,
+                        table_name TEXT NOT NULL,
+                        view_name TEXT NOT NULL,
+                        column_names TEXT[] NOT NULL DEFAULT '{}',
+                        filter_columns TEXT[] NOT NULL DEFAULT '{}',
+                        filter_operators TEXT[] NOT NULL DEFAULT '{}',
+                        filter_values TEXT[] NOT NULL DEFAULT '{}',
+                        sort_columns TEXT[] NOT NULL DEFAULT '{}',
+                        sort_directions TEXT[] NOT NULL DEFAULT '{}',
+                        is_default BOOLEAN NOT NULL DEFAULT false,
+                        UNIQUE (table_name, view_name)
+                    )";
+        sqlx::query(query).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    pub async fn get_table_views(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<TableViewInfo>, sqlx::Error> {
+        let query = "SELECT id, table_name, view_name, column_names, filter_columns,
+                            filter_operators, filter_values, sort_columns, sort_directions,
+                            is_default
+                     FROM crm_metadata.table_views
+                     WHERE table_name = $1
+                     ORDER BY view_name";
+        sqlx::query_as::<_, TableViewInfo>(query)
+            .bind(table_name)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn upsert_table_view(&self, table_view: &TableView) -> Result<(), sqlx::Error> {
+        let query = "INSERT INTO crm_metadata.table_views
+                        (table_name, view_name, column_names, filter_columns, filter_operators,
+                         filter_values, sort_columns, sort_directions, is_default)
+                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                     ON CONFLICT (table_name, view_name) DO UPDATE SET
+                        column_names = EXCLUDED.column_names,
+                        filter_columns = EXCLUDED.filter_columns,
+                        filter_operators = EXCLUDED.filter_operators,
+                        filter_values = EXCLUDED.filter_values,
+                        sort_columns = EXCLUDED.sort_columns,
+                        sort_directions = EXCLUDED.sort_directions";
+        sqlx::query(query)
+            .bind(&table_view.table_name)
+            .bind(&table_view.view_name)
+            .bind(&table_view.column_names)
+            .bind(
+                table_view
+                    .filters
+                    .iter()
+                    .map(|filter| filter.column_name.clone())
+                    .collect::<Vec<String>>(),
+            )
+            .bind(
+                table_view
+                    .filters
+                    .iter()
+                    .map(|filter| filter.operator.to_string())
+                    .collect::<Vec<String>>(),
+            )
+            .bind(
+                table_view
+                    .filters
+                    .iter()
+                    .map(|filter| filter.value.clone())
+                    .collect::<Vec<String>>(),
+            )
+            .bind(
+                table_view
+                    .sorts
+                    .iter()
+                    .map(|sort| sort.column_name.clone())
+                    .collect::<Vec<String>>(),
+            )
+            .bind(
+                table_view
+                    .sorts
+                    .iter()
+                    .map(|sort| sort.direction.to_string())
+                    .collect::<Vec<String>>(),
+            )
+            .bind(table_view.is_default)
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "UPSERT crm_metadata.table_views \"{}\" for \"{}\"",
+            table_view.view_name, table_view.table_name
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn rename_table_view(
+        &self,
+        table_view_id: i32,
+        view_name: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE crm_metadata.table_views SET view_name = $1 WHERE id = $2")
+            .bind(view_name)
+            .bind(table_view_id)
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "UPDATE crm_metadata.table_views SET view_name = '{}' WHERE id = {}",
+            view_name, table_view_id
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn delete_table_view(&self, table_view_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM crm_metadata.table_views WHERE id = $1")
+            .bind(table_view_id)
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "DELETE FROM crm_metadata.table_views WHERE id = {}",
+            table_view_id
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn delete_table_views(&self, table_name: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM crm_metadata.table_views WHERE table_name = $1")
+            .bind(table_name)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    // only one view per table can be the default
+    pub async fn set_default_table_view(
+        &self,
+        table_name: &str,
+        table_view_id: Option<i32>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE crm_metadata.table_views
+             SET is_default = (id IS NOT DISTINCT FROM $2)
+             WHERE table_name = $1",
+        )
+        .bind(table_name)
+        .bind(table_view_id)
+        .execute(&self.pool)
+        .await?;
+        self.log_query(format!(
+            "UPDATE crm_metadata.table_views default view of \"{}\" = {:?}",
+            table_name, table_view_id
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn create_audit_log_table(&self) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        let query = "CREATE TABLE IF NOT EXISTS crm_metadata.audit_log (
+                        id SERIAL PRIMARY KEY,
+                        user_name TEXT NOT NULL DEFAULT CURRENT_USER,
+                        changed_at TIMESTAMP NOT NULL DEFAULT now(),
+                        table_name TEXT NOT NULL,
+                        operation TEXT NOT NULL,
+                        primary_key_column_names TEXT[] NOT NULL,
+                        primary_key_values TEXT[] NOT NULL,
+                        column_names TEXT[] NOT NULL,
+                        old_values TEXT[],
+                        new_values TEXT[]
+                    )";
+        sqlx::query(query).execute(&self.pool).await?;
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS audit_log_table_name_idx
+             ON crm_metadata.audit_log (table_name)",
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_audit_entries(
+        &self,
+        audit_filter: &AuditFilter,
+        limit: i64,
+    ) -> Result<Vec<AuditEntryInfo>, sqlx::Error> {
+        let query = "SELECT id, user_name, changed_at::TEXT AS changed_at, table_name, operation,
+                            primary_key_column_names, primary_key_values, column_names,
+                            old_values, new_values
+                     FROM crm_metadata.audit_log
+                     WHERE ($1::TEXT IS NULL OR table_name = $1)
+                       AND ($2::TEXT IS NULL OR $2 = ANY(primary_key_values))
+                       AND ($3::TEXT IS NULL OR user_name = $3)
+                     ORDER BY id DESC
+                     LIMIT $4";
+        sqlx::query_as::<_, AuditEntryInfo>(query)
+            .bind(&audit_filter.table_name)
+            .bind(&audit_filter.primary_key_value)
+            .bind(&audit_filter.user_name)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn get_audit_entry(
+        &self,
+        audit_entry_id: i32,
+    ) -> Result<Option<AuditEntryInfo>, sqlx::Error> {
+        let query = "SELECT id, user_name, changed_at::TEXT AS changed_at, table_name, operation,
+                            primary_key_column_names, primary_key_values, column_names,
+                            old_values, new_values
+                     FROM crm_metadata.audit_log
+                     WHERE id = $1";
+        sqlx::query_as::<_, AuditEntryInfo>(query)
+            .bind(audit_entry_id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    pub async fn create_users_table(&self) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        let query = "CREATE TABLE IF NOT EXISTS crm_metadata.users (
+                        id SERIAL PRIMARY KEY,
+                        user_name TEXT NOT NULL UNIQUE,
+                        password_hash TEXT NOT NULL,
+                        role TEXT NOT NULL
+                    )";
+        sqlx::query(query).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    pub async fn get_users(&self) -> Result<Vec<UserInfo>, sqlx::Error> {
+        sqlx::query_as::<_, UserInfo>(
+            "SELECT id, user_name, password_hash, role FROM crm_metadata.users ORDER BY user_name",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn get_user(&self, user_name: &str) -> Result<Option<UserInfo>, sqlx::Error> {
+        sqlx::query_as::<_, UserInfo>(
+            "SELECT id, user_name, password_hash, role FROM crm_metadata.users WHERE user_name = $1",
+        )
+        .bind(user_name)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn insert_user(
+        &self,
+        user_name: &str,
+        password_hash: &str,
+        role: &Role,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO crm_metadata.users (user_name, password_hash, role) VALUES ($1, $2, $3)",
+        )
+        .bind(user_name)
+        .bind(password_hash)
+        .bind(role.to_string())
+        .execute(&self.pool)
+        .await?;
+        // the hash is never written to the console
+        self.log_query(format!(
+            "INSERT INTO crm_metadata.users user \"{}\" as {}",
+            user_name, role
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn update_user_role(&self, user_id: i32, role: &Role) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE crm_metadata.users SET role = $2 WHERE id = $1")
+            .bind(user_id)
+            .bind(role.to_string())
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "UPDATE crm_metadata.users SET role = '{}' WHERE id = {}",
+            role, user_id
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn delete_user(&self, user_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM crm_metadata.users WHERE id = $1")
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "DELETE FROM crm_metadata.users WHERE id = {}",
+            user_id
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn create_access_rules_table(&self) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        let query = "CREATE TABLE IF NOT EXISTS crm_metadata.access_rules (
+                        id SERIAL PRIMARY KEY,
+                        role TEXT NOT NULL,
+                        table_name TEXT NOT NULL,
+                        column_name TEXT,
+                        access_level TEXT NOT NULL
+                    )";
+        sqlx::query(query).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    pub async fn get_access_rules(&self) -> Result<Vec<AccessRuleInfo>, sqlx::Error> {
+        sqlx::query_as::<_, AccessRuleInfo>(
+            "SELECT id, role, table_name, column_name, access_level
+             FROM crm_metadata.access_rules
+             ORDER BY role, table_name, column_name NULLS FIRST",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    // a role has at most one rule per table and column, saving replaces it
+    pub async fn upsert_access_rule(&self, access_rule: &AccessRule) -> Result<(), sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        sqlx::query(
+            "DELETE FROM crm_metadata.access_rules
+             WHERE role = $1 AND table_name = $2 AND column_name IS NOT DISTINCT FROM $3",
+        )
+        .bind(access_rule.role.to_string())
+        .bind(&access_rule.table_name)
+        .bind(&access_rule.column_name)
+        .execute(&mut *transaction)
+        .await?;
+        sqlx::query(
+            "INSERT INTO crm_metadata.access_rules (role, table_name, column_name, access_level)
+             VALUES ($1, $2, $3, $4)",
+        )
+        .bind(access_rule.role.to_string())
+        .bind(&access_rule.table_name)
+        .bind(&access_rule.column_name)
+        .bind(access_rule.access_level.to_string())
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+        self.log_query(format!(
+            "INSERT INTO crm_metadata.access_rules {} {}.{} {}",
+            access_rule.role,
+            access_rule.table_name,
+            access_rule.column_name.as_deref().unwrap_or("*"),
+            access_rule.access_level
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn delete_access_rule(&self, access_rule_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM crm_metadata.access_rules WHERE id = $1")
+            .bind(access_rule_id)
+            .execute(&self.pool)
+            .await?;
+        self.log_query(format!(
+            "DELETE FROM crm_metadata.access_rules WHERE id = {}",
+            access_rule_id
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn delete_access_rules(&self, table_name: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM crm_metadata.access_rules WHERE table_name = $1")
+            .bind(table_name)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    // every statement changing a managed table notifies the listeners with its name
+    pub async fn install_table_change_trigger(&self, table_name: &str) -> Result<(), sqlx::Error> {
+        self.create_metadata_schema().await?;
+        let function_query = format!(
+            "CREATE OR REPLACE FUNCTION crm_metadata.notify_table_change() RETURNS trigger AS $$
+             BEGIN
+                 PERFORM pg_notify('{}', TG_TABLE_NAME);
+                 RETURN NULL;
+             END;
+             $$ LANGUAGE plpgsql",
+            TABLE_ROWS_CHANNEL
+        );
+        sqlx::query(&function_query).execute(&self.pool).await?;
+        let trigger_query = format!(
+            "CREATE OR REPLACE TRIGGER crm_notify_table_change
+             AFTER INSERT OR UPDATE OR DELETE OR TRUNCATE ON \"{}\"
+             FOR EACH STATEMENT EXECUTE FUNCTION crm_metadata.notify_table_change()",
+            table_name
+        );
+        sqlx::query(&trigger_query).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    async fn notify_schema_change(&self) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT pg_notify($1, '')")
+            .bind(SCHEMA_CHANNEL)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn listen_for_table_changes(&self) -> Result<PgListener, sqlx::Error> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener
+            .listen_all([TABLE_ROWS_CHANNEL, SCHEMA_CHANNEL])
+            .await?;
+        Ok(listener)
+    }
+
+    pub async fn receive_table_change(
+        &self,
+        listener: &mut PgListener,
+    ) -> Result<TableChange, sqlx::Error> {
+        let notification = listener.recv().await?;
+        if notification.channel() == SCHEMA_CHANNEL {
+            Ok(TableChange::Schema)
+        } else {
+            Ok(TableChange::Rows(notification.payload().to_string()))
+        }
+    }
+}
@@ -0,0 +1,1199 @@
+use crate::database::models::{
+    AccessRuleInfo, AuditEntryInfo, ChartDefinitionInfo, ColumnLayoutInfo, ColumnsInfo,
+    ComputedColumnInfo, NodePositionInfo, TableGeneralInfo, TableViewInfo, UserInfo,
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::iter::zip;
+use std::sync::LazyLock;
+
+static TIMESTAMP_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\d{4}-\d{2}-\d{2}([ T]\d{2}:\d{2}(:\d{2}(\.\d+)?)?)?$").unwrap()
+});
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DataType {
+    #[default]
+    TEXT,
+    INTEGER,
+    TIMESTAMP,
+    SERIAL,
+    BOOLEAN,
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DataType::TEXT => write!(f, "TEXT"),
+            DataType::INTEGER => write!(f, "INTEGER"),
+            DataType::TIMESTAMP => write!(f, "TIMESTAMP"),
+            DataType::SERIAL => write!(f, "SERIAL"),
+            DataType::BOOLEAN => write!(f, "BOOLEAN"),
+        }
+    }
+}
+
+impl TryFrom<String> for DataType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "text" => Ok(Self::TEXT),
+            "integer" => Ok(Self::INTEGER),
+            "timestamp without time zone" => Ok(Self::TIMESTAMP),
+            "serial" => Ok(Self::SERIAL),
+            "boolean" => Ok(Self::BOOLEAN),
+            _ => Err(format!("unsupported column type {}", value)),
+        }
+    }
+}
+
+impl DataType {
+    pub fn to_datatype(value: String) -> Self {
+        Self::try_from(value).unwrap_or_else(|message| panic!("{}", message))
+    }
+
+    // an empty value is stored as NULL for every type but TEXT
+    pub fn is_valid_value(&self, value: &str) -> bool {
+        let value = value.trim();
+        if value.is_empty() {
+            return true;
+        }
+        match self {
+            DataType::TEXT => true,
+            DataType::INTEGER | DataType::SERIAL => value.parse::<i32>().is_ok(),
+            DataType::BOOLEAN => matches!(
+                value.to_lowercase().as_str(),
+                "true" | "false" | "t" | "f" | "yes" | "no" | "y" | "n" | "on" | "off" | "1" | "0"
+            ),
+            DataType::TIMESTAMP => TIMESTAMP_REGEX.is_match(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Constraint {
+    ForeignKey(String, String),
+    PrimaryKey,
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Constraint::ForeignKey(referenced_table, referenced_column) => {
+                write!(f, "REFERENCES {}({})", referenced_table, referenced_column)
+            }
+            Constraint::PrimaryKey => write!(f, "PRIMARY KEY"),
+        }
+    }
+}
+
+impl Constraint {
+    pub fn to_constraint(
+        constraint_type: String,
+        referenced_table: String,
+        referenced_column: String,
+    ) -> Self {
+        match constraint_type.as_str() {
+            "PRIMARY KEY" => Self::PrimaryKey,
+            "FOREIGN KEY" => Self::ForeignKey(referenced_table, referenced_column),
+            _ => panic!("Invalid Constraint"),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Column {
+    pub name: String,
+    pub datatype: DataType,
+    pub constraints: Vec<Constraint>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct TableGeneral {
+    pub table_name: String,
+    pub column_names: Vec<String>,
+    pub data_types: Vec<DataType>,
+    pub is_unique: Vec<bool>,
+}
+
+impl TableGeneral {
+    pub fn to_table(table_general_info: TableGeneralInfo) -> Self {
+        Self {
+            table_name: table_general_info.table_name,
+            column_names: table_general_info.column_names,
+            data_types: table_general_info
+                .data_types
+                .into_iter()
+                .map(DataType::to_datatype)
+                .collect(),
+            is_unique: table_general_info.is_unique,
+        }
+    }
+
+    pub fn apply_column_layout(&mut self, column_layout: &ColumnLayout) {
+        let columns: Vec<(String, DataType, bool)> = column_layout.order(
+            zip(
+                zip(self.column_names.drain(..), self.data_types.drain(..)),
+                self.is_unique.drain(..),
+            )
+            .map(|((column_name, data_type), is_unique)| (column_name, data_type, is_unique))
+            .collect(),
+            |(column_name, _, _)| column_name,
+        );
+        for (column_name, data_type, is_unique) in columns {
+            self.column_names.push(column_name);
+            self.data_types.push(data_type);
+            self.is_unique.push(is_unique);
+        }
+    }
+}
+
+impl Column {
+    pub fn to_column(column_info: ColumnsInfo) -> Self {
+        Self::try_to_column(column_info).unwrap_or_else(|message| panic!("{}", message))
+    }
+
+    // a database made outside the app can have column types the app doesn't model
+    pub fn try_to_column(column_info: ColumnsInfo) -> Result<Self, String> {
+        // initial query couldve returned null constraint types so they
+        // need to be filtered, UNIQUE and CHECK constraints aren't modelled
+        Ok(Self {
+            name: column_info.column_name,
+            datatype: DataType::try_from(column_info.data_type)?,
+            constraints: zip(
+                zip(column_info.constraint_types, column_info.referenced_tables),
+                column_info.referenced_columns,
+            )
+            .filter(
+                |((constraint_type, _referenced_table), _referenced_column)| {
+                    matches!(
+                        constraint_type.as_deref(),
+                        Some("PRIMARY KEY" | "FOREIGN KEY")
+                    )
+                },
+            )
+            .map(|((constraint_type, referenced_table), referenced_column)| {
+                Constraint::to_constraint(
+                    constraint_type.unwrap(),
+                    referenced_table.unwrap_or_default(),
+                    referenced_column.unwrap_or_default(),
+                )
+            })
+            .collect(),
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableIn {
+    pub table_name: String,
+    pub columns: Vec<Column>,
+}
+
+// the schema file kept in version control, tables are written in foreign key order
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaDefinition {
+    pub tables: Vec<TableIn>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableAlteration {
+    pub table_name: String,
+    pub table_change_events: Vec<TableChangeEvents>,
+    pub primary_key_column_names: Vec<String>,
+}
+
+// what loading a schema file would change, reviewed before it is applied
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SchemaPlan {
+    pub create_tables: Vec<TableIn>,
+    pub alter_tables: Vec<TableAlteration>,
+    // live tables missing from the file are never dropped
+    pub untouched_tables: Vec<String>,
+}
+
+impl SchemaPlan {
+    pub fn is_empty(&self) -> bool {
+        self.create_tables.is_empty() && self.alter_tables.is_empty()
+    }
+}
+
+// differences between a source and a target database, with the script
+// that brings the target to the source
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SchemaDiff {
+    pub added_tables: Vec<TableIn>,
+    pub removed_tables: Vec<String>,
+    pub changed_tables: Vec<TableAlteration>,
+    pub migration_queries: Vec<String>,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        self.migration_queries.is_empty()
+    }
+
+    pub fn migration_script(&self) -> String {
+        self.migration_queries
+            .iter()
+            .map(|query| format!("{};\n", query))
+            .collect()
+    }
+}
+
+// one table of a backup, the data is in COPY text format
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackupTable {
+    pub table_name: String,
+    pub create_table_query: String,
+    pub column_names: Vec<String>,
+    pub data: String,
+    // serial and identity columns, their sequences continue after the restored rows
+    pub sequence_column_names: Vec<String>,
+}
+
+// tables are kept in foreign key order, referenced tables first
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct BackupArchive {
+    pub tables: Vec<BackupTable>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackupProgress {
+    pub table_name: String,
+    pub completed_tables: usize,
+    pub total_tables: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnForeignKey {
+    pub column_name: String,
+    pub referenced_column: String,
+    pub referenced_table: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct TableInsertedData {
+    pub table_name: String,
+    pub column_names: Vec<String>,
+    pub data_types: Vec<DataType>,
+    pub rows: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableChangeEvents {
+    ChangeTableName(String),
+    ChangeColumnDataType(String, DataType),
+    ChangeColumnName(String, String),
+    AddColumn(String, DataType),
+    RemoveColumn(String),
+    AddForeignKey(ColumnForeignKey),
+    RemoveForeignKey(String),
+    AddPrimaryKey(String),
+    RemovePrimaryKey(String),
+    // column name, data type and the expression postgres keeps it up to date with
+    AddGeneratedColumn(String, DataType, String),
+}
+
+impl fmt::Display for TableChangeEvents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableChangeEvents::ChangeTableName(new_name) => {
+                write!(f, "rename table to {}", new_name)
+            }
+            TableChangeEvents::ChangeColumnDataType(column_name, data_type) => {
+                write!(f, "change {} to {}", column_name, data_type)
+            }
+            TableChangeEvents::ChangeColumnName(old_name, new_name) => {
+                write!(f, "rename column {} to {}", old_name, new_name)
+            }
+            TableChangeEvents::AddColumn(column_name, data_type) => {
+                write!(f, "add column {} {}", column_name, data_type)
+            }
+            TableChangeEvents::RemoveColumn(column_name) => {
+                write!(f, "drop column {}", column_name)
+            }
+            TableChangeEvents::AddForeignKey(column_foreign_key) => write!(
+                f,
+                "add foreign key {} REFERENCES {}({})",
+                column_foreign_key.column_name,
+                column_foreign_key.referenced_table,
+                column_foreign_key.referenced_column
+            ),
+            TableChangeEvents::RemoveForeignKey(column_name) => {
+                write!(f, "drop foreign key {}", column_name)
+            }
+            TableChangeEvents::AddPrimaryKey(column_name) => {
+                write!(f, "add {} to the primary key", column_name)
+            }
+            TableChangeEvents::RemovePrimaryKey(column_name) => {
+                write!(f, "remove {} from the primary key", column_name)
+            }
+            TableChangeEvents::AddGeneratedColumn(column_name, data_type, expression) => {
+                write!(
+                    f,
+                    "add column {} {} generated as {}",
+                    column_name, data_type, expression
+                )
+            }
+        }
+    }
+}
+
+// how the rows of a column are converted when its data type changes
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnConversion {
+    pub column_name: String,
+    pub data_type: DataType,
+    // replaces the plain cast of the column, written against the column name
+    pub using_expression: String,
+    // SQL value for the rows the expression cannot convert
+    pub failure_default: String,
+}
+
+impl ColumnConversion {
+    pub fn new(column_name: String, data_type: DataType) -> Self {
+        Self {
+            column_name,
+            data_type,
+            using_expression: String::new(),
+            failure_default: String::new(),
+        }
+    }
+
+    fn row_expression(&self) -> String {
+        if self.using_expression.trim().is_empty() {
+            format!("\"{}\"::{}", self.column_name, self.data_type)
+        } else {
+            self.using_expression.trim().to_string()
+        }
+    }
+
+    /* the expression evaluated for a single value passed as $1, OFFSET 0 keeps
+     * the planner from folding the value into a cast that CASE would skip */
+    fn conversion_query(&self, expression: &str) -> String {
+        format!(
+            "SELECT {} FROM (SELECT $1 AS \"{}\" OFFSET 0) AS source_row",
+            expression, self.column_name
+        )
+    }
+
+    pub fn using_expression(&self) -> String {
+        if self.failure_default.trim().is_empty() {
+            return self.row_expression();
+        }
+        format!(
+            "CASE WHEN crm_metadata.converts(\"{}\", '{}') THEN {} ELSE ({})::{} END",
+            self.column_name,
+            self.conversion_query(&self.row_expression())
+                .replace('\'', "''"),
+            self.row_expression(),
+            self.failure_default.trim(),
+            self.data_type
+        )
+    }
+
+    pub fn preview_query(&self) -> String {
+        self.conversion_query(&self.using_expression())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionPreview {
+    pub column_conversion: ColumnConversion,
+    pub failed_row_count: i64,
+    // a sample of the values that fail to convert
+    pub failed_values: Vec<Option<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub column_name: String,
+    pub data_type: DataType,
+    // None matches NULL
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowColumnValue {
+    pub conditions: Vec<Condition>,
+    // None writes NULL, reverts restore it that way
+    pub column_values: HashMap<String, (DataType, Option<String>)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowInsertData {
+    pub column_names: Vec<String>,
+    pub data_types: Vec<DataType>,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableDataChangeEvents {
+    ModifyRowColumnValue(RowColumnValue),
+    DeleteRow(Vec<Condition>),
+    InsertRow(RowInsertData),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ChartType {
+    #[default]
+    Bar,
+    Line,
+    Pie,
+}
+
+impl fmt::Display for ChartType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChartType::Bar => write!(f, "BAR"),
+            ChartType::Line => write!(f, "LINE"),
+            ChartType::Pie => write!(f, "PIE"),
+        }
+    }
+}
+
+impl ChartType {
+    // an unknown stored value falls back to the default so the dashboard still loads
+    pub fn to_chart_type(value: String) -> Self {
+        match value.as_str() {
+            "BAR" => Self::Bar,
+            "LINE" => Self::Line,
+            "PIE" => Self::Pie,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Aggregate {
+    #[default]
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+impl fmt::Display for Aggregate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Aggregate::Count => write!(f, "COUNT"),
+            Aggregate::Sum => write!(f, "SUM"),
+            Aggregate::Avg => write!(f, "AVG"),
+            Aggregate::Min => write!(f, "MIN"),
+            Aggregate::Max => write!(f, "MAX"),
+        }
+    }
+}
+
+impl Aggregate {
+    // like chart types, an unknown aggregate counts the rows
+    pub fn to_aggregate(value: String) -> Self {
+        match value.as_str() {
+            "COUNT" => Self::Count,
+            "SUM" => Self::Sum,
+            "AVG" => Self::Avg,
+            "MIN" => Self::Min,
+            "MAX" => Self::Max,
+            _ => Self::default(),
+        }
+    }
+}
+
+// buckets TIMESTAMP group by columns through date_trunc
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeBucket {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl fmt::Display for TimeBucket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeBucket::Day => write!(f, "day"),
+            TimeBucket::Week => write!(f, "week"),
+            TimeBucket::Month => write!(f, "month"),
+            TimeBucket::Year => write!(f, "year"),
+        }
+    }
+}
+
+impl TimeBucket {
+    // an unknown bucket groups by the raw values
+    pub fn to_time_bucket(value: String) -> Option<Self> {
+        match value.as_str() {
+            "day" => Some(Self::Day),
+            "week" => Some(Self::Week),
+            "month" => Some(Self::Month),
+            "year" => Some(Self::Year),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ChartDefinition {
+    pub id: Option<i32>,
+    pub title: String,
+    pub chart_type: ChartType,
+    pub table_name: String,
+    pub group_by_column: String,
+    pub aggregate: Aggregate,
+    pub aggregate_column: Option<String>,
+    pub time_bucket: Option<TimeBucket>,
+}
+
+impl ChartDefinition {
+    pub fn to_chart_definition(chart_definition_info: ChartDefinitionInfo) -> Self {
+        Self {
+            id: Some(chart_definition_info.id),
+            title: chart_definition_info.title,
+            chart_type: ChartType::to_chart_type(chart_definition_info.chart_type),
+            table_name: chart_definition_info.table_name,
+            group_by_column: chart_definition_info.group_by_column,
+            aggregate: Aggregate::to_aggregate(chart_definition_info.aggregate),
+            aggregate_column: chart_definition_info.aggregate_column,
+            time_bucket: chart_definition_info
+                .time_bucket
+                .and_then(TimeBucket::to_time_bucket),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartPoint {
+    pub label: String,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chart {
+    pub definition: ChartDefinition,
+    pub points: Vec<ChartPoint>,
+}
+
+// a table drawn on the schema diagram, its foreign keys are kept in the column constraints
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagramTable {
+    pub table_name: String,
+    pub columns: Vec<Column>,
+}
+
+impl DiagramTable {
+    // node size in diagram units, shared by the auto-layout and the canvas
+    pub const NODE_WIDTH: f32 = 220.0;
+    pub const HEADER_HEIGHT: f32 = 28.0;
+    pub const ROW_HEIGHT: f32 = 20.0;
+
+    pub fn node_height(&self) -> f32 {
+        Self::HEADER_HEIGHT + Self::ROW_HEIGHT * self.columns.len() as f32
+    }
+
+    pub fn referenced_tables(&self) -> Vec<String> {
+        let mut referenced_tables = vec![];
+        for column in &self.columns {
+            for constraint in &column.constraints {
+                if let Constraint::ForeignKey(table_name, _) = constraint {
+                    if !referenced_tables.contains(table_name) {
+                        referenced_tables.push(table_name.clone());
+                    }
+                }
+            }
+        }
+        referenced_tables
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodePosition {
+    pub table_name: String,
+    pub x: f32,
+    pub y: f32,
+}
+
+impl NodePosition {
+    pub fn to_node_position(node_position_info: NodePositionInfo) -> Self {
+        Self {
+            table_name: node_position_info.table_name,
+            x: node_position_info.x,
+            y: node_position_info.y,
+        }
+    }
+}
+
+// the order users see a table's columns in, postgres keeps its physical order
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ColumnLayout {
+    pub table_name: String,
+    pub column_names: Vec<String>,
+    pub hidden_column_names: Vec<String>,
+}
+
+impl ColumnLayout {
+    pub fn to_column_layout(column_layout_info: ColumnLayoutInfo) -> Self {
+        Self {
+            table_name: column_layout_info.table_name,
+            column_names: column_layout_info.column_names,
+            hidden_column_names: column_layout_info.hidden_column_names,
+        }
+    }
+
+    // columns the layout does not know yet keep their database order at the end
+    pub fn order<T>(&self, mut items: Vec<T>, column_name: impl Fn(&T) -> &str) -> Vec<T> {
+        items.sort_by_key(|item| {
+            self.column_names
+                .iter()
+                .position(|layout_column_name| layout_column_name == column_name(item))
+                .unwrap_or(usize::MAX)
+        });
+        items
+    }
+
+    pub fn is_hidden(&self, column_name: &str) -> bool {
+        self.hidden_column_names
+            .iter()
+            .any(|hidden_column_name| hidden_column_name == column_name)
+    }
+
+    pub fn rename_column(&mut self, column_name: &str, new_column_name: &str) {
+        for layout_column_name in self
+            .column_names
+            .iter_mut()
+            .chain(self.hidden_column_names.iter_mut())
+        {
+            if layout_column_name == column_name {
+                *layout_column_name = new_column_name.to_string();
+            }
+        }
+    }
+}
+
+// a read only column of the grid, evaluated by postgres when the rows are selected
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ComputedColumn {
+    pub table_name: String,
+    pub column_name: String,
+    // SQL over the other columns of the row, e.g. first_name || ' ' || last_name
+    pub expression: String,
+    pub data_type: DataType,
+}
+
+impl ComputedColumn {
+    pub fn to_computed_column(computed_column_info: ComputedColumnInfo) -> Self {
+        Self {
+            table_name: computed_column_info.table_name,
+            column_name: computed_column_info.column_name,
+            expression: computed_column_info.expression,
+            data_type: DataType::to_datatype(computed_column_info.data_type),
+        }
+    }
+
+    pub fn select_expression(&self) -> String {
+        format!(
+            "({})::{} AS \"{}\"",
+            self.expression, self.data_type, self.column_name
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SortDirection::Ascending => write!(f, "ASC"),
+            SortDirection::Descending => write!(f, "DESC"),
+        }
+    }
+}
+
+impl SortDirection {
+    pub fn to_sort_direction(value: String) -> Self {
+        match value.as_str() {
+            "ASC" => Self::Ascending,
+            "DESC" => Self::Descending,
+            _ => panic!("Invalid sort direction"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum FilterOperator {
+    #[default]
+    Equals,
+    NotEquals,
+    Contains,
+    GreaterThan,
+    LessThan,
+    IsEmpty,
+}
+
+impl fmt::Display for FilterOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FilterOperator::Equals => write!(f, "="),
+            FilterOperator::NotEquals => write!(f, "<>"),
+            FilterOperator::Contains => write!(f, "CONTAINS"),
+            FilterOperator::GreaterThan => write!(f, ">"),
+            FilterOperator::LessThan => write!(f, "<"),
+            FilterOperator::IsEmpty => write!(f, "IS EMPTY"),
+        }
+    }
+}
+
+impl FilterOperator {
+    pub fn to_filter_operator(value: String) -> Self {
+        match value.as_str() {
+            "=" => Self::Equals,
+            "<>" => Self::NotEquals,
+            "CONTAINS" => Self::Contains,
+            ">" => Self::GreaterThan,
+            "<" => Self::LessThan,
+            "IS EMPTY" => Self::IsEmpty,
+            _ => panic!("Invalid filter operator"),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ViewFilter {
+    pub column_name: String,
+    pub operator: FilterOperator,
+    pub value: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ViewSort {
+    pub column_name: String,
+    pub direction: SortDirection,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct TableView {
+    pub id: Option<i32>,
+    pub table_name: String,
+    pub view_name: String,
+    // visible columns in display order
+    pub column_names: Vec<String>,
+    pub filters: Vec<ViewFilter>,
+    pub sorts: Vec<ViewSort>,
+    pub is_default: bool,
+}
+
+impl TableView {
+    pub fn to_table_view(table_view_info: TableViewInfo) -> Self {
+        Self {
+            id: Some(table_view_info.id),
+            table_name: table_view_info.table_name,
+            view_name: table_view_info.view_name,
+            column_names: table_view_info.column_names,
+            filters: zip(
+                zip(
+                    table_view_info.filter_columns,
+                    table_view_info.filter_operators,
+                ),
+                table_view_info.filter_values,
+            )
+            .map(|((column_name, operator), value)| ViewFilter {
+                column_name,
+                operator: FilterOperator::to_filter_operator(operator),
+                value,
+            })
+            .collect(),
+            sorts: zip(
+                table_view_info.sort_columns,
+                table_view_info.sort_directions,
+            )
+            .map(|(column_name, direction)| ViewSort {
+                column_name,
+                direction: SortDirection::to_sort_direction(direction),
+            })
+            .collect(),
+            is_default: table_view_info.is_default,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SearchResultRow {
+    pub values: Vec<String>,
+    pub matched_column_names: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub table_name: String,
+    pub column_names: Vec<String>,
+    pub rows: Vec<SearchResultRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum MatchType {
+    #[default]
+    Exact,
+    Email,
+    Phone,
+    Name,
+}
+
+impl fmt::Display for MatchType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchType::Exact => write!(f, "Exact"),
+            MatchType::Email => write!(f, "Email"),
+            MatchType::Phone => write!(f, "Phone"),
+            MatchType::Name => write!(f, "Similar Name"),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct DuplicateKey {
+    pub column_name: String,
+    pub match_type: MatchType,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct DuplicateGroup {
+    pub table_name: String,
+    pub column_names: Vec<String>,
+    pub data_types: Vec<DataType>,
+    pub primary_key_column_names: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeignKeyReference {
+    pub table_name: String,
+    pub column_name: String,
+    pub referenced_column: String,
+}
+
+// rows of another table whose foreign key points at one row
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChildRecords {
+    pub foreign_key_reference: ForeignKeyReference,
+    pub table_inserted_data: TableInsertedData,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct RowMerge {
+    pub table_name: String,
+    pub surviving_row_conditions: Vec<Condition>,
+    pub losing_rows_conditions: Vec<Vec<Condition>>,
+    pub merged_column_values: HashMap<String, (DataType, Option<String>)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum AuditOperation {
+    #[default]
+    Insert,
+    Update,
+    Delete,
+}
+
+impl fmt::Display for AuditOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuditOperation::Insert => write!(f, "INSERT"),
+            AuditOperation::Update => write!(f, "UPDATE"),
+            AuditOperation::Delete => write!(f, "DELETE"),
+        }
+    }
+}
+
+impl AuditOperation {
+    pub fn to_audit_operation(value: String) -> Self {
+        match value.as_str() {
+            "INSERT" => Self::Insert,
+            "UPDATE" => Self::Update,
+            "DELETE" => Self::Delete,
+            _ => panic!("Invalid audit operation"),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub id: i32,
+    pub user_name: String,
+    pub changed_at: String,
+    pub table_name: String,
+    pub operation: AuditOperation,
+    pub primary_key_column_names: Vec<String>,
+    pub primary_key_values: Vec<Option<String>>,
+    pub column_names: Vec<String>,
+    // None for inserts, the row did not exist before
+    pub old_values: Option<Vec<Option<String>>>,
+    // None for deletes
+    pub new_values: Option<Vec<Option<String>>>,
+}
+
+impl AuditEntry {
+    pub fn to_audit_entry(audit_entry_info: AuditEntryInfo) -> Self {
+        Self {
+            id: audit_entry_info.id,
+            user_name: audit_entry_info.user_name,
+            changed_at: audit_entry_info.changed_at,
+            table_name: audit_entry_info.table_name,
+            operation: AuditOperation::to_audit_operation(audit_entry_info.operation),
+            primary_key_column_names: audit_entry_info.primary_key_column_names,
+            primary_key_values: audit_entry_info.primary_key_values,
+            column_names: audit_entry_info.column_names,
+            old_values: audit_entry_info.old_values,
+            new_values: audit_entry_info.new_values,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AuditFilter {
+    pub table_name: Option<String>,
+    pub primary_key_value: Option<String>,
+    pub user_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Role {
+    Admin,
+    Editor,
+    #[default]
+    Viewer,
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Role::Admin => write!(f, "admin"),
+            Role::Editor => write!(f, "editor"),
+            Role::Viewer => write!(f, "viewer"),
+        }
+    }
+}
+
+impl Role {
+    pub fn to_role(value: String) -> Self {
+        match value.as_str() {
+            "admin" => Self::Admin,
+            "editor" => Self::Editor,
+            "viewer" => Self::Viewer,
+            _ => panic!("Invalid role"),
+        }
+    }
+
+    pub fn has_permission(&self, permission: &Permission) -> bool {
+        match self {
+            Role::Admin => true,
+            Role::Editor => *permission == Permission::EditRows,
+            Role::Viewer => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Permission {
+    AlterSchema,
+    DeleteRows,
+    EditRows,
+    ManageUsers,
+    ManageBackups,
+    RunSql,
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Permission::AlterSchema => write!(f, "change tables"),
+            Permission::DeleteRows => write!(f, "delete rows"),
+            Permission::EditRows => write!(f, "edit rows"),
+            Permission::ManageUsers => write!(f, "manage users"),
+            Permission::ManageBackups => write!(f, "back up and restore data"),
+            Permission::RunSql => write!(f, "run SQL"),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct User {
+    pub id: i32,
+    pub user_name: String,
+    pub role: Role,
+}
+
+impl User {
+    pub fn to_user(user_info: UserInfo) -> Self {
+        Self {
+            id: user_info.id,
+            user_name: user_info.user_name,
+            role: Role::to_role(user_info.role),
+        }
+    }
+}
+
+// ordered so the most restrictive of two levels is their minimum
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum AccessLevel {
+    Hidden,
+    #[default]
+    Read,
+    Write,
+}
+
+impl fmt::Display for AccessLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccessLevel::Hidden => write!(f, "hidden"),
+            AccessLevel::Read => write!(f, "read"),
+            AccessLevel::Write => write!(f, "write"),
+        }
+    }
+}
+
+impl AccessLevel {
+    pub fn to_access_level(value: String) -> Self {
+        match value.as_str() {
+            "hidden" => Self::Hidden,
+            "read" => Self::Read,
+            "write" => Self::Write,
+            _ => panic!("Invalid access level"),
+        }
+    }
+}
+
+// a rule without column_name applies to the whole table
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AccessRule {
+    pub id: Option<i32>,
+    pub role: Role,
+    pub table_name: String,
+    pub column_name: Option<String>,
+    pub access_level: AccessLevel,
+}
+
+impl AccessRule {
+    pub fn to_access_rule(access_rule_info: AccessRuleInfo) -> Self {
+        Self {
+            id: Some(access_rule_info.id),
+            role: Role::to_role(access_rule_info.role),
+            table_name: access_rule_info.table_name,
+            column_name: access_rule_info.column_name,
+            access_level: AccessLevel::to_access_level(access_rule_info.access_level),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeStatus {
+    Unchanged,
+    Modified,
+    Inserted,
+}
+
+// the pending changes of the displayed rows, derived from the change events
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableDataChangeStatus {
+    pub cell_statuses: Vec<Vec<ChangeStatus>>,
+    // rows queued for delete, each with the index of the displayed row it is shown before
+    pub deleted_rows: Vec<(usize, Vec<String>)>,
+    pub updates_count: usize,
+    pub inserts_count: usize,
+    pub deletes_count: usize,
+}
+
+impl TableDataChangeStatus {
+    pub fn summary(&self) -> String {
+        let count = |count: usize, name: &str| {
+            format!("{} {}{}", count, name, if count == 1 { "" } else { "s" })
+        };
+        if self.updates_count + self.inserts_count + self.deletes_count == 0 {
+            String::from("No pending changes")
+        } else {
+            format!(
+                "{}, {}, {}",
+                count(self.updates_count, "update"),
+                count(self.inserts_count, "insert"),
+                count(self.deletes_count, "delete")
+            )
+        }
+    }
+}
+
+// what another session changed, received through LISTEN/NOTIFY
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableChange {
+    Schema,
+    Rows(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_value() {
+        assert!(DataType::INTEGER.is_valid_value(" 42 "));
+        assert!(DataType::SERIAL.is_valid_value("-1"));
+        assert!(!DataType::INTEGER.is_valid_value("4.2"));
+        assert!(!DataType::INTEGER.is_valid_value("99999999999"));
+        assert!(DataType::BOOLEAN.is_valid_value("Yes"));
+        assert!(!DataType::BOOLEAN.is_valid_value("maybe"));
+        assert!(DataType::TIMESTAMP.is_valid_value("2024-01-31"));
+        assert!(DataType::TIMESTAMP.is_valid_value("2024-01-31T08:30:00.5"));
+        assert!(!DataType::TIMESTAMP.is_valid_value("31/01/2024"));
+        assert!(DataType::TEXT.is_valid_value("anything"));
+        // empty values become NULL
+        assert!(DataType::INTEGER.is_valid_value(""));
+    }
+
+    #[test]
+    fn test_column_conversion_queries() {
+        let mut column_conversion = ColumnConversion::new(String::from("age"), DataType::INTEGER);
+        assert_eq!(column_conversion.using_expression(), "\"age\"::INTEGER");
+        assert_eq!(
+            column_conversion.preview_query(),
+            "SELECT \"age\"::INTEGER FROM (SELECT $1 AS \"age\" OFFSET 0) AS source_row"
+        );
+
+        // quotes in the expression are escaped inside the converts() argument
+        column_conversion.using_expression = String::from(" NULLIF(\"age\", 'n/a')::INTEGER ");
+        column_conversion.failure_default = String::from(" 0 ");
+        assert_eq!(
+            column_conversion.using_expression(),
+            "CASE WHEN crm_metadata.converts(\"age\", \
+             'SELECT NULLIF(\"age\", ''n/a'')::INTEGER FROM (SELECT $1 AS \"age\" OFFSET 0) AS source_row') \
+             THEN NULLIF(\"age\", 'n/a')::INTEGER ELSE (0)::INTEGER END"
+        );
+    }
+
+    #[test]
+    fn test_chart_definition_with_unknown_values() {
+        let chart_definition = ChartDefinition::to_chart_definition(ChartDefinitionInfo {
+            id: 1,
+            title: String::from("Orders"),
+            chart_type: String::from("DONUT"),
+            table_name: String::from("orders"),
+            group_by_column: String::from("created_at"),
+            aggregate: String::from("MEDIAN"),
+            aggregate_column: None,
+            time_bucket: Some(String::from("quarter")),
+        });
+        assert_eq!(chart_definition.chart_type, ChartType::Bar);
+        assert_eq!(chart_definition.aggregate, Aggregate::Count);
+        assert_eq!(chart_definition.time_bucket, None);
+        assert_eq!(
+            TimeBucket::to_time_bucket(String::from("month")),
+            Some(TimeBucket::Month)
+        );
+    }
+}
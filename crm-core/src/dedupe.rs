@@ -0,0 +1,663 @@
+use crate::component::{
+    repository_module::BRepository, BAccessLevel, BCondition, BDataType, BDuplicateGroup,
+    BDuplicateKey, BForeignKeyReference, BMatchType, BPermission, BRowMerge, BSession,
+    BTableGeneral,
+};
+use crate::components::BusinessConsole;
+use crate::tables::utils::get_foreign_key_references;
+use sqlx::Row;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+// share of common trigrams two names need to count as the same person
+const NAME_SIMILARITY_THRESHOLD: f64 = 0.5;
+
+#[derive(Debug, Clone)]
+pub struct Dedupe {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    session: Arc<BSession>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    pub duplicate_groups: Arc<AsyncMutex<Vec<BDuplicateGroup>>>,
+}
+
+impl Dedupe {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            tables_general_info,
+            duplicate_groups: Arc::new(AsyncMutex::new(vec![])),
+        }
+    }
+
+    pub async fn find_duplicates(&self, table_name: String, duplicate_keys: Vec<BDuplicateKey>) {
+        *self.duplicate_groups.lock().await = vec![];
+        let table_general_info = {
+            self.tables_general_info
+                .lock()
+                .await
+                .iter()
+                .find(|table_general_info| table_general_info.table_name == table_name)
+                .cloned()
+        };
+        let Some(table_general_info) = table_general_info
+            .filter(|_| self.session.table_access_level(&table_name) != BAccessLevel::Hidden)
+        else {
+            return;
+        };
+        let primary_key_column_names = self
+            .repository
+            .get_primary_key_column_names(&table_name)
+            .await
+            .unwrap();
+        // hidden columns are neither compared nor shown, primary keys stay to merge the rows
+        let (column_names, data_types): (Vec<String>, Vec<BDataType>) = table_general_info
+            .column_names
+            .into_iter()
+            .zip(table_general_info.data_types)
+            .filter(|(column_name, _)| {
+                primary_key_column_names.contains(column_name)
+                    || self.session.column_access_level(&table_name, column_name)
+                        != BAccessLevel::Hidden
+            })
+            .unzip();
+        let key_column_indexes: Vec<(usize, BMatchType)> = duplicate_keys
+            .iter()
+            .filter_map(|duplicate_key| {
+                column_names
+                    .iter()
+                    .position(|column_name| *column_name == duplicate_key.column_name)
+                    .map(|index| (index, duplicate_key.match_type.clone()))
+            })
+            .collect();
+        if key_column_indexes.is_empty() {
+            return;
+        }
+
+        let rows: Vec<Vec<String>> = self
+            .repository
+            .get_table_data_rows(
+                &table_name,
+                &vec![],
+                &column_names,
+                &data_types,
+                &primary_key_column_names,
+                &vec![],
+                &vec![],
+            )
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| {
+                column_names
+                    .iter()
+                    .map(|column_name| row.get::<String, _>(column_name.as_str()))
+                    .collect()
+            })
+            .collect();
+
+        let normalized_keys: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                key_column_indexes
+                    .iter()
+                    .map(|(index, match_type)| normalize_value(&row[*index], match_type))
+                    .collect()
+            })
+            .collect();
+        let mut row_groups = RowGroups::new(rows.len());
+        let has_fuzzy_key = key_column_indexes
+            .iter()
+            .any(|(_, match_type)| *match_type == BMatchType::Name);
+
+        if has_fuzzy_key {
+            // similarity is not transitive, so pairs are compared, but only within the
+            // blocks of rows that share the other keys and a word of every name key
+            let mut rows_by_block_key: HashMap<Vec<String>, Vec<usize>> = HashMap::new();
+            for (row_index, normalized_key) in normalized_keys.iter().enumerate() {
+                for block_key in block_keys(normalized_key, &key_column_indexes) {
+                    rows_by_block_key
+                        .entry(block_key)
+                        .or_default()
+                        .push(row_index);
+                }
+            }
+            let mut compared_pairs: HashSet<(usize, usize)> = HashSet::new();
+            for row_indexes in rows_by_block_key.values() {
+                for (position, first_index) in row_indexes.iter().enumerate() {
+                    for second_index in &row_indexes[position + 1..] {
+                        if !compared_pairs.insert((*first_index, *second_index)) {
+                            continue;
+                        }
+                        let is_duplicate = key_column_indexes.iter().enumerate().all(
+                            |(key_index, (_, match_type))| {
+                                values_match(
+                                    &normalized_keys[*first_index][key_index],
+                                    &normalized_keys[*second_index][key_index],
+                                    match_type,
+                                )
+                            },
+                        );
+                        if is_duplicate {
+                            row_groups.join(*first_index, *second_index);
+                        }
+                    }
+                }
+            }
+        } else {
+            let mut first_row_by_key: HashMap<&Vec<String>, usize> = HashMap::new();
+            for (row_index, normalized_key) in normalized_keys.iter().enumerate() {
+                if normalized_key.iter().any(|value| value.is_empty()) {
+                    continue;
+                }
+                if let Some(first_row_index) = first_row_by_key.get(normalized_key) {
+                    row_groups.join(*first_row_index, row_index);
+                } else {
+                    first_row_by_key.insert(normalized_key, row_index);
+                }
+            }
+        }
+
+        let duplicate_groups = row_groups
+            .groups()
+            .into_iter()
+            .map(|row_indexes| BDuplicateGroup {
+                table_name: table_name.clone(),
+                column_names: column_names.clone(),
+                data_types: data_types.clone(),
+                primary_key_column_names: primary_key_column_names.clone(),
+                rows: row_indexes
+                    .into_iter()
+                    .map(|row_index| rows[row_index].clone())
+                    .collect(),
+            })
+            .collect();
+        *self.duplicate_groups.lock().await = duplicate_groups;
+    }
+
+    // surviving_row_index picks the row that is kept, merged_row holds the chosen
+    // value for every column; primary keys always come from the surviving row
+    pub async fn merge_duplicate_group(
+        &self,
+        group_index: usize,
+        surviving_row_index: usize,
+        merged_row: Vec<String>,
+    ) {
+        let duplicate_group = { self.duplicate_groups.lock().await.get(group_index).cloned() };
+        let Some(duplicate_group) = duplicate_group else {
+            return;
+        };
+        if duplicate_group.primary_key_column_names.is_empty() {
+            self.write_to_console(format!(
+                "Table \"{}\" needs a primary key before rows can be merged",
+                duplicate_group.table_name
+            ))
+            .await;
+            return;
+        }
+        let row_conditions = |row: &Vec<String>| -> Vec<BCondition> {
+            duplicate_group
+                .column_names
+                .iter()
+                .zip(&duplicate_group.data_types)
+                .zip(row)
+                .filter(|((column_name, _), _)| {
+                    duplicate_group
+                        .primary_key_column_names
+                        .contains(column_name)
+                })
+                .map(|((column_name, data_type), value)| BCondition {
+                    column_name: column_name.clone(),
+                    data_type: data_type.clone(),
+                    value: Some(value.clone()),
+                })
+                .collect()
+        };
+        let surviving_row = &duplicate_group.rows[surviving_row_index];
+        let row_merge = BRowMerge {
+            table_name: duplicate_group.table_name.clone(),
+            surviving_row_conditions: row_conditions(surviving_row),
+            losing_rows_conditions: duplicate_group
+                .rows
+                .iter()
+                .enumerate()
+                .filter(|(row_index, _)| *row_index != surviving_row_index)
+                .map(|(_, row)| row_conditions(row))
+                .collect(),
+            merged_column_values: duplicate_group
+                .column_names
+                .iter()
+                .zip(&duplicate_group.data_types)
+                .zip(surviving_row.iter().zip(&merged_row))
+                .filter(|((column_name, _), (surviving_value, merged_value))| {
+                    !duplicate_group
+                        .primary_key_column_names
+                        .contains(column_name)
+                        && surviving_value != merged_value
+                })
+                .map(|((column_name, data_type), (_, merged_value))| {
+                    (
+                        column_name.clone(),
+                        (data_type.clone(), Some(merged_value.clone())),
+                    )
+                })
+                .collect(),
+        };
+
+        let tables_general_info = { self.tables_general_info.lock().await.clone() };
+        let merge_result = match get_foreign_key_references(
+            self.repository.clone(),
+            &tables_general_info,
+            &duplicate_group.table_name,
+        )
+        .await
+        {
+            Ok(foreign_key_references) => {
+                if let Err(message) = self.authorize_row_merge(&row_merge, &foreign_key_references)
+                {
+                    self.write_to_console(message).await;
+                    return;
+                }
+                self.repository
+                    .merge_rows(&row_merge, &foreign_key_references)
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+        match merge_result {
+            Ok(()) => {
+                self.duplicate_groups.lock().await.remove(group_index);
+            }
+            Err(error) => {
+                self.write_to_console(format!(
+                    "Failed to merge rows of \"{}\": {}",
+                    duplicate_group.table_name, error
+                ))
+                .await;
+            }
+        }
+    }
+
+    // merging rewrites the surviving row, deletes the others and repoints their references
+    fn authorize_row_merge(
+        &self,
+        row_merge: &BRowMerge,
+        foreign_key_references: &Vec<BForeignKeyReference>,
+    ) -> Result<(), String> {
+        self.session.authorize(BPermission::EditRows)?;
+        self.session.authorize(BPermission::DeleteRows)?;
+        self.session.authorize_table_write(&row_merge.table_name)?;
+        for column_name in row_merge.merged_column_values.keys() {
+            self.session
+                .authorize_column_write(&row_merge.table_name, column_name)?;
+        }
+        for foreign_key_reference in foreign_key_references {
+            self.session.authorize_column_write(
+                &foreign_key_reference.table_name,
+                &foreign_key_reference.column_name,
+            )?;
+        }
+        Ok(())
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+fn normalize_value(value: &str, match_type: &BMatchType) -> String {
+    match match_type {
+        BMatchType::Exact => value.trim().to_string(),
+        BMatchType::Email => value.trim().to_lowercase(),
+        BMatchType::Phone => {
+            let digits: String = value.chars().filter(|c| c.is_ascii_digit()).collect();
+            // compare national numbers so "+1 555-0100" and "5550100" style prefixes match
+            if digits.len() > 10 {
+                digits[digits.len() - 10..].to_string()
+            } else {
+                digits
+            }
+        }
+        BMatchType::Name => {
+            let mut words: Vec<String> = value
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(|word| word.to_lowercase())
+                .collect();
+            // "Smith, John" and "John Smith" are the same name
+            words.sort();
+            words.join(" ")
+        }
+    }
+}
+
+fn values_match(first_value: &str, second_value: &str, match_type: &BMatchType) -> bool {
+    if first_value.is_empty() || second_value.is_empty() {
+        return false;
+    }
+    match match_type {
+        BMatchType::Name => name_similarity(first_value, second_value) >= NAME_SIMILARITY_THRESHOLD,
+        _ => first_value == second_value,
+    }
+}
+
+// one block key per combination of name words, rows with an empty key are never blocked
+fn block_keys(
+    normalized_key: &Vec<String>,
+    key_column_indexes: &Vec<(usize, BMatchType)>,
+) -> Vec<Vec<String>> {
+    let mut block_keys: Vec<Vec<String>> = vec![vec![]];
+    for (value, (_, match_type)) in normalized_key.iter().zip(key_column_indexes) {
+        if value.is_empty() {
+            return vec![];
+        }
+        let mut block_values: Vec<&str> = match match_type {
+            BMatchType::Name => value.split(' ').collect(),
+            _ => vec![value.as_str()],
+        };
+        block_values.dedup();
+        block_keys = block_keys
+            .into_iter()
+            .flat_map(|block_key| {
+                block_values.iter().map(move |block_value| {
+                    let mut block_key = block_key.clone();
+                    block_key.push(block_value.to_string());
+                    block_key
+                })
+            })
+            .collect();
+    }
+    block_keys
+}
+
+// trigram similarity in the style of pg_trgm, which may not be installed
+fn name_similarity(first_name: &str, second_name: &str) -> f64 {
+    let first_trigrams = trigrams(first_name);
+    let second_trigrams = trigrams(second_name);
+    let union_count = first_trigrams.union(&second_trigrams).count();
+    if union_count == 0 {
+        return 0.0;
+    }
+    first_trigrams.intersection(&second_trigrams).count() as f64 / union_count as f64
+}
+
+fn trigrams(name: &str) -> HashSet<String> {
+    name.split(' ')
+        .flat_map(|word| {
+            let padded: Vec<char> = format!("  {} ", word).chars().collect();
+            padded
+                .windows(3)
+                .map(|window| window.iter().collect::<String>())
+                .collect::<Vec<String>>()
+        })
+        .collect()
+}
+
+// union-find over row indexes
+struct RowGroups {
+    parents: Vec<usize>,
+}
+
+impl RowGroups {
+    fn new(rows_count: usize) -> Self {
+        Self {
+            parents: (0..rows_count).collect(),
+        }
+    }
+
+    fn root(&mut self, row_index: usize) -> usize {
+        let mut root = row_index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        self.parents[row_index] = root;
+        root
+    }
+
+    fn join(&mut self, first_row_index: usize, second_row_index: usize) {
+        let first_root = self.root(first_row_index);
+        let second_root = self.root(second_row_index);
+        if first_root != second_root {
+            self.parents[second_root] = first_root;
+        }
+    }
+
+    // groups with more than one row, in the order of their first row
+    fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = vec![];
+        let mut group_index_by_root: HashMap<usize, usize> = HashMap::new();
+        for row_index in 0..self.parents.len() {
+            let root = self.root(row_index);
+            if let Some(group_index) = group_index_by_root.get(&root) {
+                groups[*group_index].push(row_index);
+            } else {
+                group_index_by_root.insert(root, groups.len());
+                groups.push(vec![row_index]);
+            }
+        }
+        groups.into_iter().filter(|group| group.len() > 1).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{BAccessRule, BColumn, BConstraint, BRole, BTableIn};
+    use crate::tables::test_utils::{
+        create_insert_row_events, create_repository_table_and_console, create_session,
+        default_table_in,
+    };
+    use crate::tables::utils::set_tables_general_info;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_find_and_merge_duplicates(pool: PgPool) {
+        let users_table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool.clone(), &users_table_in).await;
+        let orders_table_in = BTableIn {
+            table_name: String::from("orders"),
+            columns: vec![
+                BColumn {
+                    name: String::from("id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::PrimaryKey],
+                },
+                BColumn {
+                    name: String::from("user_id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::ForeignKey(
+                        String::from("users"),
+                        String::from("id"),
+                    )],
+                },
+            ],
+        };
+        repository.create_table(&orders_table_in).await;
+        repository
+            .update_table_data(
+                &users_table_in.table_name,
+                &create_insert_row_events(
+                    &users_table_in,
+                    vec![
+                        vec!["1", "John O'Brien"],
+                        vec!["2", "O'Brien, John"],
+                        vec!["3", "Alice Jones"],
+                    ],
+                ),
+            )
+            .await
+            .unwrap();
+        repository
+            .update_table_data(
+                &orders_table_in.table_name,
+                &create_insert_row_events(&orders_table_in, vec![vec!["1", "2"], vec!["2", "3"]]),
+            )
+            .await
+            .unwrap();
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let session = create_session(BRole::Admin);
+        let dedupe = Dedupe::new(
+            repository.clone(),
+            console,
+            session.clone(),
+            tables_general_info,
+        );
+
+        dedupe
+            .find_duplicates(
+                users_table_in.table_name.clone(),
+                vec![BDuplicateKey {
+                    column_name: String::from("name"),
+                    match_type: BMatchType::Name,
+                }],
+            )
+            .await;
+        let duplicate_groups = dedupe.duplicate_groups.lock().await.clone();
+        assert_eq!(duplicate_groups.len(), 1);
+        assert_eq!(
+            duplicate_groups[0].rows,
+            vec![
+                vec![String::from("1"), String::from("John O'Brien")],
+                vec![String::from("2"), String::from("O'Brien, John")],
+            ]
+        );
+
+        // the references to the merged rows are rewritten, so they have to be writable
+        session.set_access_rules(vec![BAccessRule {
+            id: None,
+            role: BRole::Admin,
+            table_name: orders_table_in.table_name.clone(),
+            column_name: Some(String::from("user_id")),
+            access_level: BAccessLevel::Read,
+        }]);
+        dedupe
+            .merge_duplicate_group(0, 0, vec![String::from("2"), String::from("O'Brien, John")])
+            .await;
+        assert_eq!(dedupe.duplicate_groups.lock().await.len(), 1);
+
+        session.set_access_rules(vec![]);
+        dedupe
+            .merge_duplicate_group(0, 0, vec![String::from("2"), String::from("O'Brien, John")])
+            .await;
+        assert!(dedupe.duplicate_groups.lock().await.is_empty());
+
+        let users: Vec<(i32, String)> = sqlx::query_as("SELECT id, name FROM users ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        // the primary key stays with the surviving row, the other values are merged
+        assert_eq!(
+            users,
+            vec![
+                (1, String::from("O'Brien, John")),
+                (3, String::from("Alice Jones")),
+            ]
+        );
+        let order_user_ids: Vec<(i32,)> = sqlx::query_as("SELECT user_id FROM orders ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(order_user_ids, vec![(1,), (3,)]);
+
+        // the repointed reference, the deleted row and the merged values are audited
+        type AuditEntry = (String, String, Option<Vec<String>>, Option<Vec<String>>);
+        let audit_entries: Vec<AuditEntry> = sqlx::query_as(
+            "SELECT table_name, operation, old_values, new_values
+                 FROM crm_metadata.audit_log WHERE operation <> 'INSERT' ORDER BY id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            audit_entries,
+            vec![
+                (
+                    String::from("orders"),
+                    String::from("UPDATE"),
+                    Some(vec![String::from("1"), String::from("2")]),
+                    Some(vec![String::from("1"), String::from("1")]),
+                ),
+                (
+                    String::from("users"),
+                    String::from("DELETE"),
+                    Some(vec![String::from("2"), String::from("O'Brien, John")]),
+                    None,
+                ),
+                (
+                    String::from("users"),
+                    String::from("UPDATE"),
+                    Some(vec![String::from("1"), String::from("John O'Brien")]),
+                    Some(vec![String::from("1"), String::from("O'Brien, John")]),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_value() {
+        assert_eq!(normalize_value(" John ", &BMatchType::Exact), "John");
+        assert_eq!(
+            normalize_value(" John@Example.com ", &BMatchType::Email),
+            "john@example.com"
+        );
+        assert_eq!(
+            normalize_value("+1 (555) 010-0100", &BMatchType::Phone),
+            "5550100100"
+        );
+        assert_eq!(normalize_value("555-0100", &BMatchType::Phone), "5550100");
+        assert_eq!(
+            normalize_value("O'Brien,  John", &BMatchType::Name),
+            normalize_value("john o brien", &BMatchType::Name)
+        );
+    }
+
+    #[test]
+    fn test_name_similarity() {
+        assert_eq!(name_similarity("john smith", "john smith"), 1.0);
+        assert!(values_match("jon smith", "john smith", &BMatchType::Name));
+        assert!(!values_match(
+            "alice jones",
+            "john smith",
+            &BMatchType::Name
+        ));
+        // an empty value never matches, not even another empty one
+        assert!(!values_match("", "", &BMatchType::Exact));
+    }
+
+    #[test]
+    fn test_block_keys() {
+        let key_column_indexes = vec![(0, BMatchType::Email), (1, BMatchType::Name)];
+        assert_eq!(
+            block_keys(
+                &vec![String::from("john@example.com"), String::from("john smith")],
+                &key_column_indexes
+            ),
+            vec![
+                vec![String::from("john@example.com"), String::from("john")],
+                vec![String::from("john@example.com"), String::from("smith")],
+            ]
+        );
+        assert!(block_keys(
+            &vec![String::new(), String::from("john smith")],
+            &key_column_indexes
+        )
+        .is_empty());
+    }
+}
@@ -1,13 +1,10 @@
-use crate::components::business_components::component::{
-    repository_module::BRepository, BColumn, BDataType, BTableIn, BTableInfo, BusinessComponent,
-};
-use crate::components::business_components::components::BusinessConsole;
-use std::sync::{Arc, Mutex};
+use crate::component::BusinessComponent;
+use crate::components::BusinessConsole;
+use std::sync::Arc;
 use tokio::sync::Mutex as AsyncMutex;
 
 #[derive(Debug, Clone)]
 pub struct Home {
-    repository: Arc<BRepository>,
     pub title: Arc<AsyncMutex<Option<String>>>,
     console: Arc<BusinessConsole>,
 }
@@ -22,9 +19,8 @@ impl BusinessComponent for Home {
 }
 
 impl Home {
-    pub fn new(repository: Arc<BRepository>, console: Arc<BusinessConsole>) -> Self {
+    pub fn new(console: Arc<BusinessConsole>) -> Self {
         Self {
-            repository,
             title: Arc::new(AsyncMutex::new(None)),
             console,
         }
@@ -0,0 +1,32 @@
+// database access, schemas and business components shared by the app and crm-cli,
+// mostly used through the aliases of component and components
+#![allow(
+    async_fn_in_trait,
+    clippy::module_inception,
+    clippy::ptr_arg,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms
+)]
+mod access_rules;
+mod audit;
+mod authentication;
+mod backup;
+pub mod component;
+pub mod components;
+mod console;
+mod dashboard;
+mod data_transfer;
+mod database;
+mod dedupe;
+mod home;
+mod schema_comparison;
+mod schema_diagram;
+mod schema_file;
+mod search;
+mod session;
+mod tables;
+mod users;
+
+pub use crate::database::{console::RepositoryConsole, models, repository::Repository, schemas};
+pub use crate::schema_file::SchemaFile;
+pub use crate::session::Session;
@@ -0,0 +1,308 @@
+use crate::component::{
+    repository_module::{BRepository, BRepositoryConsole},
+    BPermission, BSchemaDefinition, BSchemaDiff, BSession, BTableIn,
+};
+use crate::components::BusinessConsole;
+use crate::schema_file::{create_schema_plan, sort_by_foreign_keys};
+use crate::tables::utils::get_tables_in;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+#[derive(Debug, Clone)]
+pub struct SchemaComparison {
+    repository: Arc<BRepository>,
+    repository_console: Arc<BRepositoryConsole>,
+    console: Arc<BusinessConsole>,
+    session: Arc<BSession>,
+    pub schema_diff: Arc<AsyncMutex<Option<BSchemaDiff>>>,
+}
+
+impl SchemaComparison {
+    pub fn new(
+        repository: Arc<BRepository>,
+        repository_console: Arc<BRepositoryConsole>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+    ) -> Self {
+        Self {
+            repository,
+            repository_console,
+            console,
+            session,
+            schema_diff: Arc::new(AsyncMutex::new(None)),
+        }
+    }
+
+    // an empty url is the database the app is connected to
+    async fn get_repository(&self, database_url: &str) -> Result<Arc<BRepository>, String> {
+        if database_url.trim().is_empty() {
+            return Ok(self.repository.clone());
+        }
+        BRepository::connect(database_url.trim(), self.repository_console.clone())
+            .await
+            .map(Arc::new)
+            .map_err(|error| format!("Could not connect to \"{}\": {}", database_url, error))
+    }
+
+    // only the pools connected for the comparison are closed, not the app's own
+    async fn close_repository(&self, repository: &Arc<BRepository>) {
+        if !Arc::ptr_eq(repository, &self.repository) {
+            repository.close().await;
+        }
+    }
+
+    pub async fn compare_schemas(
+        &self,
+        source_database_url: String,
+        target_database_url: String,
+    ) -> Result<(), String> {
+        *self.schema_diff.lock().await = None;
+        self.session.authorize(BPermission::AlterSchema)?;
+        let source_repository = self.get_repository(&source_database_url).await?;
+        let target_repository = match self.get_repository(&target_database_url).await {
+            Ok(target_repository) => target_repository,
+            Err(message) => {
+                self.close_repository(&source_repository).await;
+                return Err(message);
+            }
+        };
+        let schema_diff = async {
+            let source_tables = get_tables_in(source_repository.clone())
+                .await
+                .map_err(|error| format!("Reading the source schema failed: {}", error))?;
+            create_schema_diff(source_tables, &target_repository)
+                .await
+                .map_err(|error| format!("Reading the target schema failed: {}", error))
+        }
+        .await;
+        self.close_repository(&source_repository).await;
+        self.close_repository(&target_repository).await;
+        let schema_diff = schema_diff?;
+        self.write_to_console(format!(
+            "Schema comparison: {} added, {} removed and {} changed tables",
+            schema_diff.added_tables.len(),
+            schema_diff.removed_tables.len(),
+            schema_diff.changed_tables.len()
+        ))
+        .await;
+        *self.schema_diff.lock().await = Some(schema_diff);
+        Ok(())
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+/* the migration creates the new tables first, so altered tables can reference
+ * them, and drops the removed tables last */
+pub async fn create_schema_diff(
+    source_tables: Vec<BTableIn>,
+    target_repository: &Arc<BRepository>,
+) -> Result<BSchemaDiff, String> {
+    let target_tables = get_tables_in(target_repository.clone()).await?;
+    let schema_plan = create_schema_plan(
+        &target_tables,
+        &BSchemaDefinition {
+            tables: source_tables,
+        },
+    );
+
+    let mut migration_queries: Vec<String> = schema_plan
+        .create_tables
+        .iter()
+        .map(BRepository::create_table_query)
+        .collect();
+    for table_alteration in &schema_plan.alter_tables {
+        let primary_key_constraint = target_repository
+            .get_primary_key_constraint(&table_alteration.table_name)
+            .await
+            .map_err(|error| error.to_string())?;
+        let foreign_key_constraints = target_repository
+            .get_foreign_key_constraints(&table_alteration.table_name)
+            .await
+            .map_err(|error| error.to_string())?;
+        migration_queries.extend(BRepository::alter_table_queries(
+            &table_alteration.table_name,
+            &table_alteration.table_change_events,
+            &table_alteration.primary_key_column_names,
+            primary_key_constraint.as_ref(),
+            &foreign_key_constraints,
+            &vec![],
+        ));
+    }
+    // referencing tables are dropped before the tables they reference
+    let removed_tables: Vec<BTableIn> = target_tables
+        .into_iter()
+        .filter(|table_in| schema_plan.untouched_tables.contains(&table_in.table_name))
+        .collect();
+    let removed_table_names: Vec<String> = sort_by_foreign_keys(removed_tables)
+        .into_iter()
+        .rev()
+        .map(|table_in| table_in.table_name)
+        .collect();
+    migration_queries.extend(
+        removed_table_names
+            .iter()
+            .map(|table_name| BRepository::delete_table_query(table_name)),
+    );
+
+    Ok(BSchemaDiff {
+        added_tables: schema_plan.create_tables,
+        removed_tables: removed_table_names,
+        changed_tables: schema_plan.alter_tables,
+        migration_queries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{BColumn, BConstraint, BDataType, BRole, BTableChangeEvents};
+    use crate::tables::test_utils::{
+        create_database_console, create_repository_table_and_console, create_session,
+        default_table_in,
+    };
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_schema_diff_and_migration_script(pool: PgPool) {
+        let users_table_in = default_table_in();
+        let (repository, _) =
+            create_repository_table_and_console(pool.clone(), &users_table_in).await;
+        repository
+            .create_table(&BTableIn {
+                table_name: String::from("notes"),
+                columns: vec![BColumn {
+                    name: String::from("id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::PrimaryKey],
+                }],
+            })
+            .await;
+
+        // the source has no notes, a users table with an email and a new orders table
+        let mut source_users_table_in = users_table_in.clone();
+        source_users_table_in.columns.push(BColumn {
+            name: String::from("email"),
+            datatype: BDataType::TEXT,
+            constraints: vec![],
+        });
+        let orders_table_in = BTableIn {
+            table_name: String::from("orders"),
+            columns: vec![
+                BColumn {
+                    name: String::from("id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::PrimaryKey],
+                },
+                BColumn {
+                    name: String::from("user_id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::ForeignKey(
+                        String::from("users"),
+                        String::from("id"),
+                    )],
+                },
+            ],
+        };
+        let source_tables = vec![source_users_table_in, orders_table_in.clone()];
+
+        let schema_diff = create_schema_diff(source_tables.clone(), &repository)
+            .await
+            .unwrap();
+        assert_eq!(schema_diff.added_tables, vec![orders_table_in.clone()]);
+        assert_eq!(schema_diff.removed_tables, vec![String::from("notes")]);
+        assert_eq!(schema_diff.changed_tables.len(), 1);
+        assert_eq!(
+            schema_diff.changed_tables[0].table_change_events,
+            vec![BTableChangeEvents::AddColumn(
+                String::from("email"),
+                BDataType::TEXT
+            )]
+        );
+        assert_eq!(
+            schema_diff.migration_queries,
+            vec![
+                BRepository::create_table_query(&orders_table_in),
+                String::from("ALTER TABLE \"users\" ADD COLUMN \"email\" TEXT"),
+                String::from("DROP TABLE \"notes\""),
+            ]
+        );
+
+        // running the script on the target leaves nothing to migrate
+        for query in &schema_diff.migration_queries {
+            sqlx::query(query).execute(&pool).await.unwrap();
+        }
+        let schema_diff = create_schema_diff(source_tables, &repository)
+            .await
+            .unwrap();
+        assert!(schema_diff.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_compare_schemas_requires_alter_schema(pool: PgPool) {
+        let (repository, console) =
+            create_repository_table_and_console(pool, &default_table_in()).await;
+        let session = create_session(BRole::Viewer);
+        let schema_comparison = SchemaComparison::new(
+            repository.clone(),
+            create_database_console(),
+            console.clone(),
+            session,
+        );
+        assert!(schema_comparison
+            .compare_schemas(String::new(), String::new())
+            .await
+            .is_err());
+        assert!(schema_comparison.schema_diff.lock().await.is_none());
+
+        let schema_comparison = SchemaComparison::new(
+            repository,
+            create_database_console(),
+            console,
+            create_session(BRole::Admin),
+        );
+        schema_comparison
+            .compare_schemas(String::new(), String::new())
+            .await
+            .unwrap();
+        assert!(schema_comparison
+            .schema_diff
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|schema_diff| schema_diff.is_empty()));
+    }
+
+    #[sqlx::test]
+    async fn test_compare_schemas_reports_unsupported_column_types(pool: PgPool) {
+        let (repository, console) =
+            create_repository_table_and_console(pool.clone(), &default_table_in()).await;
+        sqlx::query("CREATE TABLE notes (title VARCHAR(20))")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let schema_comparison = SchemaComparison::new(
+            repository,
+            create_database_console(),
+            console,
+            create_session(BRole::Admin),
+        );
+        assert_eq!(
+            schema_comparison
+                .compare_schemas(String::new(), String::new())
+                .await,
+            Err(String::from(
+                "Reading the source schema failed: unsupported column type character varying in table notes"
+            ))
+        );
+        assert!(schema_comparison.schema_diff.lock().await.is_none());
+    }
+}
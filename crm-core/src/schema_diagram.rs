@@ -0,0 +1,300 @@
+use crate::component::{
+    repository_module::BRepository, BColumn, BDiagramTable, BNodePosition, BTableGeneral,
+    BusinessComponent,
+};
+use crate::components::BusinessConsole;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+const LAYOUT_HORIZONTAL_GAP: f32 = 120.0;
+const LAYOUT_VERTICAL_GAP: f32 = 40.0;
+
+#[derive(Debug, Clone)]
+pub struct SchemaDiagram {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    pub diagram_tables: Arc<AsyncMutex<Vec<BDiagramTable>>>,
+    pub node_positions: Arc<AsyncMutex<Vec<BNodePosition>>>,
+}
+
+impl BusinessComponent for SchemaDiagram {
+    async fn initialize_component(&self) {
+        self.repository
+            .create_diagram_positions_table()
+            .await
+            .unwrap();
+        self.set_schema_diagram().await;
+    }
+}
+
+impl SchemaDiagram {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            tables_general_info,
+            diagram_tables: Arc::new(AsyncMutex::new(vec![])),
+            node_positions: Arc::new(AsyncMutex::new(vec![])),
+        }
+    }
+
+    pub async fn set_schema_diagram(&self) {
+        self.set_diagram_tables().await;
+        self.set_node_positions().await;
+    }
+
+    async fn set_diagram_tables(&self) {
+        let table_names: Vec<String> = self
+            .tables_general_info
+            .lock()
+            .await
+            .iter()
+            .map(|table_general_info| table_general_info.table_name.clone())
+            .collect();
+        let mut diagram_tables = vec![];
+        for table_name in table_names {
+            let columns = self
+                .repository
+                .get_columns_info(&table_name)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(BColumn::to_column)
+                .collect();
+            diagram_tables.push(BDiagramTable {
+                table_name,
+                columns,
+            });
+        }
+        *self.diagram_tables.lock().await = diagram_tables;
+    }
+
+    // saved positions win, tables added since the last save are auto-laid out
+    async fn set_node_positions(&self) {
+        let saved_positions: HashMap<String, BNodePosition> = self
+            .repository
+            .get_node_positions()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|node_position_info| {
+                let node_position = BNodePosition::to_node_position(node_position_info);
+                (node_position.table_name.clone(), node_position)
+            })
+            .collect();
+        let diagram_tables = self.diagram_tables.lock().await;
+        let node_positions = auto_layout(&diagram_tables)
+            .into_iter()
+            .map(|node_position| {
+                saved_positions
+                    .get(&node_position.table_name)
+                    .cloned()
+                    .unwrap_or(node_position)
+            })
+            .collect();
+        *self.node_positions.lock().await = node_positions;
+    }
+
+    pub async fn auto_layout_nodes(&self) {
+        let node_positions = auto_layout(&self.diagram_tables.lock().await);
+        self.save_node_positions(&node_positions).await;
+        *self.node_positions.lock().await = node_positions;
+    }
+
+    pub async fn move_node(&self, table_name: &str, x: f32, y: f32) {
+        let node_position = BNodePosition {
+            table_name: table_name.to_string(),
+            x,
+            y,
+        };
+        self.save_node_positions(&vec![node_position.clone()]).await;
+        let mut node_positions = self.node_positions.lock().await;
+        if let Some(current_position) = node_positions
+            .iter_mut()
+            .find(|current_position| current_position.table_name == table_name)
+        {
+            *current_position = node_position;
+        }
+    }
+
+    async fn save_node_positions(&self, node_positions: &Vec<BNodePosition>) {
+        if let Err(error) = self.repository.save_node_positions(node_positions).await {
+            self.write_to_console(format!("Diagram positions failed to save: {}", error))
+                .await;
+        }
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+/* tables are placed in columns by foreign key depth, so referenced tables
+ * sit left of the tables pointing at them */
+pub fn auto_layout(diagram_tables: &[BDiagramTable]) -> Vec<BNodePosition> {
+    let mut levels: HashMap<String, usize> = HashMap::new();
+    for diagram_table in diagram_tables {
+        table_level(
+            diagram_tables,
+            &diagram_table.table_name,
+            &mut levels,
+            &mut vec![],
+        );
+    }
+
+    let mut column_heights: HashMap<usize, f32> = HashMap::new();
+    diagram_tables
+        .iter()
+        .map(|diagram_table| {
+            let level = levels[&diagram_table.table_name];
+            let y = column_heights.entry(level).or_insert(0.0);
+            let node_position = BNodePosition {
+                table_name: diagram_table.table_name.clone(),
+                x: level as f32 * (BDiagramTable::NODE_WIDTH + LAYOUT_HORIZONTAL_GAP),
+                y: *y,
+            };
+            *y += diagram_table.node_height() + LAYOUT_VERTICAL_GAP;
+            node_position
+        })
+        .collect()
+}
+
+fn table_level(
+    diagram_tables: &[BDiagramTable],
+    table_name: &str,
+    levels: &mut HashMap<String, usize>,
+    visiting: &mut Vec<String>,
+) -> usize {
+    if let Some(level) = levels.get(table_name) {
+        return *level;
+    }
+    // self references and foreign key cycles don't push a table further right
+    if visiting.iter().any(|visited| visited == table_name) {
+        return 0;
+    }
+    let Some(diagram_table) = diagram_tables
+        .iter()
+        .find(|diagram_table| diagram_table.table_name == table_name)
+    else {
+        return 0;
+    };
+    visiting.push(table_name.to_string());
+    let level = diagram_table
+        .referenced_tables()
+        .iter()
+        .filter(|referenced_table| referenced_table.as_str() != table_name)
+        .filter(|referenced_table| {
+            diagram_tables
+                .iter()
+                .any(|diagram_table| &diagram_table.table_name == *referenced_table)
+        })
+        .map(|referenced_table| table_level(diagram_tables, referenced_table, levels, visiting) + 1)
+        .max()
+        .unwrap_or(0);
+    visiting.pop();
+    levels.insert(table_name.to_string(), level);
+    level
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{BConstraint, BDataType, BTableIn};
+    use crate::tables::test_utils::{create_repository_table_and_console, default_table_in};
+    use crate::tables::utils::set_tables_general_info;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_schema_diagram_layout_and_positions(pool: PgPool) {
+        let users_table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool, &users_table_in).await;
+        let orders_table_in = BTableIn {
+            table_name: String::from("orders"),
+            columns: vec![
+                BColumn {
+                    name: String::from("id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::PrimaryKey],
+                },
+                BColumn {
+                    name: String::from("user_id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::ForeignKey(
+                        String::from("users"),
+                        String::from("id"),
+                    )],
+                },
+            ],
+        };
+        repository.create_table(&orders_table_in).await;
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let schema_diagram = SchemaDiagram::new(
+            repository.clone(),
+            console.clone(),
+            tables_general_info.clone(),
+        );
+        schema_diagram.initialize_component().await;
+
+        let orders_table = schema_diagram
+            .diagram_tables
+            .lock()
+            .await
+            .iter()
+            .find(|diagram_table| diagram_table.table_name == "orders")
+            .cloned()
+            .unwrap();
+        assert_eq!(
+            orders_table.referenced_tables(),
+            vec![String::from("users")]
+        );
+
+        let node_position = |table_name: &str, node_positions: &Vec<BNodePosition>| {
+            node_positions
+                .iter()
+                .find(|node_position| node_position.table_name == table_name)
+                .cloned()
+                .unwrap()
+        };
+        {
+            let node_positions = schema_diagram.node_positions.lock().await;
+            assert_eq!(node_position("users", &node_positions).x, 0.0);
+            assert_eq!(
+                node_position("orders", &node_positions).x,
+                BDiagramTable::NODE_WIDTH + LAYOUT_HORIZONTAL_GAP
+            );
+        }
+
+        // a moved node keeps its position when the diagram is loaded again
+        schema_diagram.move_node("users", 500.0, 250.0).await;
+        let reloaded_schema_diagram = SchemaDiagram::new(repository, console, tables_general_info);
+        reloaded_schema_diagram.initialize_component().await;
+        let node_positions = reloaded_schema_diagram.node_positions.lock().await;
+        assert_eq!(
+            node_position("users", &node_positions),
+            BNodePosition {
+                table_name: String::from("users"),
+                x: 500.0,
+                y: 250.0,
+            }
+        );
+        assert_eq!(
+            node_position("orders", &node_positions).x,
+            BDiagramTable::NODE_WIDTH + LAYOUT_HORIZONTAL_GAP
+        );
+    }
+}
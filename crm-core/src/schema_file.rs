@@ -0,0 +1,514 @@
+use crate::component::{
+    repository_module::BRepository, BColumn, BColumnForeignKey, BConstraint, BDataType,
+    BPermission, BSchemaDefinition, BSchemaPlan, BSession, BTableAlteration, BTableChangeEvents,
+    BTableGeneral, BTableIn,
+};
+use crate::components::BusinessConsole;
+use crate::tables::utils::{get_table_in, set_tables_general_info};
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::{fs, task};
+
+#[derive(Debug, Clone)]
+pub struct SchemaFile {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    session: Arc<BSession>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    pub schema_plan: Arc<AsyncMutex<Option<BSchemaPlan>>>,
+}
+
+impl SchemaFile {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            tables_general_info,
+            schema_plan: Arc::new(AsyncMutex::new(None)),
+        }
+    }
+
+    async fn get_live_tables(&self) -> Result<Vec<BTableIn>, String> {
+        let table_names: Vec<String> = self
+            .tables_general_info
+            .lock()
+            .await
+            .iter()
+            .map(|table_general_info| table_general_info.table_name.clone())
+            .collect();
+        let mut live_tables = vec![];
+        for table_name in table_names {
+            live_tables.push(get_table_in(self.repository.clone(), &table_name).await?);
+        }
+        Ok(live_tables)
+    }
+
+    pub async fn export_schema(&self, path: String) -> Result<(), String> {
+        let schema_definition = BSchemaDefinition {
+            tables: sort_by_foreign_keys(self.get_live_tables().await?),
+        };
+        let content = to_schema_file_content(&schema_definition)?;
+        fs::write(&path, content)
+            .await
+            .map_err(|error| format!("Could not write \"{}\": {}", path, error))?;
+        self.write_to_console(format!(
+            "Exported {} tables to \"{}\"",
+            schema_definition.tables.len(),
+            path
+        ))
+        .await;
+        Ok(())
+    }
+
+    pub async fn load_schema_file(&self, path: String) -> Result<(), String> {
+        let content = fs::read_to_string(&path)
+            .await
+            .map_err(|error| format!("Could not read \"{}\": {}", path, error))?;
+        let schema_definition = from_schema_file_content(&content)?;
+        self.set_schema_plan(schema_definition).await
+    }
+
+    pub async fn set_schema_plan(
+        &self,
+        schema_definition: BSchemaDefinition,
+    ) -> Result<(), String> {
+        let live_tables = self.get_live_tables().await?;
+        *self.schema_plan.lock().await = Some(create_schema_plan(&live_tables, &schema_definition));
+        Ok(())
+    }
+
+    pub async fn discard_schema_plan(&self) {
+        *self.schema_plan.lock().await = None;
+    }
+
+    pub async fn apply_schema_plan(&self) -> Result<(), String> {
+        self.session.authorize(BPermission::AlterSchema)?;
+        // a plan that fails to apply is kept so it can be reviewed again
+        let Some(schema_plan) = self.schema_plan.lock().await.clone() else {
+            return Ok(());
+        };
+        self.repository
+            .apply_schema_plan(&schema_plan)
+            .await
+            .map_err(|error| {
+                format!("Applying the schema failed, nothing was changed: {}", error)
+            })?;
+        *self.schema_plan.lock().await = None;
+        set_tables_general_info(self.repository.clone(), self.tables_general_info.clone()).await;
+        Ok(())
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+pub fn to_schema_file_content(schema_definition: &BSchemaDefinition) -> Result<String, String> {
+    toml::to_string_pretty(schema_definition).map_err(|error| error.to_string())
+}
+
+pub fn from_schema_file_content(content: &str) -> Result<BSchemaDefinition, String> {
+    toml::from_str(content).map_err(|error| format!("Invalid schema file: {}", error))
+}
+
+// referenced tables come first so the tables can be created in file order
+pub fn sort_by_foreign_keys(mut tables: Vec<BTableIn>) -> Vec<BTableIn> {
+    let mut sorted_tables: Vec<BTableIn> = vec![];
+    while !tables.is_empty() {
+        let ready_index = tables.iter().position(|table_in| {
+            table_in
+                .columns
+                .iter()
+                .filter_map(foreign_key)
+                .all(|(referenced_table, _)| {
+                    referenced_table == table_in.table_name
+                        || sorted_tables
+                            .iter()
+                            .any(|sorted_table| sorted_table.table_name == referenced_table)
+                        || !tables
+                            .iter()
+                            .any(|other_table| other_table.table_name == referenced_table)
+                })
+        });
+        // tables in a foreign key cycle keep their order
+        sorted_tables.push(tables.remove(ready_index.unwrap_or(0)));
+    }
+    sorted_tables
+}
+
+pub fn create_schema_plan(
+    live_tables: &[BTableIn],
+    schema_definition: &BSchemaDefinition,
+) -> BSchemaPlan {
+    let mut schema_plan = BSchemaPlan::default();
+    for table_in in sort_by_foreign_keys(schema_definition.tables.clone()) {
+        match live_tables
+            .iter()
+            .find(|live_table| live_table.table_name == table_in.table_name)
+        {
+            Some(live_table) => {
+                let table_change_events = diff_table(live_table, &table_in);
+                if !table_change_events.is_empty() {
+                    schema_plan.alter_tables.push(BTableAlteration {
+                        table_name: table_in.table_name.clone(),
+                        table_change_events,
+                        primary_key_column_names: live_table
+                            .columns
+                            .iter()
+                            .filter(|column| is_primary_key(column))
+                            .map(|column| column.name.clone())
+                            .collect(),
+                    });
+                }
+            }
+            None => schema_plan.create_tables.push(table_in),
+        }
+    }
+    schema_plan.untouched_tables = live_tables
+        .iter()
+        .filter(|live_table| {
+            !schema_definition
+                .tables
+                .iter()
+                .any(|table_in| table_in.table_name == live_table.table_name)
+        })
+        .map(|live_table| live_table.table_name.clone())
+        .collect();
+    schema_plan
+}
+
+/* columns are matched by name, a renamed column shows up as a drop and an add */
+pub fn diff_table(live_table: &BTableIn, table_in: &BTableIn) -> Vec<BTableChangeEvents> {
+    let mut table_change_events = vec![];
+    let find_live_column = |column_name: &str| {
+        live_table
+            .columns
+            .iter()
+            .find(|live_column| live_column.name == column_name)
+    };
+
+    for live_column in &live_table.columns {
+        if !table_in
+            .columns
+            .iter()
+            .any(|column| column.name == live_column.name)
+        {
+            table_change_events.push(BTableChangeEvents::RemoveColumn(live_column.name.clone()));
+        }
+    }
+
+    for column in &table_in.columns {
+        let Some(live_column) = find_live_column(&column.name) else {
+            table_change_events.push(BTableChangeEvents::AddColumn(
+                column.name.clone(),
+                column.datatype.clone(),
+            ));
+            continue;
+        };
+        if !is_same_data_type(&live_column.datatype, &column.datatype) {
+            table_change_events.push(BTableChangeEvents::ChangeColumnDataType(
+                column.name.clone(),
+                column.datatype.clone(),
+            ));
+        }
+        if foreign_key(live_column).is_some() && foreign_key(live_column) != foreign_key(column) {
+            table_change_events.push(BTableChangeEvents::RemoveForeignKey(column.name.clone()));
+        }
+        if is_primary_key(live_column) && !is_primary_key(column) {
+            table_change_events.push(BTableChangeEvents::RemovePrimaryKey(column.name.clone()));
+        }
+    }
+
+    for column in &table_in.columns {
+        let live_column = find_live_column(&column.name);
+        let live_foreign_key = live_column.and_then(foreign_key);
+        if let Some((referenced_table, referenced_column)) = foreign_key(column) {
+            if live_foreign_key != Some((referenced_table.clone(), referenced_column.clone())) {
+                table_change_events.push(BTableChangeEvents::AddForeignKey(BColumnForeignKey {
+                    column_name: column.name.clone(),
+                    referenced_column,
+                    referenced_table,
+                }));
+            }
+        }
+        if is_primary_key(column) && !live_column.is_some_and(is_primary_key) {
+            table_change_events.push(BTableChangeEvents::AddPrimaryKey(column.name.clone()));
+        }
+    }
+    table_change_events
+}
+
+// serial columns are reported as integers by the database
+fn is_same_data_type(live_data_type: &BDataType, data_type: &BDataType) -> bool {
+    let normalize = |data_type: &BDataType| match data_type {
+        BDataType::SERIAL => BDataType::INTEGER,
+        data_type => data_type.clone(),
+    };
+    normalize(live_data_type) == normalize(data_type)
+}
+
+fn foreign_key(column: &BColumn) -> Option<(String, String)> {
+    column
+        .constraints
+        .iter()
+        .find_map(|constraint| match constraint {
+            BConstraint::ForeignKey(referenced_table, referenced_column) => {
+                Some((referenced_table.clone(), referenced_column.clone()))
+            }
+            BConstraint::PrimaryKey => None,
+        })
+}
+
+fn is_primary_key(column: &BColumn) -> bool {
+    column.constraints.contains(&BConstraint::PrimaryKey)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::BRole;
+    use crate::tables::test_utils::{
+        create_repository_table_and_console, create_session, default_table_in,
+    };
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_load_and_apply_schema_file(pool: PgPool) {
+        let users_table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool, &users_table_in).await;
+        let notes_table_in = BTableIn {
+            table_name: String::from("notes"),
+            columns: vec![BColumn {
+                name: String::from("id"),
+                datatype: BDataType::INTEGER,
+                constraints: vec![BConstraint::PrimaryKey],
+            }],
+        };
+        repository.create_table(&notes_table_in).await;
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let schema_file = SchemaFile::new(
+            repository.clone(),
+            console,
+            create_session(BRole::Admin),
+            tables_general_info,
+        );
+
+        let mut target_users_table_in = users_table_in.clone();
+        target_users_table_in.columns.push(BColumn {
+            name: String::from("email"),
+            datatype: BDataType::TEXT,
+            constraints: vec![],
+        });
+        let orders_table_in = BTableIn {
+            table_name: String::from("orders"),
+            columns: vec![
+                BColumn {
+                    name: String::from("id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::PrimaryKey],
+                },
+                BColumn {
+                    name: String::from("user_id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::ForeignKey(
+                        String::from("users"),
+                        String::from("id"),
+                    )],
+                },
+            ],
+        };
+        // the file lists orders before the users table it references
+        let content = to_schema_file_content(&BSchemaDefinition {
+            tables: vec![orders_table_in.clone(), target_users_table_in],
+        })
+        .unwrap();
+        let schema_definition = from_schema_file_content(&content).unwrap();
+        schema_file
+            .set_schema_plan(schema_definition.clone())
+            .await
+            .unwrap();
+
+        let schema_plan = schema_file.schema_plan.lock().await.clone().unwrap();
+        assert_eq!(schema_plan.create_tables, vec![orders_table_in]);
+        assert_eq!(
+            schema_plan.alter_tables,
+            vec![BTableAlteration {
+                table_name: String::from("users"),
+                table_change_events: vec![BTableChangeEvents::AddColumn(
+                    String::from("email"),
+                    BDataType::TEXT
+                )],
+                primary_key_column_names: vec![String::from("id")],
+            }]
+        );
+        assert_eq!(schema_plan.untouched_tables, vec![String::from("notes")]);
+
+        schema_file.apply_schema_plan().await.unwrap();
+        assert!(schema_file.schema_plan.lock().await.is_none());
+
+        // once applied the database matches the file
+        schema_file
+            .set_schema_plan(schema_definition)
+            .await
+            .unwrap();
+        assert!(schema_file
+            .schema_plan
+            .lock()
+            .await
+            .clone()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_export_and_load_schema_file(pool: PgPool) {
+        let users_table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool, &users_table_in).await;
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let schema_file = SchemaFile::new(
+            repository,
+            console,
+            create_session(BRole::Admin),
+            tables_general_info,
+        );
+        let path = std::env::temp_dir()
+            .join(format!("crm_schema_{}.toml", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+
+        schema_file.export_schema(path.clone()).await.unwrap();
+        assert_eq!(
+            from_schema_file_content(&fs::read_to_string(&path).await.unwrap()).unwrap(),
+            BSchemaDefinition {
+                tables: vec![users_table_in],
+            }
+        );
+        schema_file.load_schema_file(path.clone()).await.unwrap();
+        assert!(schema_file
+            .schema_plan
+            .lock()
+            .await
+            .clone()
+            .unwrap()
+            .is_empty());
+        fs::remove_file(&path).await.unwrap();
+
+        assert!(schema_file.load_schema_file(path).await.is_err());
+    }
+
+    #[sqlx::test]
+    async fn test_change_foreign_key_target(pool: PgPool) {
+        let users_table_in = default_table_in();
+        let (repository, console) =
+            create_repository_table_and_console(pool.clone(), &users_table_in).await;
+        let id_column = BColumn {
+            name: String::from("id"),
+            datatype: BDataType::INTEGER,
+            constraints: vec![BConstraint::PrimaryKey],
+        };
+        let customers_table_in = BTableIn {
+            table_name: String::from("customers"),
+            columns: vec![id_column.clone()],
+        };
+        let orders_table_in = |referenced_table: &str| BTableIn {
+            table_name: String::from("orders"),
+            columns: vec![
+                id_column.clone(),
+                BColumn {
+                    name: String::from("user_id"),
+                    datatype: BDataType::INTEGER,
+                    constraints: vec![BConstraint::ForeignKey(
+                        String::from(referenced_table),
+                        String::from("id"),
+                    )],
+                },
+            ],
+        };
+        repository.create_table(&customers_table_in).await;
+        repository.create_table(&orders_table_in("users")).await;
+        for query in [
+            "INSERT INTO users (id, name) VALUES (1, 'Alice')",
+            "INSERT INTO orders (id, user_id) VALUES (1, 1)",
+        ] {
+            sqlx::query(query).execute(&pool).await.unwrap();
+        }
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let schema_file = SchemaFile::new(
+            repository.clone(),
+            console,
+            create_session(BRole::Admin),
+            tables_general_info,
+        );
+        let tags_table_in = BTableIn {
+            table_name: String::from("tags"),
+            columns: vec![id_column.clone()],
+        };
+        let schema_definition = BSchemaDefinition {
+            tables: vec![
+                users_table_in,
+                customers_table_in,
+                orders_table_in("customers"),
+                tags_table_in,
+            ],
+        };
+        let referenced_tables = || async {
+            sqlx::query_scalar::<_, String>(
+                "SELECT confrelid::regclass::TEXT FROM pg_constraint
+                 WHERE conrelid = 'orders'::regclass AND contype = 'f'",
+            )
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+        };
+
+        // customer 1 is missing, so the new foreign key fails and nothing is applied
+        schema_file
+            .set_schema_plan(schema_definition.clone())
+            .await
+            .unwrap();
+        assert!(schema_file.apply_schema_plan().await.is_err());
+        assert!(schema_file.schema_plan.lock().await.is_some());
+        assert_eq!(referenced_tables().await, vec!["users"]);
+        let tags_table: Option<String> = sqlx::query_scalar("SELECT to_regclass('tags')::TEXT")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(tags_table, None);
+
+        sqlx::query("INSERT INTO customers (id) VALUES (1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        schema_file.apply_schema_plan().await.unwrap();
+        assert_eq!(referenced_tables().await, vec!["customers"]);
+        schema_file
+            .set_schema_plan(schema_definition)
+            .await
+            .unwrap();
+        assert!(schema_file
+            .schema_plan
+            .lock()
+            .await
+            .clone()
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,214 @@
+use crate::component::{
+    repository_module::BRepository, BAccessLevel, BDataType, BSearchResult, BSearchResultRow,
+    BSession, BTableGeneral,
+};
+use crate::components::BusinessConsole;
+use sqlx::Row;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+// rows returned per table, a broad query should not pull whole tables
+const SEARCH_RESULT_LIMIT: i64 = 50;
+
+#[derive(Debug, Clone)]
+pub struct Search {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    session: Arc<BSession>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    pub search_results: Arc<AsyncMutex<Vec<BSearchResult>>>,
+}
+
+impl Search {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            tables_general_info,
+            search_results: Arc::new(AsyncMutex::new(vec![])),
+        }
+    }
+
+    pub async fn search(&self, search_query: String) {
+        let search_query = search_query.trim().to_string();
+        if search_query.is_empty() {
+            *self.search_results.lock().await = vec![];
+            return;
+        }
+        let tables_general_info = { self.tables_general_info.lock().await.clone() };
+        let lowercase_search_query = search_query.to_lowercase();
+        let mut search_results = vec![];
+
+        // hidden tables and columns are neither searched nor shown
+        for table_general_info in tables_general_info {
+            let table_name = table_general_info.table_name.clone();
+            if self.session.table_access_level(&table_name) == BAccessLevel::Hidden {
+                continue;
+            }
+            let (column_names, data_types): (Vec<String>, Vec<BDataType>) = table_general_info
+                .column_names
+                .into_iter()
+                .zip(table_general_info.data_types)
+                .filter(|(column_name, _)| {
+                    self.session.column_access_level(&table_name, column_name)
+                        != BAccessLevel::Hidden
+                })
+                .unzip();
+            let text_column_names: Vec<String> = column_names
+                .iter()
+                .zip(&data_types)
+                .filter(|(_, data_type)| **data_type == BDataType::TEXT)
+                .map(|(column_name, _)| column_name.clone())
+                .collect();
+            if text_column_names.is_empty() {
+                continue;
+            }
+            let rows = match self
+                .repository
+                .search_table_data_rows(
+                    &table_name,
+                    &column_names,
+                    &text_column_names,
+                    &search_query,
+                    SEARCH_RESULT_LIMIT,
+                )
+                .await
+            {
+                Ok(rows) => rows,
+                Err(error) => {
+                    self.write_to_console(format!(
+                        "Search in table \"{}\" failed: {}",
+                        table_name, error
+                    ))
+                    .await;
+                    continue;
+                }
+            };
+            if rows.is_empty() {
+                continue;
+            }
+            let rows = rows
+                .iter()
+                .map(|row| {
+                    let values: Vec<String> = column_names
+                        .iter()
+                        .map(|column_name| row.get::<String, _>(column_name.as_str()))
+                        .collect();
+                    let matched_column_names = column_names
+                        .iter()
+                        .zip(&values)
+                        .filter(|(column_name, value)| {
+                            text_column_names.contains(column_name)
+                                && value.to_lowercase().contains(&lowercase_search_query)
+                        })
+                        .map(|(column_name, _)| column_name.clone())
+                        .collect();
+                    BSearchResultRow {
+                        values,
+                        matched_column_names,
+                    }
+                })
+                .collect();
+            search_results.push(BSearchResult {
+                table_name,
+                column_names,
+                rows,
+            });
+        }
+        *self.search_results.lock().await = search_results;
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{BAccessRule, BRole, BRowInsertData, BTableDataChangeEvents};
+    use crate::tables::test_utils::{
+        create_repository_table_and_console, create_session, default_table_in,
+    };
+    use crate::tables::utils::set_tables_general_info;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_search(pool: PgPool) {
+        let table_in = default_table_in();
+        let (repository, console) = create_repository_table_and_console(pool, &table_in).await;
+        let (column_names, data_types): (Vec<String>, Vec<_>) = table_in
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.datatype.clone()))
+            .unzip();
+        let insert_row_events = vec![
+            vec!["1", "alice@example.com"],
+            vec!["2", "bob@example.com"],
+            vec!["3", "100%_real"],
+        ]
+        .into_iter()
+        .map(|values| {
+            BTableDataChangeEvents::InsertRow(BRowInsertData {
+                column_names: column_names.clone(),
+                data_types: data_types.clone(),
+                values: values.into_iter().map(String::from).collect(),
+            })
+        })
+        .collect();
+        repository
+            .update_table_data(&table_in.table_name, &insert_row_events)
+            .await
+            .unwrap();
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let session = create_session(BRole::Editor);
+        let search = Search::new(repository, console, session.clone(), tables_general_info);
+
+        search.search(String::from("ALICE@")).await;
+        assert_eq!(
+            *search.search_results.lock().await,
+            vec![BSearchResult {
+                table_name: table_in.table_name.clone(),
+                column_names: column_names.clone(),
+                rows: vec![BSearchResultRow {
+                    values: vec![String::from("1"), String::from("alice@example.com")],
+                    matched_column_names: vec![String::from("name")],
+                }],
+            }]
+        );
+
+        // LIKE wildcards in the query are matched literally
+        search.search(String::from("%_")).await;
+        let search_results = search.search_results.lock().await.clone();
+        assert_eq!(search_results[0].rows.len(), 1);
+        assert_eq!(search_results[0].rows[0].values[0], "3");
+
+        search.search(String::from("nobody")).await;
+        assert!(search.search_results.lock().await.is_empty());
+
+        // hidden columns are not searched
+        session.set_access_rules(vec![BAccessRule {
+            id: None,
+            role: BRole::Editor,
+            table_name: table_in.table_name.clone(),
+            column_name: Some(String::from("name")),
+            access_level: BAccessLevel::Hidden,
+        }]);
+        search.search(String::from("alice")).await;
+        assert!(search.search_results.lock().await.is_empty());
+    }
+}
@@ -0,0 +1,188 @@
+use crate::component::{BAccessLevel, BAccessRule, BPermission, BTableDataChangeEvents, BUser};
+use std::sync::Mutex;
+
+// the logged in user, checked by business components before any write
+#[derive(Debug)]
+pub struct Session {
+    pub current_user: BUser,
+    // only the rules of the current user's role, read from sync and async code
+    access_rules: Mutex<Vec<BAccessRule>>,
+}
+
+impl Session {
+    pub fn new(current_user: BUser) -> Self {
+        Self {
+            current_user,
+            access_rules: Mutex::new(vec![]),
+        }
+    }
+
+    // the error is the message written to the console
+    pub fn authorize(&self, permission: BPermission) -> Result<(), String> {
+        if self.current_user.role.has_permission(&permission) {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} ({}) is not allowed to {}",
+                self.current_user.user_name, self.current_user.role, permission
+            ))
+        }
+    }
+
+    pub fn set_access_rules(&self, access_rules: Vec<BAccessRule>) {
+        *self.access_rules.lock().unwrap() = access_rules
+            .into_iter()
+            .filter(|access_rule| access_rule.role == self.current_user.role)
+            .collect();
+    }
+
+    // rules can only narrow what the role allows, a viewer never gets write access
+    fn role_access_level(&self) -> BAccessLevel {
+        if self
+            .current_user
+            .role
+            .has_permission(&BPermission::EditRows)
+        {
+            BAccessLevel::Write
+        } else {
+            BAccessLevel::Read
+        }
+    }
+
+    fn access_rule_level(
+        &self,
+        table_name: &str,
+        column_name: Option<&str>,
+    ) -> Option<BAccessLevel> {
+        self.access_rules
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|access_rule| {
+                access_rule.table_name == table_name
+                    && access_rule.column_name.as_deref() == column_name
+            })
+            .map(|access_rule| access_rule.access_level.clone())
+    }
+
+    pub fn table_access_level(&self, table_name: &str) -> BAccessLevel {
+        self.access_rule_level(table_name, None)
+            .map_or(self.role_access_level(), |access_level| {
+                access_level.min(self.role_access_level())
+            })
+    }
+
+    // a column rule can only narrow the rule of its table
+    pub fn column_access_level(&self, table_name: &str, column_name: &str) -> BAccessLevel {
+        self.access_rule_level(table_name, Some(column_name))
+            .map_or(self.table_access_level(table_name), |access_level| {
+                access_level.min(self.table_access_level(table_name))
+            })
+    }
+
+    pub fn authorize_table_write(&self, table_name: &str) -> Result<(), String> {
+        if self.table_access_level(table_name) == BAccessLevel::Write {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} ({}) is not allowed to change rows of \"{}\"",
+                self.current_user.user_name, self.current_user.role, table_name
+            ))
+        }
+    }
+
+    pub fn authorize_column_write(
+        &self,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<(), String> {
+        if self.column_access_level(table_name, column_name) == BAccessLevel::Write {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} ({}) is not allowed to change \"{}\".\"{}\"",
+                self.current_user.user_name, self.current_user.role, table_name, column_name
+            ))
+        }
+    }
+
+    // an inserted row only carries values for the columns the user may write
+    pub fn authorize_table_data_change_events(
+        &self,
+        table_name: &str,
+        table_data_change_events: &Vec<BTableDataChangeEvents>,
+    ) -> Result<(), String> {
+        for table_data_change_event in table_data_change_events {
+            match table_data_change_event {
+                BTableDataChangeEvents::DeleteRow(_) => {
+                    self.authorize(BPermission::DeleteRows)?;
+                    self.authorize_table_write(table_name)?;
+                }
+                BTableDataChangeEvents::InsertRow(row_insert_data) => {
+                    self.authorize(BPermission::EditRows)?;
+                    self.authorize_table_write(table_name)?;
+                    for (column_name, value) in row_insert_data
+                        .column_names
+                        .iter()
+                        .zip(&row_insert_data.values)
+                    {
+                        if !value.is_empty() {
+                            self.authorize_column_write(table_name, column_name)?;
+                        }
+                    }
+                }
+                BTableDataChangeEvents::ModifyRowColumnValue(row_column_value) => {
+                    self.authorize(BPermission::EditRows)?;
+                    for column_name in row_column_value.column_values.keys() {
+                        self.authorize_column_write(table_name, column_name)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::component::{BAccessLevel, BAccessRule, BRole};
+    use crate::tables::test_utils::create_session;
+
+    fn access_rule(column_name: Option<&str>, access_level: BAccessLevel) -> BAccessRule {
+        BAccessRule {
+            id: None,
+            role: BRole::Editor,
+            table_name: String::from("users"),
+            column_name: column_name.map(String::from),
+            access_level,
+        }
+    }
+
+    #[test]
+    fn test_column_access_level() {
+        let session = create_session(BRole::Editor);
+        session.set_access_rules(vec![
+            access_rule(None, BAccessLevel::Read),
+            access_rule(Some("name"), BAccessLevel::Write),
+            access_rule(Some("email"), BAccessLevel::Hidden),
+        ]);
+        // a column can't be written when its table is read only
+        assert_eq!(
+            session.column_access_level("users", "name"),
+            BAccessLevel::Read
+        );
+        assert!(session.authorize_column_write("users", "name").is_err());
+        assert_eq!(
+            session.column_access_level("users", "email"),
+            BAccessLevel::Hidden
+        );
+        assert_eq!(
+            session.column_access_level("users", "id"),
+            BAccessLevel::Read
+        );
+        assert_eq!(
+            session.column_access_level("orders", "id"),
+            BAccessLevel::Write
+        );
+    }
+}
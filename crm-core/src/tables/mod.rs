@@ -0,0 +1,7 @@
+pub mod table_data;
+pub mod table_info;
+pub mod table_views;
+pub mod tables;
+#[cfg(test)]
+pub(super) mod test_utils;
+pub(super) mod utils;
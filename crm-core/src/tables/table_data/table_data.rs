@@ -0,0 +1,2239 @@
+use crate::component::{
+    repository_module::BRepository, BAccessLevel, BChangeStatus, BChildRecords, BComputedColumn,
+    BCondition, BDataType, BFilterOperator, BForeignKeyReference, BPermission, BRowColumnValue,
+    BRowInsertData, BSession, BTableChangeEvents, BTableDataChangeEvents, BTableDataChangeStatus,
+    BTableGeneral, BTableInsertedData, BTableView, BViewFilter,
+};
+use crate::components::BusinessConsole;
+use crate::tables::utils::{get_foreign_key_references, set_tables_general_info};
+use sqlx::Row;
+use std::collections::HashMap;
+use std::iter::zip;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+#[derive(Debug, Clone)]
+pub struct TableData {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    pub session: Arc<BSession>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    pub table_inserted_data: Arc<AsyncMutex<Option<BTableInsertedData>>>,
+    table_data_change_events: Arc<AsyncMutex<Vec<BTableDataChangeEvents>>>,
+    primary_key_column_names: Arc<AsyncMutex<Vec<String>>>,
+    current_to_initial_row_indexes: Arc<AsyncMutex<HashMap<usize, usize>>>,
+    pub table_view: Arc<AsyncMutex<Option<BTableView>>>,
+    // pending changes that clash with changes saved by someone else
+    pub table_data_conflicts: Arc<AsyncMutex<Vec<String>>>,
+    // columns the table's layout leaves out of the default view
+    pub hidden_column_names: Arc<AsyncMutex<Vec<String>>>,
+    pub computed_columns: Arc<AsyncMutex<Vec<BComputedColumn>>>,
+    // stored GENERATED columns, read-only like the computed ones
+    pub generated_column_names: Arc<AsyncMutex<Vec<String>>>,
+    // rows of other tables referencing the record shown in the form
+    pub child_records: Arc<AsyncMutex<Vec<BChildRecords>>>,
+    // child tables of the selected row in the master-detail view
+    pub detail_tables: Arc<AsyncMutex<Vec<DetailTableData>>>,
+}
+
+// a child table filtered to the rows whose foreign key holds the parent row's value
+#[derive(Debug, Clone)]
+pub struct DetailTableData {
+    pub foreign_key_reference: BForeignKeyReference,
+    pub referenced_value: String,
+    pub table_data: Arc<TableData>,
+}
+
+impl DetailTableData {
+    // a new child already points at the parent row
+    pub fn add_insert_row_event(&self) {
+        let Some(column_names) = self
+            .table_data
+            .table_inserted_data
+            .blocking_lock()
+            .as_ref()
+            .map(|table_inserted_data| table_inserted_data.column_names.clone())
+        else {
+            return;
+        };
+        let values = column_names
+            .iter()
+            .map(|column_name| {
+                if *column_name == self.foreign_key_reference.column_name {
+                    self.referenced_value.clone()
+                } else {
+                    String::new()
+                }
+            })
+            .collect();
+        self.table_data.add_insert_row_event(values);
+    }
+}
+
+impl TableData {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+        tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            tables_general_info,
+            table_inserted_data: Arc::new(AsyncMutex::new(None)),
+            table_data_change_events: Arc::new(AsyncMutex::new(vec![])),
+            primary_key_column_names: Arc::new(AsyncMutex::new(vec![])),
+            current_to_initial_row_indexes: Arc::new(AsyncMutex::new(HashMap::new())),
+            table_view: Arc::new(AsyncMutex::new(None)),
+            table_data_conflicts: Arc::new(AsyncMutex::new(vec![])),
+            hidden_column_names: Arc::new(AsyncMutex::new(vec![])),
+            computed_columns: Arc::new(AsyncMutex::new(vec![])),
+            generated_column_names: Arc::new(AsyncMutex::new(vec![])),
+            child_records: Arc::new(AsyncMutex::new(vec![])),
+            detail_tables: Arc::new(AsyncMutex::new(vec![])),
+        }
+    }
+
+    pub async fn set_table_view(&self, table_view: Option<BTableView>) {
+        *self.table_view.lock().await = table_view;
+    }
+
+    pub fn reset_table_data(&self) {
+        let mut locked_table_inserted_data = self.table_inserted_data.blocking_lock();
+        *locked_table_inserted_data = None;
+        let mut locked_table_data_change_events = self.table_data_change_events.blocking_lock();
+        *locked_table_data_change_events = vec![];
+        let mut locked_primary_key_column_names = self.primary_key_column_names.blocking_lock();
+        *locked_primary_key_column_names = vec![];
+        let mut locked_current_to_initial_row_indexes =
+            self.current_to_initial_row_indexes.blocking_lock();
+        *locked_current_to_initial_row_indexes = HashMap::new();
+        let mut locked_table_view = self.table_view.blocking_lock();
+        *locked_table_view = None;
+        *self.table_data_conflicts.blocking_lock() = vec![];
+        *self.hidden_column_names.blocking_lock() = vec![];
+        *self.computed_columns.blocking_lock() = vec![];
+        *self.generated_column_names.blocking_lock() = vec![];
+        *self.child_records.blocking_lock() = vec![];
+        *self.detail_tables.blocking_lock() = vec![];
+    }
+
+    fn get_primary_key_conditions(
+        &self,
+        row_index: usize,
+        table_inserted_data: &BTableInsertedData,
+    ) -> Vec<BCondition> {
+        let primary_key_column_names = self.primary_key_column_names.blocking_lock();
+        let adjusted_row_index = *self
+            .current_to_initial_row_indexes
+            .blocking_lock()
+            .get(&row_index)
+            .unwrap();
+        table_inserted_data
+            .column_names
+            .iter()
+            .zip(&table_inserted_data.data_types)
+            .zip(&table_inserted_data.rows[adjusted_row_index])
+            .filter(|((column_name, _), _)| primary_key_column_names.contains(column_name))
+            .map(|((column_name, data_type), value)| BCondition {
+                column_name: column_name.clone(),
+                data_type: data_type.clone(),
+                value: Some(value.clone()),
+            })
+            .collect()
+    }
+
+    fn find_existing_row_insert_event(
+        &self,
+        table_data_change_events: &[BTableDataChangeEvents],
+        table_inserted_data: &BTableInsertedData,
+        row_index: usize,
+    ) -> Option<usize> {
+        // Calculate the adjusted index for insertion events
+        let delete_row_event_count = table_data_change_events
+            .iter()
+            .filter(|event| matches!(event, BTableDataChangeEvents::DeleteRow(_)))
+            .count();
+        // checks if row was inserted through a insert row event
+        // by row_index >= len(rows) - deleted row events
+        let index_of_events_filtered_by_insert_row =
+            row_index.checked_sub(table_inserted_data.rows.len() - delete_row_event_count)?;
+
+        // Find the nth InsertRow event index
+        table_data_change_events
+            .iter()
+            .enumerate()
+            .filter_map(|(index, event)| {
+                if let BTableDataChangeEvents::InsertRow(_) = event {
+                    Some(index)
+                } else {
+                    None
+                }
+            })
+            .nth(index_of_events_filtered_by_insert_row)
+    }
+
+    fn find_existing_modify_row_event(
+        &self,
+        table_data_change_events: &[BTableDataChangeEvents],
+        table_inserted_data: &BTableInsertedData,
+        row_index: usize,
+    ) -> Option<usize> {
+        let conditions = self.get_primary_key_conditions(row_index, table_inserted_data);
+        table_data_change_events.iter().position(|event| matches!(event, BTableDataChangeEvents::ModifyRowColumnValue(row_column_value) if row_column_value.conditions == conditions))
+    }
+
+    fn update_modify_row_event(
+        &self,
+        table_data_change_events: &mut Vec<BTableDataChangeEvents>,
+        table_inserted_data: &BTableInsertedData,
+        row_index: usize,
+        event_index: usize,
+        column_name: String,
+        new_value: String,
+        data_type: BDataType,
+    ) {
+        if let Some(BTableDataChangeEvents::ModifyRowColumnValue(row_column_value)) =
+            table_data_change_events.get_mut(event_index)
+        {
+            if let Some((data_type, _value)) = row_column_value.column_values.get(&column_name) {
+                let column_index = table_inserted_data
+                    .column_names
+                    .iter()
+                    .position(|col_name| *col_name == column_name)
+                    .unwrap();
+                let original_value = &table_inserted_data.rows[row_index][column_index];
+                if new_value == *original_value {
+                    row_column_value.column_values.remove(&column_name);
+                    if row_column_value.column_values.is_empty() {
+                        table_data_change_events.remove(event_index);
+                    }
+                } else {
+                    row_column_value
+                        .column_values
+                        .insert(column_name, (data_type.clone(), Some(new_value)));
+                }
+            } else {
+                row_column_value
+                    .column_values
+                    .insert(column_name, (data_type, Some(new_value)));
+            }
+        }
+    }
+
+    pub fn add_insert_row_event(&self, values: Vec<String>) {
+        let locked_table_inserted_data = self.table_inserted_data.blocking_lock();
+        let table_inserted_data = locked_table_inserted_data.as_ref().unwrap();
+        if let Err(message) = self
+            .session
+            .authorize_table_write(&table_inserted_data.table_name)
+        {
+            self.console.write(message);
+            return;
+        }
+        let mut locked_table_data_change_events = self.table_data_change_events.blocking_lock();
+        locked_table_data_change_events.push(BTableDataChangeEvents::InsertRow(BRowInsertData {
+            column_names: table_inserted_data.column_names.clone(),
+            values,
+            data_types: table_inserted_data.data_types.clone(),
+        }));
+        self.console
+            .write(format!("{:?}", locked_table_data_change_events));
+    }
+
+    pub fn add_modify_row_column_value_event(
+        &self,
+        row_index: usize,
+        column_name: String,
+        new_value: String,
+    ) {
+        match self.push_modify_row_column_value_event(row_index, column_name, new_value) {
+            Ok(true) => self.console.write(format!(
+                "{:?}",
+                self.table_data_change_events.blocking_lock()
+            )),
+            Ok(false) => {}
+            Err(message) => self.console.write(message),
+        }
+    }
+
+    // returns whether a pending event was added or changed
+    fn push_modify_row_column_value_event(
+        &self,
+        row_index: usize,
+        column_name: String,
+        new_value: String,
+    ) -> Result<bool, String> {
+        // Step 1: Acquire the table data lock first, process what can be done without holding all locks
+        let table_inserted_data = {
+            let locked_table_inserted_data = self.table_inserted_data.blocking_lock();
+            locked_table_inserted_data.as_ref().unwrap().clone() // Clone to minimize locking duration
+        };
+        self.session
+            .authorize_column_write(&table_inserted_data.table_name, &column_name)?;
+        if self.is_computed_column(&column_name) || self.is_generated_column(&column_name) {
+            return Err(format!(
+                "\"{}\" is computed and can't be edited",
+                column_name
+            ));
+        }
+
+        // Step 3: Acquire necessary locks in a consistent order
+        let mut locked_table_data_change_events = self.table_data_change_events.blocking_lock();
+
+        // Step 4: Check if there is an existing row insert event
+
+        if let Some(existing_event_index) = self.find_existing_row_insert_event(
+            &locked_table_data_change_events,
+            &table_inserted_data,
+            row_index,
+        ) {
+            self.update_existing_insert_row_event(
+                &mut locked_table_data_change_events[existing_event_index],
+                &column_name,
+                &new_value,
+                &table_inserted_data,
+            );
+            return Ok(true);
+        }
+
+        // Step 2: Check if the row index is in the database
+        if row_index >= table_inserted_data.rows.len() {
+            return Ok(false); // Invalid row index, no further processing needed
+        }
+
+        let column_datatype_index = table_inserted_data
+            .column_names
+            .iter()
+            .position(|col_name| *col_name == column_name)
+            .unwrap();
+
+        let data_type = table_inserted_data.data_types[column_datatype_index].clone();
+        // Step 8: Check for existing event and replace if necessary
+        if let Some(existing_event_index) = self.find_existing_modify_row_event(
+            &locked_table_data_change_events,
+            &table_inserted_data,
+            row_index,
+        ) {
+            self.update_modify_row_event(
+                &mut locked_table_data_change_events,
+                &table_inserted_data,
+                row_index,
+                existing_event_index,
+                column_name,
+                new_value,
+                data_type,
+            );
+        } else {
+            // Step 7: Proceed with new event creation
+            let conditions = self.get_primary_key_conditions(row_index, &table_inserted_data);
+            let mut column_values = HashMap::new();
+            column_values.insert(column_name.clone(), (data_type, Some(new_value.clone())));
+            let row_column_value = BRowColumnValue {
+                conditions: conditions.clone(),
+                column_values,
+            };
+
+            // Add the new event
+            locked_table_data_change_events.push(BTableDataChangeEvents::ModifyRowColumnValue(
+                row_column_value,
+            ));
+        }
+        Ok(true)
+    }
+
+    fn update_existing_insert_row_event(
+        &self,
+        event: &mut BTableDataChangeEvents,
+        column_name: &str,
+        new_value: &str,
+        table_inserted_data: &BTableInsertedData,
+    ) {
+        if let BTableDataChangeEvents::InsertRow(row_insert_data) = event {
+            row_insert_data.values =
+                zip(&table_inserted_data.column_names, &row_insert_data.values)
+                    .map(|(col_name, value)| {
+                        if col_name == column_name {
+                            new_value.to_string() // Update the value for the matching column
+                        } else {
+                            value.to_string() // Keep the existing value
+                        }
+                    })
+                    .collect();
+        }
+    }
+
+    pub fn add_delete_row_event(&self, row_index: usize) {
+        match self.push_delete_row_event(row_index) {
+            Ok(true) => self.console.write(format!(
+                "{:?}",
+                self.table_data_change_events.blocking_lock()
+            )),
+            Ok(false) => {}
+            Err(message) => self.console.write(message),
+        }
+    }
+
+    // returns whether a pending event was added or removed
+    fn push_delete_row_event(&self, row_index: usize) -> Result<bool, String> {
+        // Acquire locks for necessary data
+        let locked_table_inserted_data = self.table_inserted_data.blocking_lock();
+
+        let mut locked_table_data_change_events = self.table_data_change_events.blocking_lock();
+
+        // Safely unwrap the locked data
+        let table_inserted_data = locked_table_inserted_data.as_ref().unwrap();
+        self.session
+            .authorize_table_write(&table_inserted_data.table_name)?;
+
+        if let Some(existing_event_index) = self.find_existing_row_insert_event(
+            &locked_table_data_change_events,
+            table_inserted_data,
+            row_index,
+        ) {
+            locked_table_data_change_events.remove(existing_event_index);
+            return Ok(true);
+        }
+        // Ensure the row index is valid
+        else if row_index >= table_inserted_data.rows.len() {
+            return Ok(false); // Exit if the row index is out of bounds
+        }
+
+        // Extract conditions based on primary key column names
+        let conditions = self.get_primary_key_conditions(row_index, table_inserted_data);
+
+        // Add the delete row event
+        locked_table_data_change_events.push(BTableDataChangeEvents::DeleteRow(conditions));
+        let mut locked_current_to_initial_row_indexes =
+            self.current_to_initial_row_indexes.blocking_lock();
+
+        let mut keys_to_update: Vec<_> = locked_current_to_initial_row_indexes
+            .keys()
+            .cloned()
+            .filter(|current_row_index| *current_row_index > row_index)
+            .collect();
+
+        keys_to_update.sort_by(|a, b| b.cmp(a));
+
+        for (iter_index, current_row_index) in keys_to_update.iter().enumerate() {
+            let initial_row_index = *locked_current_to_initial_row_indexes
+                .get(current_row_index)
+                .unwrap();
+            let new_current_row_index = current_row_index - 1;
+            locked_current_to_initial_row_indexes.insert(new_current_row_index, initial_row_index);
+            if iter_index == 0 {
+                locked_current_to_initial_row_indexes.remove(current_row_index);
+            }
+        }
+        Ok(true)
+    }
+
+    // one modify event per selected row, merged with the row's pending events;
+    // the console gets one line for the whole selection
+    pub fn add_bulk_modify_column_value_events(
+        &self,
+        row_indexes: &[usize],
+        column_name: String,
+        new_value: String,
+    ) {
+        let mut changed_rows_count = 0;
+        for row_index in row_indexes {
+            match self.push_modify_row_column_value_event(
+                *row_index,
+                column_name.clone(),
+                new_value.clone(),
+            ) {
+                Ok(is_changed) => changed_rows_count += usize::from(is_changed),
+                Err(message) => {
+                    self.console.write(message);
+                    break;
+                }
+            }
+        }
+        if changed_rows_count > 0 {
+            self.console.write(format!(
+                "Set \"{}\" to \"{}\" in {} rows of \"{}\"",
+                column_name,
+                new_value,
+                changed_rows_count,
+                self.current_table_name()
+            ));
+        }
+    }
+
+    // deleting from the last row keeps the remaining row indexes valid
+    pub fn add_bulk_delete_row_events(&self, row_indexes: &[usize]) {
+        let mut row_indexes = row_indexes.to_vec();
+        row_indexes.sort_by(|a, b| b.cmp(a));
+        row_indexes.dedup();
+        let mut deleted_rows_count = 0;
+        for row_index in row_indexes {
+            match self.push_delete_row_event(row_index) {
+                Ok(is_deleted) => deleted_rows_count += usize::from(is_deleted),
+                Err(message) => {
+                    self.console.write(message);
+                    break;
+                }
+            }
+        }
+        if deleted_rows_count > 0 {
+            self.console.write(format!(
+                "Deleted {} rows from \"{}\"",
+                deleted_rows_count,
+                self.current_table_name()
+            ));
+        }
+    }
+
+    fn current_table_name(&self) -> String {
+        self.table_inserted_data
+            .blocking_lock()
+            .as_ref()
+            .map(|table_inserted_data| table_inserted_data.table_name.clone())
+            .unwrap_or_default()
+    }
+
+    // drops the pending change of one cell and returns the value it goes back to,
+    // an inserted row has no loaded value so its cell is emptied
+    pub fn revert_row_column_value(&self, row_index: usize, column_name: &str) -> Option<String> {
+        let table_inserted_data = self.table_inserted_data.blocking_lock().clone()?;
+        let column_index = table_inserted_data
+            .column_names
+            .iter()
+            .position(|col_name| col_name == column_name)?;
+        let mut locked_table_data_change_events = self.table_data_change_events.blocking_lock();
+
+        if let Some(existing_event_index) = self.find_existing_row_insert_event(
+            &locked_table_data_change_events,
+            &table_inserted_data,
+            row_index,
+        ) {
+            self.update_existing_insert_row_event(
+                &mut locked_table_data_change_events[existing_event_index],
+                column_name,
+                "",
+                &table_inserted_data,
+            );
+            return Some(String::new());
+        }
+        let initial_row_index = *self
+            .current_to_initial_row_indexes
+            .blocking_lock()
+            .get(&row_index)?;
+
+        if let Some(existing_event_index) = self.find_existing_modify_row_event(
+            &locked_table_data_change_events,
+            &table_inserted_data,
+            row_index,
+        ) {
+            if let BTableDataChangeEvents::ModifyRowColumnValue(row_column_value) =
+                &mut locked_table_data_change_events[existing_event_index]
+            {
+                row_column_value.column_values.remove(column_name);
+                if row_column_value.column_values.is_empty() {
+                    locked_table_data_change_events.remove(existing_event_index);
+                }
+            }
+            self.console
+                .write(format!("{:?}", locked_table_data_change_events));
+        }
+        Some(table_inserted_data.rows[initial_row_index][column_index].clone())
+    }
+
+    pub async fn update_table_data(&self) {
+        // Extract and drop the lock on `table_inserted_data`
+        let (table_name, table_data_change_events) = {
+            let table_inserted_data_guard = self.table_inserted_data.lock().await;
+            if let Some(ref table_inserted_data) = *table_inserted_data_guard {
+                let table_name = table_inserted_data.table_name.clone();
+                let table_data_change_events_guard = self.table_data_change_events.lock().await;
+                let table_data_change_events = table_data_change_events_guard.clone();
+                (table_name, table_data_change_events)
+            } else {
+                return; // If there's no table_inserted_data, exit the function
+            }
+        };
+        if let Err(message) = self
+            .session
+            .authorize_table_data_change_events(&table_name, &table_data_change_events)
+        {
+            let console = self.console.clone();
+            task::spawn_blocking(move || {
+                console.write(message);
+            })
+            .await
+            .unwrap();
+            return;
+        }
+        let mut read_only_column_names: Vec<String> = self
+            .computed_columns
+            .lock()
+            .await
+            .iter()
+            .map(|computed_column| computed_column.column_name.clone())
+            .collect();
+        read_only_column_names.extend(self.generated_column_names.lock().await.clone());
+        let table_data_change_events =
+            without_read_only_columns(table_data_change_events, &read_only_column_names);
+        // Use the extracted values without holding the locks
+        if let Err(error) = self
+            .repository
+            .update_table_data(&table_name, &table_data_change_events)
+            .await
+        {
+            // nothing was written, the pending changes stay so they can be fixed
+            let console = self.console.clone();
+            task::spawn_blocking(move || {
+                console.write(format!("Failed to update table data: {}", error));
+            })
+            .await
+            .unwrap();
+            return;
+        }
+        self.set_table_data(table_name.to_string()).await;
+    }
+
+    // hidden columns are never selected, primary keys stay so rows can be identified
+    fn readable_columns(
+        &self,
+        table_general_info: &BTableGeneral,
+        primary_key_column_names: &Vec<String>,
+    ) -> (Vec<String>, Vec<BDataType>) {
+        table_general_info
+            .column_names
+            .iter()
+            .zip(&table_general_info.data_types)
+            .filter(|(column_name, _)| {
+                primary_key_column_names.contains(column_name)
+                    || self
+                        .session
+                        .column_access_level(&table_general_info.table_name, column_name)
+                        != BAccessLevel::Hidden
+            })
+            .map(|(column_name, data_type)| (column_name.clone(), data_type.clone()))
+            .unzip()
+    }
+
+    pub async fn set_table_data(&self, table_name: String) {
+        // Lock the general info table
+        let tables_general_info = self.tables_general_info.lock().await;
+        if let Some(table_general_info) = tables_general_info
+            .iter()
+            .find(|info| info.table_name == table_name)
+        {
+            let primary_key_column_names = self
+                .repository
+                .get_primary_key_column_names(&table_name)
+                .await
+                .unwrap();
+            // views of other tables are dropped once a different table is selected
+            let (filters, sorts) = {
+                let mut locked_table_view = self.table_view.lock().await;
+                match locked_table_view.as_ref() {
+                    Some(table_view) if table_view.table_name == table_name => {
+                        (table_view.filters.clone(), table_view.sorts.clone())
+                    }
+                    _ => {
+                        *locked_table_view = None;
+                        (vec![], vec![])
+                    }
+                }
+            };
+            let computed_columns: Vec<BComputedColumn> = self
+                .repository
+                .get_computed_columns(&table_name)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(BComputedColumn::to_computed_column)
+                .filter(|computed_column| {
+                    self.session
+                        .column_access_level(&table_name, &computed_column.column_name)
+                        != BAccessLevel::Hidden
+                })
+                .collect();
+            let (mut column_names, mut data_types) =
+                self.readable_columns(table_general_info, &primary_key_column_names);
+            // computed columns come after the stored ones
+            for computed_column in &computed_columns {
+                column_names.push(computed_column.column_name.clone());
+                data_types.push(computed_column.data_type.clone());
+            }
+            // Fetch rows for the table
+            let table_inserted_data_rows = match self
+                .repository
+                .get_table_data_rows(
+                    &table_name,
+                    &computed_columns,
+                    &column_names,
+                    &data_types,
+                    &primary_key_column_names,
+                    &filters,
+                    &sorts,
+                )
+                .await
+            {
+                Ok(table_inserted_data_rows) => table_inserted_data_rows,
+                Err(error) => {
+                    // an invalid filter value (e.g. text for an integer column) leaves
+                    // the table empty instead of crashing
+                    let console = self.console.clone();
+                    task::spawn_blocking(move || {
+                        console.write(format!("Failed to load table data: {}", error));
+                    })
+                    .await
+                    .unwrap();
+                    vec![]
+                }
+            };
+            let mut locked_current_to_initial_row_indexes =
+                self.current_to_initial_row_indexes.lock().await;
+            // Construct the inserted data
+            let table_inserted_data = BTableInsertedData {
+                table_name: table_name.clone(),
+                rows: table_inserted_data_rows
+                    .iter()
+                    .map(|row| {
+                        column_names
+                            .iter()
+                            .map(|column_name| row.get::<String, _>(column_name.as_str()))
+                            .collect::<Vec<String>>()
+                    })
+                    .collect::<Vec<Vec<String>>>(),
+                column_names,
+                data_types,
+            };
+            *locked_current_to_initial_row_indexes = HashMap::new();
+            for (index, _) in table_inserted_data.rows.iter().enumerate() {
+                locked_current_to_initial_row_indexes.insert(index, index);
+            } // Update the shared table inserted data
+            *self.table_inserted_data.lock().await = Some(table_inserted_data);
+            *self.table_data_change_events.lock().await = vec![];
+            *self.primary_key_column_names.lock().await = primary_key_column_names;
+            *self.table_data_conflicts.lock().await = vec![];
+            *self.hidden_column_names.lock().await = self
+                .repository
+                .get_column_layout(&table_name)
+                .await
+                .unwrap()
+                .map(|column_layout_info| column_layout_info.hidden_column_names)
+                .unwrap_or_default();
+            *self.computed_columns.lock().await = computed_columns;
+            *self.generated_column_names.lock().await = self
+                .repository
+                .get_generated_column_names(&table_name)
+                .await
+                .unwrap();
+        }
+    }
+
+    // foreign keys of visible tables pointing at the row, with the value they must hold;
+    // the row is passed as shown, a pending edit of its key already applies
+    async fn referencing_foreign_keys(
+        &self,
+        column_names: &Vec<String>,
+        row_values: &Vec<String>,
+    ) -> Vec<(BForeignKeyReference, String)> {
+        let Some(table_name) = self
+            .table_inserted_data
+            .lock()
+            .await
+            .as_ref()
+            .map(|table_inserted_data| table_inserted_data.table_name.clone())
+        else {
+            return vec![];
+        };
+        let tables_general_info = self.tables_general_info.lock().await.clone();
+        get_foreign_key_references(self.repository.clone(), &tables_general_info, &table_name)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|foreign_key_reference| {
+                let referenced_value = column_names
+                    .iter()
+                    .position(|column_name| *column_name == foreign_key_reference.referenced_column)
+                    .map(|column_index| row_values[column_index].clone())?;
+                (!referenced_value.is_empty()
+                    && self
+                        .session
+                        .table_access_level(&foreign_key_reference.table_name)
+                        != BAccessLevel::Hidden)
+                    .then_some((foreign_key_reference, referenced_value))
+            })
+            .collect()
+    }
+
+    pub async fn set_child_records(&self, column_names: Vec<String>, row_values: Vec<String>) {
+        let tables_general_info = self.tables_general_info.lock().await.clone();
+        let mut child_records = vec![];
+        for (foreign_key_reference, referenced_value) in self
+            .referencing_foreign_keys(&column_names, &row_values)
+            .await
+        {
+            let Some(child_table_general_info) =
+                tables_general_info.iter().find(|table_general_info| {
+                    table_general_info.table_name == foreign_key_reference.table_name
+                })
+            else {
+                continue;
+            };
+            let child_primary_key_column_names = self
+                .repository
+                .get_primary_key_column_names(&foreign_key_reference.table_name)
+                .await
+                .unwrap();
+            let (child_column_names, child_data_types) =
+                self.readable_columns(child_table_general_info, &child_primary_key_column_names);
+            // a filter on a column that isn't selected would be ignored and list every row
+            if !child_column_names.contains(&foreign_key_reference.column_name) {
+                continue;
+            }
+            let child_rows = self
+                .repository
+                .get_table_data_rows(
+                    &foreign_key_reference.table_name,
+                    &vec![],
+                    &child_column_names,
+                    &child_data_types,
+                    &child_primary_key_column_names,
+                    &vec![BViewFilter {
+                        column_name: foreign_key_reference.column_name.clone(),
+                        operator: BFilterOperator::Equals,
+                        value: referenced_value,
+                    }],
+                    &vec![],
+                )
+                .await
+                .unwrap_or_default();
+            child_records.push(BChildRecords {
+                table_inserted_data: BTableInsertedData {
+                    table_name: foreign_key_reference.table_name.clone(),
+                    rows: child_rows
+                        .iter()
+                        .map(|row| {
+                            child_column_names
+                                .iter()
+                                .map(|column_name| row.get::<String, _>(column_name.as_str()))
+                                .collect()
+                        })
+                        .collect(),
+                    column_names: child_column_names,
+                    data_types: child_data_types,
+                },
+                foreign_key_reference,
+            });
+        }
+        *self.child_records.lock().await = child_records;
+    }
+
+    // each child table is loaded into its own TableData, so its rows are edited and saved
+    // like the rows of any table
+    pub async fn set_detail_tables(&self, column_names: Vec<String>, row_values: Vec<String>) {
+        let mut detail_tables = vec![];
+        for (foreign_key_reference, referenced_value) in self
+            .referencing_foreign_keys(&column_names, &row_values)
+            .await
+        {
+            let table_data = TableData::new(
+                self.repository.clone(),
+                self.console.clone(),
+                self.session.clone(),
+                self.tables_general_info.clone(),
+            );
+            table_data
+                .set_table_view(Some(BTableView {
+                    table_name: foreign_key_reference.table_name.clone(),
+                    filters: vec![BViewFilter {
+                        column_name: foreign_key_reference.column_name.clone(),
+                        operator: BFilterOperator::Equals,
+                        value: referenced_value.clone(),
+                    }],
+                    ..Default::default()
+                }))
+                .await;
+            table_data
+                .set_table_data(foreign_key_reference.table_name.clone())
+                .await;
+            // a filter on a column that isn't selected would be ignored and list every row
+            let is_foreign_key_readable = table_data
+                .table_inserted_data
+                .lock()
+                .await
+                .as_ref()
+                .is_some_and(|table_inserted_data| {
+                    table_inserted_data
+                        .column_names
+                        .contains(&foreign_key_reference.column_name)
+                });
+            if is_foreign_key_readable {
+                detail_tables.push(DetailTableData {
+                    foreign_key_reference,
+                    referenced_value,
+                    table_data: Arc::new(table_data),
+                });
+            }
+        }
+        *self.detail_tables.lock().await = detail_tables;
+    }
+
+    pub fn is_computed_column(&self, column_name: &str) -> bool {
+        self.computed_columns
+            .blocking_lock()
+            .iter()
+            .any(|computed_column| computed_column.column_name == column_name)
+    }
+
+    // a row opened from elsewhere, e.g. a search result, found by its primary key values
+    pub fn find_row_index_by_key(
+        &self,
+        table_inserted_data: &BTableInsertedData,
+        column_names: &Vec<String>,
+        row: &Vec<String>,
+    ) -> Option<usize> {
+        row_index_by_key(
+            table_inserted_data,
+            &self.primary_key_column_names.blocking_lock(),
+            column_names,
+            row,
+        )
+    }
+
+    pub fn is_generated_column(&self, column_name: &str) -> bool {
+        self.generated_column_names
+            .blocking_lock()
+            .iter()
+            .any(|generated_column_name| generated_column_name == column_name)
+    }
+
+    // the grid is reloaded with the new columns, so pending changes have to be saved first
+    async fn authorize_computed_column_change(&self) -> Result<String, String> {
+        self.session.authorize(BPermission::AlterSchema)?;
+        if !self.table_data_change_events.lock().await.is_empty() {
+            return Err(String::from("Save or reset the pending changes first"));
+        }
+        self.table_inserted_data
+            .lock()
+            .await
+            .as_ref()
+            .map(|table_inserted_data| table_inserted_data.table_name.clone())
+            .ok_or(String::from("No table is selected"))
+    }
+
+    pub async fn add_computed_column(
+        &self,
+        column_name: String,
+        data_type: BDataType,
+        expression: String,
+    ) -> Result<(), String> {
+        let table_name = self.authorize_computed_column_change().await?;
+        if column_name.trim().is_empty() || expression.trim().is_empty() {
+            return Err(String::from(
+                "A computed column needs a name and an expression",
+            ));
+        }
+        let is_stored_column = self
+            .tables_general_info
+            .lock()
+            .await
+            .iter()
+            .find(|table_general_info| table_general_info.table_name == table_name)
+            .is_some_and(|table_general_info| {
+                table_general_info.column_names.contains(&column_name)
+            });
+        if is_stored_column {
+            return Err(format!(
+                "\"{}\" already has a column \"{}\"",
+                table_name, column_name
+            ));
+        }
+        let computed_column = BComputedColumn {
+            table_name: table_name.clone(),
+            column_name,
+            expression,
+            data_type,
+        };
+        self.repository
+            .save_computed_column(&computed_column)
+            .await
+            .map_err(|error| {
+                format!(
+                    "\"{}\" can't be computed: {}",
+                    computed_column.expression, error
+                )
+            })?;
+        self.set_table_data(table_name).await;
+        Ok(())
+    }
+
+    pub async fn remove_computed_column(&self, column_name: String) -> Result<(), String> {
+        let table_name = self.authorize_computed_column_change().await?;
+        self.repository
+            .delete_computed_column(&table_name, &column_name)
+            .await
+            .map_err(|error| error.to_string())?;
+        self.set_table_data(table_name).await;
+        Ok(())
+    }
+
+    // turns the formula into a GENERATED column postgres stores with every row
+    pub async fn promote_computed_column(&self, column_name: String) -> Result<(), String> {
+        let table_name = self.authorize_computed_column_change().await?;
+        let Some(computed_column) = self
+            .computed_columns
+            .lock()
+            .await
+            .iter()
+            .find(|computed_column| computed_column.column_name == column_name)
+            .cloned()
+        else {
+            return Err(format!("\"{}\" is not a computed column", column_name));
+        };
+        let primary_key_column_names = self.primary_key_column_names.lock().await.clone();
+        self.repository
+            .alter_table(
+                &table_name,
+                &vec![BTableChangeEvents::AddGeneratedColumn(
+                    computed_column.column_name.clone(),
+                    computed_column.data_type.clone(),
+                    computed_column.expression.clone(),
+                )],
+                &primary_key_column_names,
+                &vec![],
+            )
+            .await
+            .map_err(|error| format!("\"{}\" can't be stored: {}", column_name, error))?;
+        self.repository
+            .delete_computed_column(&table_name, &column_name)
+            .await
+            .map_err(|error| error.to_string())?;
+        set_tables_general_info(self.repository.clone(), self.tables_general_info.clone()).await;
+        self.set_table_data(table_name).await;
+        Ok(())
+    }
+
+    // reloads rows saved by someone else, pending events are kept on top of them
+    pub async fn refresh_table_data(&self) {
+        let Some(previous_table_inserted_data) = self.table_inserted_data.lock().await.clone()
+        else {
+            return;
+        };
+        let table_name = previous_table_inserted_data.table_name.clone();
+        let table_exists = self
+            .tables_general_info
+            .lock()
+            .await
+            .iter()
+            .any(|table_general_info| table_general_info.table_name == table_name);
+        if !table_exists {
+            let table_data = self.clone();
+            task::spawn_blocking(move || table_data.reset_table_data())
+                .await
+                .unwrap();
+            return;
+        }
+        let table_data_change_events = self.table_data_change_events.lock().await.clone();
+        self.set_table_data(table_name).await;
+        if table_data_change_events.is_empty() {
+            return;
+        }
+        let Some(table_inserted_data) = self.table_inserted_data.lock().await.clone() else {
+            return;
+        };
+
+        let mut conflicts = vec![];
+        let mut kept_table_data_change_events = vec![];
+        let mut deleted_row_indexes = vec![];
+        for table_data_change_event in table_data_change_events {
+            let conditions = match &table_data_change_event {
+                BTableDataChangeEvents::InsertRow(_) => {
+                    kept_table_data_change_events.push(table_data_change_event);
+                    continue;
+                }
+                BTableDataChangeEvents::DeleteRow(conditions) => conditions,
+                BTableDataChangeEvents::ModifyRowColumnValue(row_column_value) => {
+                    &row_column_value.conditions
+                }
+            };
+            let row_description = conditions
+                .iter()
+                .map(|condition| {
+                    format!(
+                        "{} = {}",
+                        condition.column_name,
+                        condition.value.as_deref().unwrap_or("NULL")
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            // a row that no longer exists cannot be changed, its event is dropped
+            let Some(row_index) = find_row_index(&table_inserted_data, conditions) else {
+                conflicts.push(format!(
+                    "Row [{}] was deleted by someone else, its pending change was dropped",
+                    row_description
+                ));
+                continue;
+            };
+            if let BTableDataChangeEvents::ModifyRowColumnValue(row_column_value) =
+                &table_data_change_event
+            {
+                let previous_row = find_row_index(&previous_table_inserted_data, conditions).map(
+                    |previous_row_index| &previous_table_inserted_data.rows[previous_row_index],
+                );
+                for column_name in row_column_value.column_values.keys() {
+                    let value = |table_inserted_data: &BTableInsertedData, row: &Vec<String>| {
+                        table_inserted_data
+                            .column_names
+                            .iter()
+                            .position(|existing_column_name| existing_column_name == column_name)
+                            .map(|column_index| row[column_index].clone())
+                    };
+                    let current_value =
+                        value(&table_inserted_data, &table_inserted_data.rows[row_index]);
+                    let previous_value = previous_row.and_then(|previous_row| {
+                        value(&previous_table_inserted_data, previous_row)
+                    });
+                    if current_value != previous_value {
+                        conflicts.push(format!(
+                            "\"{}\" of row [{}] was changed by someone else to \"{}\"",
+                            column_name,
+                            row_description,
+                            current_value.unwrap_or_default()
+                        ));
+                    }
+                }
+            } else {
+                deleted_row_indexes.push(row_index);
+            }
+            kept_table_data_change_events.push(table_data_change_event);
+        }
+
+        *self.current_to_initial_row_indexes.lock().await = (0..table_inserted_data.rows.len())
+            .filter(|row_index| !deleted_row_indexes.contains(row_index))
+            .enumerate()
+            .collect();
+        *self.table_data_change_events.lock().await = kept_table_data_change_events;
+        *self.table_data_conflicts.lock().await = conflicts.clone();
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            for conflict in conflicts {
+                console.write(conflict);
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    // the loaded rows with the pending events applied, as they are displayed
+    pub fn get_current_table_inserted_data(&self) -> Option<BTableInsertedData> {
+        let mut table_inserted_data = self.table_inserted_data.blocking_lock().clone()?;
+        let table_data_change_events = self.table_data_change_events.blocking_lock().clone();
+        let current_to_initial_row_indexes = self.current_to_initial_row_indexes.blocking_lock();
+        let mut current_rows: Vec<Vec<String>> = (0..current_to_initial_row_indexes.len())
+            .filter_map(|row_index| current_to_initial_row_indexes.get(&row_index))
+            .map(|initial_row_index| table_inserted_data.rows[*initial_row_index].clone())
+            .collect();
+        for table_data_change_event in table_data_change_events {
+            match table_data_change_event {
+                BTableDataChangeEvents::ModifyRowColumnValue(row_column_value) => {
+                    let matching_row = current_rows.iter_mut().find(|row| {
+                        row_matches_conditions(
+                            &table_inserted_data.column_names,
+                            row,
+                            &row_column_value.conditions,
+                        )
+                    });
+                    if let Some(row) = matching_row {
+                        for (column_name, (_, value)) in row_column_value.column_values {
+                            if let Some(column_index) =
+                                table_inserted_data.column_names.iter().position(
+                                    |existing_column_name| *existing_column_name == column_name,
+                                )
+                            {
+                                row[column_index] = value.unwrap_or_default();
+                            }
+                        }
+                    }
+                }
+                BTableDataChangeEvents::InsertRow(row_insert_data) => {
+                    current_rows.push(
+                        table_inserted_data
+                            .column_names
+                            .iter()
+                            .map(|column_name| {
+                                row_insert_data
+                                    .column_names
+                                    .iter()
+                                    .position(|inserted_column_name| {
+                                        inserted_column_name == column_name
+                                    })
+                                    .map(|column_index| {
+                                        row_insert_data.values[column_index].clone()
+                                    })
+                                    .unwrap_or_default()
+                            })
+                            .collect(),
+                    );
+                }
+                BTableDataChangeEvents::DeleteRow(_) => {}
+            }
+        }
+        table_inserted_data.rows = current_rows;
+        Some(table_inserted_data)
+    }
+
+    // statuses line up with the rows of get_current_table_inserted_data
+    pub fn get_table_data_change_status(&self) -> Option<BTableDataChangeStatus> {
+        let table_inserted_data = self.table_inserted_data.blocking_lock().clone()?;
+        let table_data_change_events = self.table_data_change_events.blocking_lock().clone();
+        let current_to_initial_row_indexes = self.current_to_initial_row_indexes.blocking_lock();
+        let initial_row_indexes: Vec<usize> = (0..current_to_initial_row_indexes.len())
+            .filter_map(|row_index| current_to_initial_row_indexes.get(&row_index).cloned())
+            .collect();
+        let column_names = &table_inserted_data.column_names;
+        // modify events find their row by the loaded primary key values, in column order
+        // like get_primary_key_conditions builds them
+        let primary_key_column_names = self.primary_key_column_names.blocking_lock().clone();
+        let primary_key_values = |row: &Vec<String>| -> Vec<String> {
+            zip(column_names, row)
+                .filter(|(column_name, _)| primary_key_column_names.contains(column_name))
+                .map(|(_, value)| value.clone())
+                .collect()
+        };
+        let mut row_indexes_by_primary_key = HashMap::new();
+        for (row_index, initial_row_index) in initial_row_indexes.iter().enumerate() {
+            row_indexes_by_primary_key
+                .entry(primary_key_values(
+                    &table_inserted_data.rows[*initial_row_index],
+                ))
+                .or_insert(row_index);
+        }
+        let mut table_data_change_status = BTableDataChangeStatus {
+            cell_statuses: vec![
+                vec![BChangeStatus::Unchanged; column_names.len()];
+                initial_row_indexes.len()
+            ],
+            ..BTableDataChangeStatus::default()
+        };
+        for table_data_change_event in table_data_change_events {
+            match table_data_change_event {
+                BTableDataChangeEvents::ModifyRowColumnValue(row_column_value) => {
+                    table_data_change_status.updates_count += 1;
+                    let primary_key: Vec<String> = row_column_value
+                        .conditions
+                        .iter()
+                        .map(|condition| condition.value.clone().unwrap_or_default())
+                        .collect();
+                    if let Some(&row_index) = row_indexes_by_primary_key.get(&primary_key) {
+                        for column_name in row_column_value.column_values.keys() {
+                            if let Some(column_index) =
+                                column_names.iter().position(|existing_column_name| {
+                                    existing_column_name == column_name
+                                })
+                            {
+                                table_data_change_status.cell_statuses[row_index][column_index] =
+                                    BChangeStatus::Modified;
+                            }
+                        }
+                    }
+                }
+                BTableDataChangeEvents::InsertRow(_) => {
+                    table_data_change_status.inserts_count += 1;
+                    table_data_change_status
+                        .cell_statuses
+                        .push(vec![BChangeStatus::Inserted; column_names.len()]);
+                }
+                BTableDataChangeEvents::DeleteRow(_) => {
+                    table_data_change_status.deletes_count += 1;
+                }
+            }
+        }
+        // loaded rows no displayed row maps to are the ones queued for delete
+        let mut next_row_index = 0;
+        for (initial_row_index, row) in table_inserted_data.rows.into_iter().enumerate() {
+            if initial_row_indexes.get(next_row_index) == Some(&initial_row_index) {
+                next_row_index += 1;
+            } else {
+                table_data_change_status
+                    .deleted_rows
+                    .push((next_row_index, row));
+            }
+        }
+        Some(table_data_change_status)
+    }
+}
+
+// inserted rows carry a value for every column of the grid, computed and generated ones
+// are left to the query and to postgres
+fn without_read_only_columns(
+    table_data_change_events: Vec<BTableDataChangeEvents>,
+    read_only_column_names: &Vec<String>,
+) -> Vec<BTableDataChangeEvents> {
+    table_data_change_events
+        .into_iter()
+        .filter_map(|table_data_change_event| match table_data_change_event {
+            BTableDataChangeEvents::InsertRow(row_insert_data) => {
+                let (column_names, (values, data_types)) = zip(
+                    row_insert_data.column_names,
+                    zip(row_insert_data.values, row_insert_data.data_types),
+                )
+                .filter(|(column_name, _)| !read_only_column_names.contains(column_name))
+                .unzip();
+                Some(BTableDataChangeEvents::InsertRow(BRowInsertData {
+                    column_names,
+                    values,
+                    data_types,
+                }))
+            }
+            BTableDataChangeEvents::ModifyRowColumnValue(mut row_column_value) => {
+                row_column_value
+                    .column_values
+                    .retain(|column_name, _| !read_only_column_names.contains(column_name));
+                (!row_column_value.column_values.is_empty()).then_some(
+                    BTableDataChangeEvents::ModifyRowColumnValue(row_column_value),
+                )
+            }
+            table_data_change_event => Some(table_data_change_event),
+        })
+        .collect()
+}
+
+fn row_matches_conditions(
+    column_names: &Vec<String>,
+    row: &Vec<String>,
+    conditions: &Vec<BCondition>,
+) -> bool {
+    conditions.iter().all(|condition| {
+        column_names
+            .iter()
+            .position(|column_name| *column_name == condition.column_name)
+            .is_some_and(|column_index| {
+                // the grid shows NULL as an empty value
+                row[column_index] == condition.value.as_deref().unwrap_or_default()
+            })
+    })
+}
+
+// tables without a primary key are matched on every column of the row
+fn row_index_by_key(
+    table_inserted_data: &BTableInsertedData,
+    primary_key_column_names: &Vec<String>,
+    column_names: &Vec<String>,
+    row: &Vec<String>,
+) -> Option<usize> {
+    let key_values = column_names
+        .iter()
+        .zip(row)
+        .filter(|(column_name, _)| {
+            primary_key_column_names.is_empty() || primary_key_column_names.contains(column_name)
+        })
+        .map(|(column_name, value)| {
+            table_inserted_data
+                .column_names
+                .iter()
+                .position(|table_column_name| table_column_name == column_name)
+                .map(|column_index| (column_index, value))
+        })
+        .collect::<Option<Vec<(usize, &String)>>>()?;
+    if key_values.is_empty() {
+        return None;
+    }
+    table_inserted_data.rows.iter().position(|table_row| {
+        key_values
+            .iter()
+            .all(|(column_index, value)| table_row[*column_index] == **value)
+    })
+}
+
+fn find_row_index(
+    table_inserted_data: &BTableInsertedData,
+    conditions: &Vec<BCondition>,
+) -> Option<usize> {
+    table_inserted_data
+        .rows
+        .iter()
+        .position(|row| row_matches_conditions(&table_inserted_data.column_names, row, conditions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{
+        BAccessRule, BColumn, BConstraint, BRole, BSortDirection, BTableGeneral, BTableIn,
+        BViewSort,
+    };
+    use crate::tables::test_utils::{
+        create_insert_row_events, create_repository_table_and_console, create_session,
+        default_table_in,
+    };
+    use crate::tables::utils::set_tables_general_info;
+
+    #[test]
+    fn test_row_index_by_key() {
+        // the grid has a computed column the opened row doesn't have
+        let table_inserted_data = BTableInsertedData {
+            table_name: String::from("users"),
+            column_names: vec![
+                String::from("id"),
+                String::from("name"),
+                String::from("name_length"),
+            ],
+            data_types: vec![BDataType::INTEGER, BDataType::TEXT, BDataType::INTEGER],
+            rows: vec![
+                vec![String::from("1"), String::from("Alice"), String::from("5")],
+                vec![String::from("2"), String::from("Alice"), String::from("5")],
+            ],
+        };
+        let column_names = vec![String::from("id"), String::from("name")];
+        // the name changed since the row was found, the key still matches
+        assert_eq!(
+            row_index_by_key(
+                &table_inserted_data,
+                &vec![String::from("id")],
+                &column_names,
+                &vec![String::from("2"), String::from("Alicia")],
+            ),
+            Some(1)
+        );
+        assert_eq!(
+            row_index_by_key(
+                &table_inserted_data,
+                &vec![String::from("id")],
+                &column_names,
+                &vec![String::from("3"), String::from("Alice")],
+            ),
+            None
+        );
+        assert_eq!(
+            row_index_by_key(
+                &table_inserted_data,
+                &vec![],
+                &column_names,
+                &vec![String::from("2"), String::from("Alice")],
+            ),
+            Some(1)
+        );
+    }
+    use sqlx::PgPool;
+    use std::collections::HashMap;
+
+    async fn create_table_data(
+        pool: PgPool,
+        table_in: &BTableIn,
+        insert_row_events: &Vec<BTableDataChangeEvents>,
+        session: Arc<BSession>,
+    ) -> TableData {
+        let (repository_result, console_result) =
+            create_repository_table_and_console(pool, table_in).await;
+        repository_result
+            .update_table_data(&table_in.table_name, insert_row_events)
+            .await
+            .unwrap();
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::<BTableGeneral>::new()));
+        set_tables_general_info(repository_result.clone(), tables_general_info.clone()).await;
+        let table_data = TableData::new(
+            repository_result,
+            console_result,
+            session,
+            tables_general_info,
+        );
+        table_data.set_table_data(table_in.table_name.clone()).await;
+        table_data
+    }
+
+    #[sqlx::test]
+    async fn test_update_table_data(pool: PgPool) {
+        let table_in = default_table_in();
+        // Define common column names and data types
+        // (["id", "name"], [BDataType::INTEGER, BDataType::TEXT])
+        let (column_names, data_types): (Vec<String>, Vec<BDataType>) = table_in
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.datatype.clone()))
+            .unzip();
+        let (id, name) = (column_names[0].clone(), column_names[1].clone());
+        // Create a vector of InsertRow events
+        let insert_row_events = vec![
+            BTableDataChangeEvents::InsertRow(BRowInsertData {
+                column_names: column_names.clone(),
+                data_types: data_types.clone(),
+                values: vec!["1".to_string(), "Alice".to_string()],
+            }),
+            BTableDataChangeEvents::InsertRow(BRowInsertData {
+                column_names: column_names.clone(),
+                data_types: data_types.clone(),
+                values: vec!["2".to_string(), "Bob".to_string()],
+            }),
+            BTableDataChangeEvents::InsertRow(BRowInsertData {
+                column_names: column_names.clone(),
+                data_types: data_types.clone(),
+                values: vec!["3".to_string(), "Charlie".to_string()],
+            }),
+            BTableDataChangeEvents::InsertRow(BRowInsertData {
+                column_names: column_names.clone(),
+                data_types: data_types.clone(),
+                values: vec!["4".to_string(), "Jacob".to_string()],
+            }),
+        ];
+        let table_data = Arc::new(
+            create_table_data(
+                pool,
+                &table_in,
+                &insert_row_events,
+                create_session(BRole::Admin),
+            )
+            .await,
+        );
+        let copied_table_data = table_data.clone();
+        task::spawn_blocking(move || {
+            copied_table_data.add_modify_row_column_value_event(0, id.clone(), "5".to_string());
+            copied_table_data.add_modify_row_column_value_event(
+                3,
+                name.clone(),
+                "Liam".to_string(),
+            );
+
+            copied_table_data.add_modify_row_column_value_event(3, id.clone(), "8".to_string());
+            copied_table_data.add_delete_row_event(1);
+            copied_table_data.add_insert_row_event(vec!["6".to_string(), "".to_string()]);
+            // row index is out of range
+            copied_table_data.add_modify_row_column_value_event(
+                4,
+                name.clone(),
+                "John".to_string(),
+            );
+            copied_table_data.add_modify_row_column_value_event(
+                2,
+                name.clone(),
+                "Daniel".to_string(),
+            );
+
+            // since there was a delete row event index 3 -> 2
+        })
+        .await
+        .unwrap();
+
+        table_data.update_table_data().await;
+        let expected_table_inserted_data = BTableInsertedData {
+            table_name: table_in.table_name.clone(),
+            column_names: column_names.clone(),
+            data_types: data_types.clone(),
+            rows: vec![
+                vec!["3".to_string(), "Charlie".to_string()],
+                vec!["5".to_string(), "Alice".to_string()],
+                vec!["6".to_string(), "".to_string()],
+                vec!["8".to_string(), "Daniel".to_string()],
+            ],
+        };
+        let locked_table_inserted_data = table_data.table_inserted_data.lock().await;
+        assert_eq!(
+            *locked_table_inserted_data,
+            Some(expected_table_inserted_data)
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_access_rules(pool: PgPool) {
+        let table_in = default_table_in();
+        let (column_names, data_types): (Vec<String>, Vec<BDataType>) = table_in
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.datatype.clone()))
+            .unzip();
+        let insert_row_events = vec![BTableDataChangeEvents::InsertRow(BRowInsertData {
+            column_names: column_names.clone(),
+            data_types: data_types.clone(),
+            values: vec!["1".to_string(), "Alice".to_string()],
+        })];
+        let session = create_session(BRole::Editor);
+        session.set_access_rules(vec![
+            BAccessRule {
+                id: None,
+                role: BRole::Editor,
+                table_name: table_in.table_name.clone(),
+                column_name: None,
+                access_level: BAccessLevel::Read,
+            },
+            BAccessRule {
+                id: None,
+                role: BRole::Editor,
+                table_name: table_in.table_name.clone(),
+                column_name: Some(String::from("name")),
+                access_level: BAccessLevel::Hidden,
+            },
+            // rules of other roles are ignored
+            BAccessRule {
+                id: None,
+                role: BRole::Viewer,
+                table_name: table_in.table_name.clone(),
+                column_name: Some(String::from("id")),
+                access_level: BAccessLevel::Hidden,
+            },
+        ]);
+        let table_data =
+            Arc::new(create_table_data(pool, &table_in, &insert_row_events, session).await);
+
+        let copied_table_data = table_data.clone();
+        task::spawn_blocking(move || {
+            copied_table_data.add_modify_row_column_value_event(
+                0,
+                String::from("id"),
+                "2".to_string(),
+            );
+            copied_table_data.add_delete_row_event(0);
+            copied_table_data.add_insert_row_event(vec!["3".to_string()]);
+        })
+        .await
+        .unwrap();
+        table_data.update_table_data().await;
+
+        // the hidden column is not selected and the read-only table is unchanged
+        let expected_table_inserted_data = BTableInsertedData {
+            table_name: table_in.table_name.clone(),
+            column_names: vec![String::from("id")],
+            data_types: vec![BDataType::INTEGER],
+            rows: vec![vec!["1".to_string()]],
+        };
+        assert_eq!(
+            *table_data.table_inserted_data.lock().await,
+            Some(expected_table_inserted_data)
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_refresh_table_data_keeps_pending_changes(pool: PgPool) {
+        let table_in = default_table_in();
+        let (column_names, data_types): (Vec<String>, Vec<BDataType>) = table_in
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.datatype.clone()))
+            .unzip();
+        let insert_row_event = |values: Vec<&str>| {
+            BTableDataChangeEvents::InsertRow(BRowInsertData {
+                column_names: column_names.clone(),
+                data_types: data_types.clone(),
+                values: values.into_iter().map(String::from).collect(),
+            })
+        };
+        let insert_row_events = vec![
+            insert_row_event(vec!["1", "Alice"]),
+            insert_row_event(vec!["2", "Bob"]),
+            insert_row_event(vec!["3", "Carl"]),
+        ];
+        let table_data = Arc::new(
+            create_table_data(
+                pool,
+                &table_in,
+                &insert_row_events,
+                create_session(BRole::Admin),
+            )
+            .await,
+        );
+        let copied_table_data = table_data.clone();
+        task::spawn_blocking(move || {
+            copied_table_data.add_modify_row_column_value_event(
+                0,
+                String::from("name"),
+                "Alicia".to_string(),
+            );
+            copied_table_data.add_modify_row_column_value_event(
+                1,
+                String::from("name"),
+                "Bobby".to_string(),
+            );
+        })
+        .await
+        .unwrap();
+
+        // someone else changes Alice, deletes Bob and adds Dan
+        let condition = |value: &str| BCondition {
+            column_name: String::from("id"),
+            data_type: BDataType::INTEGER,
+            value: Some(value.to_string()),
+        };
+        table_data
+            .repository
+            .update_table_data(
+                &table_in.table_name,
+                &vec![
+                    BTableDataChangeEvents::ModifyRowColumnValue(BRowColumnValue {
+                        conditions: vec![condition("1")],
+                        column_values: HashMap::from([(
+                            String::from("name"),
+                            (BDataType::TEXT, Some(String::from("Alison"))),
+                        )]),
+                    }),
+                    BTableDataChangeEvents::DeleteRow(vec![condition("2")]),
+                    insert_row_event(vec!["4", "Dan"]),
+                ],
+            )
+            .await
+            .unwrap();
+
+        table_data.refresh_table_data().await;
+
+        assert_eq!(table_data.table_data_conflicts.lock().await.len(), 2);
+        let copied_table_data = table_data.clone();
+        let current_table_inserted_data =
+            task::spawn_blocking(move || copied_table_data.get_current_table_inserted_data())
+                .await
+                .unwrap()
+                .unwrap();
+        assert_eq!(
+            current_table_inserted_data.rows,
+            vec![
+                vec!["1".to_string(), "Alicia".to_string()],
+                vec!["3".to_string(), "Carl".to_string()],
+                vec!["4".to_string(), "Dan".to_string()],
+            ]
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_bulk_edit_selected_rows(pool: PgPool) {
+        let table_in = default_table_in();
+        let insert_row_events = create_insert_row_events(
+            &table_in,
+            vec![
+                vec!["1", "Alice"],
+                vec!["2", "Bob"],
+                vec!["3", "Carl"],
+                vec!["4", "Dan"],
+            ],
+        );
+        let table_data = Arc::new(
+            create_table_data(
+                pool,
+                &table_in,
+                &insert_row_events,
+                create_session(BRole::Admin),
+            )
+            .await,
+        );
+        let copied_table_data = table_data.clone();
+        task::spawn_blocking(move || {
+            copied_table_data.add_insert_row_event(vec!["5".to_string(), "Eve".to_string()]);
+            copied_table_data.console.clear_messages();
+            // the pending insert is part of the selection
+            copied_table_data.add_bulk_modify_column_value_events(
+                &[0, 2, 4],
+                String::from("name"),
+                "Zed".to_string(),
+            );
+            copied_table_data.add_bulk_delete_row_events(&[1, 3]);
+            // one line per operation, not one per row
+            assert_eq!(
+                copied_table_data.console.get_messages(),
+                vec![
+                    String::from("Set \"name\" to \"Zed\" in 3 rows of \"users\""),
+                    String::from("Deleted 2 rows from \"users\""),
+                ]
+            );
+        })
+        .await
+        .unwrap();
+        table_data.update_table_data().await;
+
+        let mut rows = table_data
+            .table_inserted_data
+            .lock()
+            .await
+            .as_ref()
+            .unwrap()
+            .rows
+            .clone();
+        rows.sort();
+        assert_eq!(
+            rows,
+            vec![
+                vec!["1".to_string(), "Zed".to_string()],
+                vec!["3".to_string(), "Zed".to_string()],
+                vec!["5".to_string(), "Zed".to_string()],
+            ]
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_revert_row_column_value(pool: PgPool) {
+        let table_in = default_table_in();
+        let (column_names, data_types): (Vec<String>, Vec<BDataType>) = table_in
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.datatype.clone()))
+            .unzip();
+        let insert_row_events = vec![BTableDataChangeEvents::InsertRow(BRowInsertData {
+            column_names: column_names.clone(),
+            data_types: data_types.clone(),
+            values: vec!["1".to_string(), "Alice".to_string()],
+        })];
+        let table_data = Arc::new(
+            create_table_data(
+                pool,
+                &table_in,
+                &insert_row_events,
+                create_session(BRole::Admin),
+            )
+            .await,
+        );
+        let copied_table_data = table_data.clone();
+        let reverted_values = task::spawn_blocking(move || {
+            copied_table_data.add_modify_row_column_value_event(
+                0,
+                String::from("name"),
+                "Alicia".to_string(),
+            );
+            copied_table_data.add_modify_row_column_value_event(
+                0,
+                String::from("id"),
+                "5".to_string(),
+            );
+            copied_table_data.add_insert_row_event(vec!["6".to_string(), "Bob".to_string()]);
+            (
+                copied_table_data.revert_row_column_value(0, "name"),
+                copied_table_data.revert_row_column_value(1, "name"),
+            )
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            reverted_values,
+            (Some(String::from("Alice")), Some(String::new()))
+        );
+        table_data.update_table_data().await;
+
+        let mut rows = table_data
+            .table_inserted_data
+            .lock()
+            .await
+            .as_ref()
+            .unwrap()
+            .rows
+            .clone();
+        rows.sort();
+        assert_eq!(
+            rows,
+            vec![
+                vec!["5".to_string(), "Alice".to_string()],
+                vec!["6".to_string(), "".to_string()],
+            ]
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_table_data_change_status(pool: PgPool) {
+        let table_in = default_table_in();
+        let insert_row_events = create_insert_row_events(
+            &table_in,
+            vec![vec!["1", "Alice"], vec!["2", "Bob"], vec!["3", "Carl"]],
+        );
+        let table_data = Arc::new(
+            create_table_data(
+                pool,
+                &table_in,
+                &insert_row_events,
+                create_session(BRole::Admin),
+            )
+            .await,
+        );
+        let copied_table_data = table_data.clone();
+        let table_data_change_status = task::spawn_blocking(move || {
+            copied_table_data.add_modify_row_column_value_event(
+                0,
+                String::from("name"),
+                "Alicia".to_string(),
+            );
+            copied_table_data.add_delete_row_event(1);
+            copied_table_data.add_insert_row_event(vec!["4".to_string(), "Dan".to_string()]);
+            copied_table_data.get_table_data_change_status()
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            table_data_change_status.cell_statuses,
+            vec![
+                vec![BChangeStatus::Unchanged, BChangeStatus::Modified],
+                vec![BChangeStatus::Unchanged, BChangeStatus::Unchanged],
+                vec![BChangeStatus::Inserted, BChangeStatus::Inserted],
+            ]
+        );
+        // Bob is shown before Carl, who moved up to row 1
+        assert_eq!(
+            table_data_change_status.deleted_rows,
+            vec![(1, vec!["2".to_string(), "Bob".to_string()])]
+        );
+        assert_eq!(
+            table_data_change_status.summary(),
+            "1 update, 1 insert, 1 delete"
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_computed_columns(pool: PgPool) {
+        let table_in = default_table_in();
+        let insert_row_events = create_insert_row_events(
+            &table_in,
+            vec![vec!["1", "Alice"], vec!["2", "Bob"], vec!["3", "Carl"]],
+        );
+        let table_data = Arc::new(
+            create_table_data(
+                pool.clone(),
+                &table_in,
+                &insert_row_events,
+                create_session(BRole::Admin),
+            )
+            .await,
+        );
+
+        table_data
+            .add_computed_column(
+                String::from("label"),
+                BDataType::TEXT,
+                String::from("name || '#' || id"),
+            )
+            .await
+            .unwrap();
+        table_data
+            .add_computed_column(
+                String::from("double_id"),
+                BDataType::INTEGER,
+                String::from("id * 2"),
+            )
+            .await
+            .unwrap();
+        // unknown columns and names of stored columns are refused
+        assert!(table_data
+            .add_computed_column(
+                String::from("broken"),
+                BDataType::INTEGER,
+                String::from("missing + 1"),
+            )
+            .await
+            .is_err());
+        assert!(table_data
+            .add_computed_column(String::from("name"), BDataType::TEXT, String::from("'x'"))
+            .await
+            .is_err());
+
+        table_data
+            .set_table_view(Some(BTableView {
+                table_name: table_in.table_name.clone(),
+                filters: vec![BViewFilter {
+                    column_name: String::from("double_id"),
+                    operator: BFilterOperator::GreaterThan,
+                    value: String::from("2"),
+                }],
+                sorts: vec![BViewSort {
+                    column_name: String::from("double_id"),
+                    direction: BSortDirection::Descending,
+                }],
+                ..BTableView::default()
+            }))
+            .await;
+        table_data.set_table_data(table_in.table_name.clone()).await;
+        {
+            let table_inserted_data = table_data.table_inserted_data.lock().await;
+            let table_inserted_data = table_inserted_data.as_ref().unwrap();
+            assert_eq!(
+                table_inserted_data.column_names,
+                vec!["id", "name", "double_id", "label"]
+            );
+            assert_eq!(
+                table_inserted_data.rows,
+                vec![
+                    vec!["3", "Carl", "6", "Carl#3"],
+                    vec!["2", "Bob", "4", "Bob#2"]
+                ]
+            );
+        }
+
+        // inserted rows leave the computed values to postgres
+        let copied_table_data = table_data.clone();
+        task::spawn_blocking(move || {
+            copied_table_data.add_insert_row_event(
+                vec!["4", "Dan", "", ""]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            );
+        })
+        .await
+        .unwrap();
+        table_data.update_table_data().await;
+        assert_eq!(
+            table_data
+                .table_inserted_data
+                .lock()
+                .await
+                .as_ref()
+                .unwrap()
+                .rows[0],
+            vec!["4", "Dan", "8", "Dan#4"]
+        );
+
+        table_data
+            .promote_computed_column(String::from("double_id"))
+            .await
+            .unwrap();
+        let double_ids: Vec<i32> = sqlx::query_scalar("SELECT double_id FROM users ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(double_ids, vec![2, 4, 6, 8]);
+        let computed_column_names: Vec<String> = table_data
+            .computed_columns
+            .lock()
+            .await
+            .iter()
+            .map(|computed_column| computed_column.column_name.clone())
+            .collect();
+        assert_eq!(computed_column_names, vec!["label"]);
+        assert_eq!(
+            *table_data.generated_column_names.lock().await,
+            vec!["double_id"]
+        );
+
+        // the stored column can't be edited and is left out of inserts
+        let copied_table_data = table_data.clone();
+        task::spawn_blocking(move || {
+            copied_table_data.add_modify_row_column_value_event(
+                0,
+                String::from("double_id"),
+                String::from("1"),
+            );
+            copied_table_data.add_insert_row_event(
+                vec!["5", "Eve", "", ""]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            );
+        })
+        .await
+        .unwrap();
+        assert_eq!(table_data.table_data_change_events.lock().await.len(), 1);
+        table_data.update_table_data().await;
+        assert!(table_data
+            .table_inserted_data
+            .lock()
+            .await
+            .as_ref()
+            .unwrap()
+            .rows
+            .contains(&vec![
+                String::from("5"),
+                String::from("Eve"),
+                String::from("10"),
+                String::from("Eve#5")
+            ]));
+
+        // hidden computed columns aren't evaluated
+        table_data.session.set_access_rules(vec![BAccessRule {
+            id: None,
+            role: BRole::Admin,
+            table_name: table_in.table_name.clone(),
+            column_name: Some(String::from("label")),
+            access_level: BAccessLevel::Hidden,
+        }]);
+        table_data.set_table_data(table_in.table_name.clone()).await;
+        assert_eq!(
+            table_data
+                .table_inserted_data
+                .lock()
+                .await
+                .as_ref()
+                .unwrap()
+                .column_names,
+            vec!["id", "name", "double_id"]
+        );
+        assert!(table_data.computed_columns.lock().await.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_child_records(pool: PgPool) {
+        let table_in = default_table_in();
+        let table_data = create_table_data(
+            pool.clone(),
+            &table_in,
+            &vec![],
+            create_session(BRole::Admin),
+        )
+        .await;
+        table_data
+            .repository
+            .create_table(&BTableIn {
+                table_name: String::from("orders"),
+                columns: vec![
+                    BColumn {
+                        name: String::from("id"),
+                        datatype: BDataType::SERIAL,
+                        constraints: vec![BConstraint::PrimaryKey],
+                    },
+                    BColumn {
+                        name: String::from("user_id"),
+                        datatype: BDataType::INTEGER,
+                        constraints: vec![BConstraint::ForeignKey(
+                            table_in.table_name.clone(),
+                            String::from("id"),
+                        )],
+                    },
+                ],
+            })
+            .await;
+        sqlx::query("INSERT INTO users VALUES (1, 'Alice'), (2, 'Bob')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO orders (user_id) VALUES (1), (2), (1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        set_tables_general_info(
+            table_data.repository.clone(),
+            table_data.tables_general_info.clone(),
+        )
+        .await;
+
+        let column_names = vec![String::from("id"), String::from("name")];
+        table_data
+            .set_child_records(
+                column_names.clone(),
+                vec![String::from("1"), String::from("Alice")],
+            )
+            .await;
+        let child_records = table_data.child_records.lock().await.clone();
+        assert_eq!(child_records.len(), 1);
+        assert_eq!(child_records[0].foreign_key_reference.table_name, "orders");
+        assert_eq!(
+            child_records[0].foreign_key_reference.column_name,
+            "user_id"
+        );
+        assert_eq!(
+            child_records[0].table_inserted_data.rows,
+            vec![vec!["1", "1"], vec!["3", "1"]]
+        );
+
+        // an unsaved row has no key for children to point at
+        table_data
+            .set_child_records(column_names, vec![String::new(), String::from("Carl")])
+            .await;
+        assert!(table_data.child_records.lock().await.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_detail_tables(pool: PgPool) {
+        let table_in = default_table_in();
+        let table_data = create_table_data(
+            pool.clone(),
+            &table_in,
+            &vec![],
+            create_session(BRole::Admin),
+        )
+        .await;
+        table_data
+            .repository
+            .create_table(&BTableIn {
+                table_name: String::from("orders"),
+                columns: vec![
+                    BColumn {
+                        name: String::from("id"),
+                        datatype: BDataType::SERIAL,
+                        constraints: vec![BConstraint::PrimaryKey],
+                    },
+                    BColumn {
+                        name: String::from("user_id"),
+                        datatype: BDataType::INTEGER,
+                        constraints: vec![BConstraint::ForeignKey(
+                            table_in.table_name.clone(),
+                            String::from("id"),
+                        )],
+                    },
+                    BColumn {
+                        name: String::from("item"),
+                        datatype: BDataType::TEXT,
+                        constraints: vec![],
+                    },
+                ],
+            })
+            .await;
+        sqlx::query("INSERT INTO users VALUES (1, 'Alice'), (2, 'Bob')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO orders (user_id, item) VALUES (1, 'pen'), (2, 'ink'), (1, 'cap')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        set_tables_general_info(
+            table_data.repository.clone(),
+            table_data.tables_general_info.clone(),
+        )
+        .await;
+
+        table_data
+            .set_detail_tables(
+                vec![String::from("id"), String::from("name")],
+                vec![String::from("1"), String::from("Alice")],
+            )
+            .await;
+        let detail_tables = table_data.detail_tables.lock().await.clone();
+        assert_eq!(detail_tables.len(), 1);
+        let detail_table = detail_tables[0].clone();
+        assert_eq!(detail_table.referenced_value, "1");
+        assert_eq!(
+            detail_table
+                .table_data
+                .table_inserted_data
+                .lock()
+                .await
+                .as_ref()
+                .unwrap()
+                .rows,
+            vec![vec!["1", "1", "pen"], vec!["3", "1", "cap"]]
+        );
+
+        // new children are inserted with the parent's key filled in
+        let copied_detail_table = detail_table.clone();
+        task::spawn_blocking(move || {
+            copied_detail_table.add_insert_row_event();
+            copied_detail_table
+                .table_data
+                .add_modify_row_column_value_event(2, String::from("item"), String::from("pad"));
+            copied_detail_table
+                .table_data
+                .add_modify_row_column_value_event(0, String::from("item"), String::from("pencil"));
+        })
+        .await
+        .unwrap();
+        detail_table.table_data.update_table_data().await;
+
+        let items: Vec<(i32, String)> =
+            sqlx::query_as("SELECT user_id, item FROM orders ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            items,
+            vec![
+                (1, String::from("pencil")),
+                (2, String::from("ink")),
+                (1, String::from("cap")),
+                (1, String::from("pad")),
+            ]
+        );
+        assert_eq!(
+            detail_table
+                .table_data
+                .table_inserted_data
+                .lock()
+                .await
+                .as_ref()
+                .unwrap()
+                .rows
+                .len(),
+            3
+        );
+    }
+}
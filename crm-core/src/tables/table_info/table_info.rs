@@ -1,13 +1,17 @@
-use crate::components::business_components::component::{
-    repository_module::BRepository, BColumn, BColumnForeignKey, BConstraint, BDataType,
-    BTableChangeEvents, BTableData, BTableGeneral, BTableInsertedData, BusinessComponent,
+use crate::component::{
+    repository_module::BRepository, BColumn, BColumnConversion, BColumnForeignKey, BColumnLayout,
+    BConstraint, BConversionPreview, BDataType, BPermission, BTableChangeEvents, BTableData,
+    BTableGeneral,
 };
-use crate::components::business_components::components::BusinessConsole;
-use crate::components::business_components::tables::utils::set_tables_general_info;
-use std::sync::{Arc, Mutex};
+use crate::components::BusinessConsole;
+use crate::tables::utils::set_tables_general_info;
+use std::sync::Arc;
 use tokio::sync::Mutex as AsyncMutex;
 use tokio::task;
 
+// failing values shown for each column whose type changes
+const CONVERSION_SAMPLE_SIZE: i64 = 5;
+
 #[derive(Debug, Clone)]
 pub struct TableInfo {
     repository: Arc<BRepository>,
@@ -15,6 +19,8 @@ pub struct TableInfo {
     pub columns_info: Arc<AsyncMutex<Vec<BColumn>>>,
     pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
     table_change_events: Arc<AsyncMutex<Vec<BTableChangeEvents>>>,
+    pub conversion_previews: Arc<AsyncMutex<Vec<BConversionPreview>>>,
+    pub column_layout: Arc<AsyncMutex<BColumnLayout>>,
     console: Arc<BusinessConsole>,
     table_data: Arc<BTableData>,
 }
@@ -31,6 +37,8 @@ impl TableInfo {
             table_name: Arc::new(AsyncMutex::new(None)),
             columns_info: Arc::new(AsyncMutex::new(vec![])),
             table_change_events: Arc::new(AsyncMutex::new(vec![])),
+            conversion_previews: Arc::new(AsyncMutex::new(vec![])),
+            column_layout: Arc::new(AsyncMutex::new(BColumnLayout::default())),
             console,
             tables_general_info,
             table_data,
@@ -48,6 +56,8 @@ impl TableInfo {
         *columns_info = vec![];
         let mut table_change_events = self.table_change_events.blocking_lock();
         *table_change_events = vec![];
+        *self.conversion_previews.blocking_lock() = vec![];
+        *self.column_layout.blocking_lock() = BColumnLayout::default();
     }
 
     pub async fn set_table_info(&self, table_name: String) {
@@ -60,12 +70,24 @@ impl TableInfo {
             console.clear_messages()
         });
         let columns_info = self.repository.get_columns_info(&table_name).await.unwrap();
-        let columns_info_with_enums = columns_info
-            .into_iter()
-            .map(|column_info| BColumn::to_column(column_info))
-            .collect();
+        let column_layout = self
+            .repository
+            .get_column_layout(&table_name)
+            .await
+            .unwrap()
+            .map(BColumnLayout::to_column_layout)
+            .unwrap_or(BColumnLayout {
+                table_name: table_name.clone(),
+                ..BColumnLayout::default()
+            });
+        let columns_info_with_enums = column_layout.order(
+            columns_info.into_iter().map(BColumn::to_column).collect(),
+            |column| &column.name,
+        );
 
         // Lock the async mutex and update the columns_info
+        *self.column_layout.lock().await = column_layout;
+        *self.conversion_previews.lock().await = vec![];
         let mut locked_columns_info = self.columns_info.lock().await;
         *locked_columns_info = columns_info_with_enums;
         let mut locked_table_name = self.table_name.lock().await;
@@ -111,6 +133,9 @@ impl TableInfo {
             BTableChangeEvents::RemovePrimaryKey(column_name) => {
                 self.handle_remove_primary_key(column_name, &mut locked_table_change_events);
             }
+            BTableChangeEvents::AddGeneratedColumn(..) => {
+                locked_table_change_events.push(table_change_event);
+            }
         }
 
         self.console
@@ -362,7 +387,7 @@ impl TableInfo {
     fn update_existing_add_column_event_locked(
         &self,
         event_index: usize,
-        column_name: String,
+        _column_name: String,
         new_column_name: String,
         locked_table_change_events: &mut Vec<BTableChangeEvents>,
     ) {
@@ -549,7 +574,7 @@ impl TableInfo {
             .filter(|table_change_event| {
                 matches!(
                     table_change_event,
-                    BTableChangeEvents::AddPrimaryKey(column_name)
+                    BTableChangeEvents::AddPrimaryKey(_column_name)
                 )
             })
             .count()
@@ -566,15 +591,52 @@ impl TableInfo {
                     .await
                     .unwrap();
 
+            let column_conversions: Vec<BColumnConversion> = self
+                .conversion_previews
+                .lock()
+                .await
+                .iter()
+                .map(|conversion_preview| conversion_preview.column_conversion.clone())
+                .collect();
             let res = self
                 .repository
                 .alter_table(
                     locked_table_name.as_ref().unwrap(),
-                    &*locked_table_change_events,
+                    &locked_table_change_events,
                     &primary_key_column_names,
+                    &column_conversions,
                 )
                 .await;
-            println!("Alter table result: {:?}", res);
+            if let Err(error) = res {
+                let console = self.console.clone();
+                let table_name = locked_table_name.as_ref().unwrap().clone();
+                task::spawn_blocking(move || {
+                    console.write(format!("Altering \"{}\" failed: {}", table_name, error));
+                })
+                .await
+                .unwrap();
+            } else {
+                self.rename_column_layout(
+                    locked_table_name.as_ref().unwrap(),
+                    &locked_table_change_events,
+                )
+                .await;
+                if let Some(BTableChangeEvents::ChangeTableName(new_table_name)) =
+                    locked_table_change_events
+                        .iter()
+                        .find(|table_change_event| {
+                            matches!(table_change_event, BTableChangeEvents::ChangeTableName(_))
+                        })
+                {
+                    self.repository
+                        .rename_computed_columns_table(
+                            locked_table_name.as_ref().unwrap(),
+                            new_table_name,
+                        )
+                        .await
+                        .unwrap();
+                }
+            }
         }
 
         for event in locked_table_change_events.iter() {
@@ -587,6 +649,152 @@ impl TableInfo {
         locked_table_change_events.clear();
     }
 
+    // the layout follows renamed tables and columns
+    async fn rename_column_layout(
+        &self,
+        table_name: &str,
+        table_change_events: &Vec<BTableChangeEvents>,
+    ) {
+        let Some(column_layout_info) = self.repository.get_column_layout(table_name).await.unwrap()
+        else {
+            return;
+        };
+        let mut column_layout = BColumnLayout::to_column_layout(column_layout_info);
+        for table_change_event in table_change_events {
+            match table_change_event {
+                BTableChangeEvents::ChangeTableName(new_table_name) => {
+                    column_layout.table_name = new_table_name.clone();
+                }
+                BTableChangeEvents::ChangeColumnName(column_name, new_column_name) => {
+                    column_layout.rename_column(column_name, new_column_name);
+                }
+                _ => {}
+            }
+        }
+        if column_layout.table_name != table_name {
+            self.repository
+                .delete_column_layout(table_name)
+                .await
+                .unwrap();
+        }
+        self.repository
+            .save_column_layout(&column_layout)
+            .await
+            .unwrap();
+    }
+
+    pub async fn save_column_layout(
+        &self,
+        column_names: Vec<String>,
+        hidden_column_names: Vec<String>,
+    ) -> Result<(), String> {
+        self.table_data
+            .session
+            .authorize(BPermission::AlterSchema)?;
+        let table_name = self.table_name.lock().await.clone().unwrap();
+        let column_layout = BColumnLayout {
+            table_name: table_name.clone(),
+            column_names,
+            hidden_column_names,
+        };
+        self.repository
+            .save_column_layout(&column_layout)
+            .await
+            .map_err(|error| error.to_string())?;
+        {
+            let mut locked_columns_info = self.columns_info.lock().await;
+            *locked_columns_info = column_layout
+                .order(locked_columns_info.drain(..).collect(), |column| {
+                    &column.name
+                });
+        }
+        *self.column_layout.lock().await = column_layout;
+        set_tables_general_info(self.repository.clone(), self.tables_general_info.clone()).await;
+        self.table_data.refresh_table_data().await;
+        Ok(())
+    }
+
+    // type changes of columns that already have rows to convert
+    fn column_type_changes(
+        table_change_events: &Vec<BTableChangeEvents>,
+        columns_info: &Vec<BColumn>,
+    ) -> Vec<(String, String, BDataType)> {
+        table_change_events
+            .iter()
+            .filter_map(|table_change_event| match table_change_event {
+                BTableChangeEvents::ChangeColumnDataType(column_name, data_type) => {
+                    // a renamed column still has its original name in the database
+                    let database_column_name = table_change_events
+                        .iter()
+                        .find_map(|table_change_event| match table_change_event {
+                            BTableChangeEvents::ChangeColumnName(original_name, new_name)
+                                if new_name == column_name =>
+                            {
+                                Some(original_name.clone())
+                            }
+                            _ => None,
+                        })
+                        .unwrap_or(column_name.clone());
+                    columns_info
+                        .iter()
+                        .any(|column| column.name == database_column_name)
+                        .then(|| (column_name.clone(), database_column_name, data_type.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn has_column_type_changes(&self) -> bool {
+        !Self::column_type_changes(
+            &self.table_change_events.blocking_lock(),
+            &self.columns_info.blocking_lock(),
+        )
+        .is_empty()
+    }
+
+    /* checks every pending type change against the rows of the table, the
+     * given conversions replace the plain casts of their columns */
+    pub async fn preview_column_conversions(
+        &self,
+        column_conversions: Vec<BColumnConversion>,
+    ) -> Result<(), String> {
+        let table_name = self.table_name.lock().await.clone().unwrap();
+        let column_type_changes = Self::column_type_changes(
+            &*self.table_change_events.lock().await,
+            &*self.columns_info.lock().await,
+        );
+
+        let mut conversion_previews = vec![];
+        for (column_name, database_column_name, data_type) in column_type_changes {
+            let column_conversion = column_conversions
+                .iter()
+                .find(|column_conversion| {
+                    column_conversion.column_name == column_name
+                        && column_conversion.data_type == data_type
+                })
+                .cloned()
+                .unwrap_or_else(|| BColumnConversion::new(column_name.clone(), data_type));
+            let (failed_row_count, failed_values) = self
+                .repository
+                .preview_column_conversion(
+                    &table_name,
+                    &database_column_name,
+                    &column_conversion,
+                    CONVERSION_SAMPLE_SIZE,
+                )
+                .await
+                .map_err(|error| format!("Checking \"{}\" failed: {}", column_name, error))?;
+            conversion_previews.push(BConversionPreview {
+                column_conversion,
+                failed_row_count,
+                failed_values,
+            });
+        }
+        *self.conversion_previews.lock().await = conversion_previews;
+        Ok(())
+    }
+
     pub fn at_least_one_primary_key(&self) -> bool {
         let mut remove_primary_key_count = 0;
         let primary_key_column_names = self.primary_key_column_names();
@@ -602,7 +810,7 @@ impl TableInfo {
                 {
                     remove_primary_key_count += 1;
                 }
-            } else if let BTableChangeEvents::RemovePrimaryKey(column_name) = table_change_event {
+            } else if let BTableChangeEvents::RemovePrimaryKey(_column_name) = table_change_event {
                 remove_primary_key_count += 1;
             }
         }
@@ -610,6 +818,15 @@ impl TableInfo {
     }
 
     pub async fn update_table(&self) {
+        if let Err(message) = self.table_data.session.authorize(BPermission::AlterSchema) {
+            let console = self.console.clone();
+            task::spawn_blocking(move || {
+                console.write(message);
+            })
+            .await
+            .unwrap();
+            return;
+        }
         let table_info = self.clone();
         task::spawn_blocking(move || {
             let at_least_one_primary_key = table_info.at_least_one_primary_key();
@@ -622,7 +839,8 @@ impl TableInfo {
                 table_info.add_table_change_event(BTableChangeEvents::AddPrimaryKey(column_name));
             }
         })
-        .await;
+        .await
+        .unwrap();
         self.alter_table().await;
         let current_table_name = { self.table_name.lock().await.as_ref().unwrap().clone() };
 
@@ -638,12 +856,9 @@ impl TableInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::components::business_components::component::{
-        repository_module::BRepositoryConsole, BTableIn,
-    };
-    use crate::components::business_components::tables::test_utils::{
-        create_btable_general, create_repository_table_and_console, default_table_in, sort_columns,
-        sort_tables_general_info,
+    use crate::component::{BRole, BTableIn};
+    use crate::tables::test_utils::{
+        create_repository_table_and_console, create_session, default_table_in, sort_columns,
     };
     use sqlx::PgPool;
 
@@ -657,6 +872,7 @@ mod tests {
         let table_data = Arc::new(BTableData::new(
             repository_result.clone(),
             console_result.clone(),
+            create_session(BRole::Admin),
             Arc::new(AsyncMutex::new(Vec::new())),
         ));
         let table_info = TableInfo::new(
@@ -771,9 +987,9 @@ mod tests {
             for event in table_change_events {
                 table_info_copy.add_table_change_event(event);
             }
-            println!("{:?}", table_info_copy.table_change_events.blocking_lock());
         })
-        .await;
+        .await
+        .unwrap();
         table_info.update_table().await;
 
         let mut expected_columns = vec![
@@ -813,8 +1029,10 @@ mod tests {
         ];
         sort_columns(&mut expected_columns);
 
-        let columns_info = table_info.columns_info.lock().await;
-        assert_eq!(*columns_info, expected_columns);
+        // the columns come in the order they were added in, not by name
+        let mut columns_info = table_info.columns_info.lock().await.clone();
+        sort_columns(&mut columns_info);
+        assert_eq!(columns_info, expected_columns);
 
         let expected_table_name = String::from("clients");
         assert_eq!(
@@ -826,6 +1044,110 @@ mod tests {
         assert!(table_change_events.is_empty());
     }
 
+    #[sqlx::test]
+    async fn test_preview_and_apply_column_conversion(pool: PgPool) {
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        let table_in = default_table_in();
+        let table_info = create_table_info(pool.clone(), &table_in, tables_general_info).await;
+        sqlx::query("INSERT INTO users VALUES (1, '42'), (2, 'forty'), (3, NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let table_info_copy = table_info.clone();
+        task::spawn_blocking(move || {
+            table_info_copy.add_table_change_event(BTableChangeEvents::ChangeColumnName(
+                String::from("name"),
+                String::from("age"),
+            ));
+            table_info_copy.add_table_change_event(BTableChangeEvents::ChangeColumnDataType(
+                String::from("age"),
+                BDataType::INTEGER,
+            ));
+            assert!(table_info_copy.has_column_type_changes());
+        })
+        .await
+        .unwrap();
+
+        table_info.preview_column_conversions(vec![]).await.unwrap();
+        {
+            let conversion_previews = table_info.conversion_previews.lock().await;
+            assert_eq!(conversion_previews.len(), 1);
+            assert_eq!(conversion_previews[0].failed_row_count, 1);
+            assert_eq!(
+                conversion_previews[0].failed_values,
+                vec![Some(String::from("forty"))]
+            );
+        }
+
+        // the failing row falls back to the default
+        let mut column_conversion = BColumnConversion::new(String::from("age"), BDataType::INTEGER);
+        column_conversion.failure_default = String::from("0");
+        table_info
+            .preview_column_conversions(vec![column_conversion])
+            .await
+            .unwrap();
+        assert_eq!(
+            table_info.conversion_previews.lock().await[0].failed_row_count,
+            0
+        );
+
+        table_info.update_table().await;
+        let ages: Vec<Option<i32>> = sqlx::query_scalar("SELECT age FROM users ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(ages, vec![Some(42), Some(0), None]);
+    }
+
+    #[sqlx::test]
+    async fn test_column_layout(pool: PgPool) {
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        let table_in = default_table_in();
+        let table_info =
+            create_table_info(pool.clone(), &table_in, tables_general_info.clone()).await;
+
+        table_info
+            .save_column_layout(
+                vec![String::from("name"), String::from("id")],
+                vec![String::from("id")],
+            )
+            .await
+            .unwrap();
+        let column_names: Vec<String> = table_info
+            .columns_info
+            .lock()
+            .await
+            .iter()
+            .map(|column| column.name.clone())
+            .collect();
+        assert_eq!(column_names, vec![String::from("name"), String::from("id")]);
+        assert_eq!(
+            tables_general_info.lock().await[0].column_names,
+            vec![String::from("name"), String::from("id")]
+        );
+
+        // the layout follows a renamed column
+        let table_info_copy = table_info.clone();
+        task::spawn_blocking(move || {
+            table_info_copy.add_table_change_event(BTableChangeEvents::ChangeColumnName(
+                String::from("id"),
+                String::from("user_id"),
+            ));
+        })
+        .await
+        .unwrap();
+        table_info.update_table().await;
+        assert_eq!(
+            *table_info.column_layout.lock().await,
+            BColumnLayout {
+                table_name: String::from("users"),
+                column_names: vec![String::from("name"), String::from("user_id")],
+                hidden_column_names: vec![String::from("user_id")],
+            }
+        );
+    }
+
     #[sqlx::test]
     async fn test_alter_table_that_removes_all_primary_keys(pool: PgPool) {
         let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
@@ -841,9 +1163,9 @@ mod tests {
             for event in table_change_events {
                 table_info_copy.add_table_change_event(event);
             }
-            println!("{:?}", table_info_copy.table_change_events.blocking_lock());
         })
-        .await;
+        .await
+        .unwrap();
         table_info.update_table().await;
         let columns_info = table_info.columns_info.lock().await;
         let expected_primary_key_column = BColumn {
@@ -851,8 +1173,6 @@ mod tests {
             datatype: BDataType::INTEGER,
             constraints: vec![BConstraint::PrimaryKey],
         };
-        assert!(columns_info
-            .iter()
-            .any(|column| *column == expected_primary_key_column));
+        assert!(columns_info.contains(&expected_primary_key_column));
     }
 }
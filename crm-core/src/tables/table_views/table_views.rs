@@ -0,0 +1,268 @@
+use crate::component::{repository_module::BRepository, BTableData, BTableView, BusinessComponent};
+use crate::components::BusinessConsole;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+#[derive(Debug, Clone)]
+pub struct TableViews {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    table_data: Arc<BTableData>,
+    pub table_name: Arc<AsyncMutex<Option<String>>>,
+    pub table_views: Arc<AsyncMutex<Vec<BTableView>>>,
+}
+
+impl BusinessComponent for TableViews {
+    async fn initialize_component(&self) {
+        self.repository.create_table_views_table().await.unwrap();
+    }
+}
+
+impl TableViews {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        table_data: Arc<BTableData>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            table_data,
+            table_name: Arc::new(AsyncMutex::new(None)),
+            table_views: Arc::new(AsyncMutex::new(vec![])),
+        }
+    }
+
+    pub fn reset_table_views(&self) {
+        *self.table_name.blocking_lock() = None;
+        *self.table_views.blocking_lock() = vec![];
+    }
+
+    async fn set_table_views(&self, table_name: String) {
+        let table_views = self
+            .repository
+            .get_table_views(&table_name)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(BTableView::to_table_view)
+            .collect();
+        *self.table_views.lock().await = table_views;
+        *self.table_name.lock().await = Some(table_name);
+    }
+
+    // loads the table's views and its rows through the default view if one is set
+    pub async fn open_table(&self, table_name: String) {
+        self.set_table_views(table_name.clone()).await;
+        let default_table_view = self
+            .table_views
+            .lock()
+            .await
+            .iter()
+            .find(|table_view| table_view.is_default)
+            .cloned();
+        self.table_data.set_table_view(default_table_view).await;
+        self.table_data.set_table_data(table_name).await;
+    }
+
+    // used when jumping to a specific row, a default view could filter it out
+    pub async fn open_table_without_view(&self, table_name: String) {
+        self.set_table_views(table_name.clone()).await;
+        self.table_data.set_table_view(None).await;
+        self.table_data.set_table_data(table_name).await;
+    }
+
+    pub async fn apply_table_view(&self, table_view: Option<BTableView>) {
+        let table_name = { self.table_name.lock().await.clone() };
+        if let Some(table_name) = table_name {
+            self.table_data.set_table_view(table_view).await;
+            self.table_data.set_table_data(table_name).await;
+        }
+    }
+
+    pub async fn save_table_view(&self, table_view: BTableView) {
+        if let Err(error) = self.repository.upsert_table_view(&table_view).await {
+            self.write_to_console(format!(
+                "Failed to save view \"{}\": {}",
+                table_view.view_name, error
+            ))
+            .await;
+            return;
+        }
+        self.set_table_views(table_view.table_name.clone()).await;
+        let saved_table_view = self
+            .table_views
+            .lock()
+            .await
+            .iter()
+            .find(|existing_table_view| existing_table_view.view_name == table_view.view_name)
+            .cloned();
+        self.apply_table_view(saved_table_view).await;
+    }
+
+    pub async fn rename_table_view(&self, table_view_id: i32, view_name: String) {
+        if let Err(error) = self
+            .repository
+            .rename_table_view(table_view_id, &view_name)
+            .await
+        {
+            self.write_to_console(format!(
+                "Failed to rename view to \"{}\": {}",
+                view_name, error
+            ))
+            .await;
+            return;
+        }
+        self.reload_table_views().await;
+        let mut locked_table_view = self.table_data.table_view.lock().await;
+        if let Some(table_view) = locked_table_view.as_mut() {
+            if table_view.id == Some(table_view_id) {
+                table_view.view_name = view_name;
+            }
+        }
+    }
+
+    pub async fn delete_table_view(&self, table_view_id: i32) {
+        self.repository
+            .delete_table_view(table_view_id)
+            .await
+            .unwrap();
+        self.reload_table_views().await;
+        let is_active_table_view = self
+            .table_data
+            .table_view
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|table_view| table_view.id == Some(table_view_id));
+        if is_active_table_view {
+            self.apply_table_view(None).await;
+        }
+    }
+
+    // passing None clears the default so the table opens unfiltered
+    pub async fn set_default_table_view(&self, table_view_id: Option<i32>) {
+        let table_name = { self.table_name.lock().await.clone() };
+        if let Some(table_name) = table_name {
+            self.repository
+                .set_default_table_view(&table_name, table_view_id)
+                .await
+                .unwrap();
+            self.reload_table_views().await;
+        }
+    }
+
+    pub async fn delete_table_views(&self, table_name: &str) {
+        self.repository
+            .delete_table_views(table_name)
+            .await
+            .unwrap();
+    }
+
+    async fn reload_table_views(&self) {
+        let table_name = { self.table_name.lock().await.clone() };
+        if let Some(table_name) = table_name {
+            self.set_table_views(table_name).await;
+        }
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::{BFilterOperator, BRole, BSortDirection, BViewFilter, BViewSort};
+    use crate::tables::test_utils::{
+        create_insert_row_events, create_repository_table_and_console, create_session,
+        default_table_in,
+    };
+    use crate::tables::utils::set_tables_general_info;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_save_and_apply_table_view(pool: PgPool) {
+        let table_in = default_table_in();
+        let (repository, console) = create_repository_table_and_console(pool, &table_in).await;
+        let insert_row_events = create_insert_row_events(
+            &table_in,
+            vec![vec!["1", "Alice"], vec!["2", "Bob"], vec!["3", "Alicia"]],
+        );
+        repository
+            .update_table_data(&table_in.table_name, &insert_row_events)
+            .await
+            .unwrap();
+
+        let tables_general_info = Arc::new(AsyncMutex::new(Vec::new()));
+        set_tables_general_info(repository.clone(), tables_general_info.clone()).await;
+        let table_data = Arc::new(BTableData::new(
+            repository.clone(),
+            console.clone(),
+            create_session(BRole::Admin),
+            tables_general_info,
+        ));
+        let table_views = TableViews::new(repository, console, table_data.clone());
+        table_views.initialize_component().await;
+        table_views.open_table(table_in.table_name.clone()).await;
+
+        table_views
+            .save_table_view(BTableView {
+                id: None,
+                table_name: table_in.table_name.clone(),
+                view_name: String::from("Ali"),
+                column_names: vec![String::from("name")],
+                filters: vec![BViewFilter {
+                    column_name: String::from("name"),
+                    operator: BFilterOperator::Contains,
+                    value: String::from("ali"),
+                }],
+                sorts: vec![BViewSort {
+                    column_name: String::from("id"),
+                    direction: BSortDirection::Descending,
+                }],
+                is_default: false,
+            })
+            .await;
+
+        let rows = |table_data: &Arc<BTableData>| {
+            let table_data = table_data.clone();
+            async move {
+                table_data
+                    .table_inserted_data
+                    .lock()
+                    .await
+                    .as_ref()
+                    .unwrap()
+                    .rows
+                    .clone()
+            }
+        };
+        assert_eq!(
+            rows(&table_data).await,
+            vec![
+                vec![String::from("3"), String::from("Alicia")],
+                vec![String::from("1"), String::from("Alice")],
+            ]
+        );
+
+        // the default view is applied when the table is opened again
+        let table_view_id = table_views.table_views.lock().await[0].id;
+        table_views.set_default_table_view(table_view_id).await;
+        table_views.apply_table_view(None).await;
+        assert_eq!(rows(&table_data).await.len(), 3);
+        table_views.open_table(table_in.table_name.clone()).await;
+        assert_eq!(rows(&table_data).await.len(), 2);
+
+        table_views.delete_table_view(table_view_id.unwrap()).await;
+        assert!(table_views.table_views.lock().await.is_empty());
+        assert_eq!(rows(&table_data).await.len(), 3);
+    }
+}
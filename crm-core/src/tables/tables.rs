@@ -0,0 +1,564 @@
+use crate::component::{
+    repository_module::BRepository, BAccessLevel, BColumn, BConstraint, BDataType, BPermission,
+    BSession, BTableChange, BTableData, BTableGeneral, BTableIn, BTableInfo, BTableViews,
+    BusinessComponent,
+};
+
+use crate::components::BusinessConsole;
+use crate::tables::utils::set_tables_general_info;
+use sqlx::postgres::PgListener;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::{task, time};
+
+// wait before reconnecting when the notification connection is lost
+const TABLE_CHANGE_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct Tables {
+    repository: Arc<BRepository>,
+    pub table_info: Arc<BTableInfo>,
+    pub table_data: Arc<BTableData>,
+    pub table_views: Arc<BTableViews>,
+    pub tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+    pub session: Arc<BSession>,
+    console: Arc<BusinessConsole>,
+    table_change_listener: Arc<AsyncMutex<Option<PgListener>>>,
+}
+
+impl BusinessComponent for Tables {
+    async fn initialize_component(&self) {
+        set_tables_general_info(self.repository.clone(), self.tables_general_info.clone()).await;
+        self.table_views.initialize_component().await;
+        // tables created outside of the app get their trigger here
+        let table_names: Vec<String> = self
+            .tables_general_info
+            .lock()
+            .await
+            .iter()
+            .map(|table_general_info| table_general_info.table_name.clone())
+            .collect();
+        for table_name in table_names {
+            if let Err(error) = self
+                .repository
+                .install_table_change_trigger(&table_name)
+                .await
+            {
+                self.write_to_console(format!(
+                    "Changes to \"{}\" will not refresh automatically: {}",
+                    table_name, error
+                ))
+                .await;
+            }
+        }
+    }
+}
+
+impl Tables {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+    ) -> Self {
+        let tables_general_info = Arc::new(AsyncMutex::new(vec![]));
+        let table_data = Arc::new(BTableData::new(
+            repository.clone(),
+            console.clone(),
+            session.clone(),
+            tables_general_info.clone(),
+        ));
+
+        Self {
+            table_info: Arc::new(BTableInfo::new(
+                repository.clone(),
+                console.clone(),
+                tables_general_info.clone(),
+                table_data.clone(),
+            )),
+            table_views: Arc::new(BTableViews::new(
+                repository.clone(),
+                console.clone(),
+                table_data.clone(),
+            )),
+            table_data,
+            repository,
+            tables_general_info,
+            session,
+            console,
+            table_change_listener: Arc::new(AsyncMutex::new(None)),
+        }
+    }
+
+    // waits for the next change saved by any session, including this one
+    pub async fn wait_for_table_change(&self) -> BTableChange {
+        let mut locked_table_change_listener = self.table_change_listener.lock().await;
+        loop {
+            if locked_table_change_listener.is_none() {
+                match self.repository.listen_for_table_changes().await {
+                    Ok(table_change_listener) => {
+                        *locked_table_change_listener = Some(table_change_listener)
+                    }
+                    Err(error) => {
+                        self.write_to_console(format!("Failed to listen for changes: {}", error))
+                            .await;
+                        time::sleep(TABLE_CHANGE_RETRY_DELAY).await;
+                        continue;
+                    }
+                }
+            }
+            let table_change_listener = locked_table_change_listener.as_mut().unwrap();
+            match self
+                .repository
+                .receive_table_change(table_change_listener)
+                .await
+            {
+                Ok(table_change) => return table_change,
+                Err(error) => {
+                    self.write_to_console(format!("Lost the change notifications: {}", error))
+                        .await;
+                    *locked_table_change_listener = None;
+                    time::sleep(TABLE_CHANGE_RETRY_DELAY).await;
+                }
+            }
+        }
+    }
+
+    pub async fn apply_table_change(&self, table_change: BTableChange) {
+        let open_table_name = {
+            self.table_data
+                .table_inserted_data
+                .lock()
+                .await
+                .as_ref()
+                .map(|table_inserted_data| table_inserted_data.table_name.clone())
+        };
+        match table_change {
+            BTableChange::Schema => {
+                set_tables_general_info(self.repository.clone(), self.tables_general_info.clone())
+                    .await;
+                self.table_data.refresh_table_data().await;
+            }
+            BTableChange::Rows(table_name) if open_table_name.as_ref() == Some(&table_name) => {
+                self.table_data.refresh_table_data().await;
+            }
+            BTableChange::Rows(_) => {}
+        }
+    }
+
+    pub async fn add_table(&self, mut table_in: BTableIn) {
+        if let Err(message) = self.session.authorize(BPermission::AlterSchema) {
+            self.write_to_console(message).await;
+            return;
+        }
+        // Check if no column has a primary key constraint
+        if !table_in.columns.iter().any(|column| {
+            column
+                .constraints
+                .iter()
+                .any(|constraint| matches!(constraint, BConstraint::PrimaryKey))
+        }) {
+            // Add a default primary key column if none exists
+            table_in.columns.push(BColumn {
+                name: "id".to_string(),
+                datatype: BDataType::INTEGER,
+                constraints: vec![BConstraint::PrimaryKey],
+            });
+        }
+
+        // Create the table and update general info
+        self.repository.create_table(&table_in).await;
+        set_tables_general_info(self.repository.clone(), self.tables_general_info.clone()).await;
+    }
+
+    pub async fn delete_table(&self, table_name: String) {
+        if let Err(message) = self.session.authorize(BPermission::AlterSchema) {
+            self.write_to_console(message).await;
+            return;
+        }
+        self.repository.delete_table(&table_name).await;
+        self.table_views.delete_table_views(&table_name).await;
+        self.repository
+            .delete_access_rules(&table_name)
+            .await
+            .unwrap();
+        self.repository
+            .delete_column_layout(&table_name)
+            .await
+            .unwrap();
+        self.repository
+            .delete_computed_columns(&table_name)
+            .await
+            .unwrap();
+        let table_info = self.table_info.clone();
+        let table_data = self.table_data.clone();
+        let table_views = self.table_views.clone();
+        task::spawn_blocking(move || {
+            let reset_table_info =
+                if let Some(current_table_name) = table_info.table_name.blocking_lock().as_ref() {
+                    *current_table_name == table_name
+                } else {
+                    false
+                };
+            if reset_table_info {
+                table_info.reset_table_info();
+            }
+
+            let reset_table_data = if let Some(table_inserted_data) =
+                table_data.table_inserted_data.blocking_lock().as_ref()
+            {
+                table_inserted_data.table_name == table_name
+            } else {
+                false
+            };
+            if reset_table_data {
+                table_data.reset_table_data();
+                table_views.reset_table_views();
+            }
+        })
+        .await
+        .unwrap();
+        set_tables_general_info(self.repository.clone(), self.tables_general_info.clone()).await;
+    }
+
+    pub async fn duplicate_table(
+        &self,
+        source_table_name: String,
+        table_name: String,
+        with_data: bool,
+    ) -> Result<(), String> {
+        self.session.authorize(BPermission::AlterSchema)?;
+        if table_name.is_empty() {
+            return Err(String::from("The copy needs a table name"));
+        }
+        if with_data {
+            // the copy gets no access rules, hidden data must not end up in it
+            let column_names = self
+                .tables_general_info
+                .lock()
+                .await
+                .iter()
+                .find(|table_general_info| table_general_info.table_name == source_table_name)
+                .map(|table_general_info| table_general_info.column_names.clone())
+                .unwrap_or_default();
+            if self.session.table_access_level(&source_table_name) == BAccessLevel::Hidden
+                || column_names.iter().any(|column_name| {
+                    self.session
+                        .column_access_level(&source_table_name, column_name)
+                        == BAccessLevel::Hidden
+                })
+            {
+                return Err(format!(
+                    "{} cannot copy the data of \"{}\", some of it is hidden",
+                    self.session.current_user.user_name, source_table_name
+                ));
+            }
+        }
+
+        if let Err(error) = self
+            .repository
+            .duplicate_table(&source_table_name, &table_name, with_data)
+            .await
+        {
+            let message = format!(
+                "Duplicating \"{}\" as \"{}\" failed: {}",
+                source_table_name, table_name, error
+            );
+            self.write_to_console(message.clone()).await;
+            return Err(message);
+        }
+        set_tables_general_info(self.repository.clone(), self.tables_general_info.clone()).await;
+        Ok(())
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::component::{BRole, BRowInsertData, BTableDataChangeEvents};
+    use crate::tables::test_utils::{
+        create_btable_general, create_repository_table_and_console, create_session,
+        default_table_in, sort_by_table_name,
+    };
+    use sqlx::PgPool;
+
+    async fn tables_component(pool: PgPool, table_in: &BTableIn, role: BRole) -> Tables {
+        let (repository_result, console_result) =
+            create_repository_table_and_console(pool, table_in).await;
+        Tables::new(repository_result, console_result, create_session(role))
+    }
+
+    async fn initialized_tables_component(pool: PgPool, table_in: &BTableIn) -> Tables {
+        let tables = tables_component(pool, table_in, BRole::Admin).await;
+        tables.initialize_component().await;
+        tables
+    }
+
+    #[sqlx::test]
+    async fn test_initialize_tables_component(pool: PgPool) {
+        let table_in = default_table_in();
+        let tables = initialized_tables_component(pool, &table_in).await;
+
+        let mut expected_tables_general_info = vec![create_btable_general(&table_in)];
+        let mut tables_general_info = tables.tables_general_info.lock().await.clone();
+
+        // Sort both vectors
+        sort_by_table_name(&mut expected_tables_general_info);
+        sort_by_table_name(&mut tables_general_info);
+
+        assert_eq!(tables_general_info, expected_tables_general_info);
+    }
+
+    #[sqlx::test]
+    async fn test_add_table(pool: PgPool) {
+        let initial_table_in = default_table_in();
+        let tables = initialized_tables_component(pool, &initial_table_in).await;
+
+        let new_table_in = BTableIn {
+            table_name: String::from("products"),
+            columns: vec![BColumn {
+                name: String::from("product_name"),
+                datatype: BDataType::TEXT,
+                constraints: vec![],
+            }],
+        };
+
+        // Add a new table
+        tables.add_table(new_table_in.clone()).await;
+
+        // Prepare expected results
+        let mut new_table = new_table_in;
+        new_table.columns.push(BColumn {
+            name: String::from("id"),
+            datatype: BDataType::INTEGER,
+            constraints: vec![BConstraint::PrimaryKey],
+        });
+        let mut expected_tables_general_info = vec![
+            create_btable_general(&initial_table_in),
+            create_btable_general(&new_table),
+        ];
+
+        let mut tables_general_info = tables.tables_general_info.lock().await.clone();
+
+        // Sort both vectors
+        sort_by_table_name(&mut expected_tables_general_info);
+        sort_by_table_name(&mut tables_general_info);
+
+        assert_eq!(tables_general_info, expected_tables_general_info);
+    }
+
+    #[sqlx::test]
+    async fn test_delete_table(pool: PgPool) {
+        let table_in = default_table_in();
+        let tables = initialized_tables_component(pool, &table_in).await;
+
+        // Delete the initial table
+        tables.delete_table(table_in.table_name.clone()).await;
+
+        // Verify no tables exist in `tables_general_info`
+        let tables_general_info = tables.tables_general_info.lock().await;
+        assert!(tables_general_info.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_duplicate_table(pool: PgPool) {
+        let table_in = default_table_in();
+        let tables = initialized_tables_component(pool.clone(), &table_in).await;
+        tables
+            .add_table(BTableIn {
+                table_name: String::from("orders"),
+                columns: vec![
+                    BColumn {
+                        name: String::from("id"),
+                        datatype: BDataType::SERIAL,
+                        constraints: vec![BConstraint::PrimaryKey],
+                    },
+                    BColumn {
+                        name: String::from("user_id"),
+                        datatype: BDataType::INTEGER,
+                        constraints: vec![BConstraint::ForeignKey(
+                            String::from("users"),
+                            String::from("id"),
+                        )],
+                    },
+                ],
+            })
+            .await;
+        sqlx::query("INSERT INTO users VALUES (1, 'Alice')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO orders (user_id) VALUES (1), (1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        // a relation already has the name the sequence of the copy would get
+        sqlx::query("CREATE SEQUENCE orders_copy_id_seq")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        tables
+            .duplicate_table(String::from("orders"), String::from("orders_copy"), true)
+            .await
+            .unwrap();
+        tables
+            .duplicate_table(String::from("users"), String::from("users_copy"), false)
+            .await
+            .unwrap();
+
+        // the copy has its own sequence, continuing after the copied rows
+        sqlx::query("INSERT INTO orders_copy (user_id) VALUES (1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let order_ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM orders_copy ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(order_ids, vec![1, 2, 3]);
+        let next_order_id: i32 =
+            sqlx::query_scalar("INSERT INTO orders (user_id) VALUES (1) RETURNING id")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(next_order_id, 3);
+
+        // the primary and foreign keys are copied
+        assert!(sqlx::query("INSERT INTO orders_copy VALUES (1, 1)")
+            .execute(&pool)
+            .await
+            .is_err());
+        assert!(sqlx::query("INSERT INTO orders_copy (user_id) VALUES (2)")
+            .execute(&pool)
+            .await
+            .is_err());
+
+        let user_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users_copy")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(user_count, 0);
+        assert_eq!(tables.tables_general_info.lock().await.len(), 4);
+        assert!(tables
+            .duplicate_table(String::from("users"), String::from("orders"), false)
+            .await
+            .is_err());
+    }
+
+    #[sqlx::test]
+    async fn test_duplicate_table_with_generated_and_identity_columns(pool: PgPool) {
+        let table_in = default_table_in();
+        let tables = initialized_tables_component(pool.clone(), &table_in).await;
+        sqlx::query(
+            "CREATE TABLE notes (
+                id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+                title TEXT,
+                title_length INTEGER GENERATED ALWAYS AS (length(title)) STORED
+            )",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO notes (title) VALUES ('a'), ('abc')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        tables
+            .duplicate_table(String::from("notes"), String::from("notes_copy"), true)
+            .await
+            .unwrap();
+
+        sqlx::query("INSERT INTO notes_copy (title) VALUES ('ab')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let notes: Vec<(i32, String, i32)> =
+            sqlx::query_as("SELECT id, title, title_length FROM notes_copy ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            notes,
+            vec![
+                (1, String::from("a"), 1),
+                (2, String::from("abc"), 3),
+                (3, String::from("ab"), 2),
+            ]
+        );
+        // the copy is still generated, not a plain column holding copied values
+        assert!(
+            sqlx::query("INSERT INTO notes_copy (title, title_length) VALUES ('a', 5)")
+                .execute(&pool)
+                .await
+                .is_err()
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_viewer_cannot_change_tables(pool: PgPool) {
+        let table_in = default_table_in();
+        let tables = tables_component(pool, &table_in, BRole::Viewer).await;
+        tables.initialize_component().await;
+
+        tables.delete_table(table_in.table_name.clone()).await;
+
+        assert_eq!(
+            tables.tables_general_info.lock().await.clone(),
+            vec![create_btable_general(&table_in)]
+        );
+    }
+
+    #[sqlx::test]
+    async fn test_table_change_notifications(pool: PgPool) {
+        let table_in = default_table_in();
+        let (repository, console) = create_repository_table_and_console(pool, &table_in).await;
+        let tables = Tables::new(repository.clone(), console, create_session(BRole::Admin));
+        tables.initialize_component().await;
+        let mut table_change_listener = repository.listen_for_table_changes().await.unwrap();
+
+        repository
+            .update_table_data(
+                &table_in.table_name,
+                &vec![BTableDataChangeEvents::InsertRow(BRowInsertData {
+                    column_names: vec![String::from("id"), String::from("name")],
+                    data_types: vec![BDataType::INTEGER, BDataType::TEXT],
+                    values: vec![String::from("1"), String::from("Alice")],
+                })],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            repository
+                .receive_table_change(&mut table_change_listener)
+                .await
+                .unwrap(),
+            BTableChange::Rows(table_in.table_name.clone())
+        );
+
+        tables
+            .add_table(BTableIn {
+                table_name: String::from("products"),
+                columns: vec![],
+            })
+            .await;
+        assert_eq!(
+            repository
+                .receive_table_change(&mut table_change_listener)
+                .await
+                .unwrap(),
+            BTableChange::Schema
+        );
+    }
+}
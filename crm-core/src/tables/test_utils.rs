@@ -1,8 +1,8 @@
-use crate::components::business_components::{
+use crate::{
     component::{
         repository_module::{BRepository, BRepositoryConsole},
-        BColumn, BConstraint, BDataType, BTableChangeEvents, BTableData, BTableGeneral, BTableIn,
-        BTableInfo, BTableInsertedData, BusinessComponent,
+        BColumn, BConstraint, BDataType, BRole, BRowInsertData, BSession, BTableDataChangeEvents,
+        BTableGeneral, BTableIn, BUser,
     },
     components::BusinessConsole,
 };
@@ -23,6 +23,14 @@ pub async fn create_repository(
     Arc::new(BRepository::new(Some(pool), database_console).await)
 }
 
+pub fn create_session(role: BRole) -> Arc<BSession> {
+    Arc::new(BSession::new(BUser {
+        id: 1,
+        user_name: format!("{}_user", role),
+        role,
+    }))
+}
+
 pub async fn create_repository_table_and_console(
     pool: PgPool,
     table_in: &BTableIn,
@@ -30,6 +38,11 @@ pub async fn create_repository_table_and_console(
     let database_console = create_database_console();
     let business_console = create_console(database_console.clone());
     let repository = create_repository(pool, database_console).await;
+    // data changes are audited, so the log table has to exist before any insert
+    repository.create_audit_log_table().await.unwrap();
+    repository.create_access_rules_table().await.unwrap();
+    repository.create_column_layouts_table().await.unwrap();
+    repository.create_computed_columns_table().await.unwrap();
     repository.create_table(table_in).await;
     (repository, business_console)
 }
@@ -77,10 +90,27 @@ pub fn sort_by_table_name(tables: &mut Vec<BTableGeneral>) {
     tables.sort_by(|a, b| a.table_name.cmp(&b.table_name));
 }
 
-pub fn sort_tables_general_info(tables: &mut Vec<BTableGeneral>) {
-    tables.sort_by(|a, b| a.table_name.cmp(&b.table_name));
-}
-
 pub fn sort_columns(columns: &mut Vec<BColumn>) {
     columns.sort_by(|a, b| a.name.cmp(&b.name));
 }
+
+// one InsertRow event per row, the values are in the column order of the table
+pub fn create_insert_row_events(
+    table_in: &BTableIn,
+    rows: Vec<Vec<&str>>,
+) -> Vec<BTableDataChangeEvents> {
+    let (column_names, data_types): (Vec<String>, Vec<BDataType>) = table_in
+        .columns
+        .iter()
+        .map(|column| (column.name.clone(), column.datatype.clone()))
+        .unzip();
+    rows.into_iter()
+        .map(|values| {
+            BTableDataChangeEvents::InsertRow(BRowInsertData {
+                column_names: column_names.clone(),
+                data_types: data_types.clone(),
+                values: values.into_iter().map(String::from).collect(),
+            })
+        })
+        .collect()
+}
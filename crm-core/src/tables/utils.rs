@@ -0,0 +1,95 @@
+use crate::component::{
+    repository_module::BRepository, BColumn, BColumnLayout, BConstraint, BForeignKeyReference,
+    BTableGeneral, BTableIn,
+};
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+
+pub async fn set_tables_general_info(
+    repository: Arc<BRepository>,
+    tables_general_info: Arc<AsyncMutex<Vec<BTableGeneral>>>,
+) {
+    let mut locked_tables_general_info = tables_general_info.lock().await;
+    let new_tables_general_info = repository.get_general_tables_info().await.unwrap();
+    let column_layouts: Vec<BColumnLayout> = repository
+        .get_column_layouts()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(BColumnLayout::to_column_layout)
+        .collect();
+    let new_tables_general_info_structured = new_tables_general_info
+        .into_iter()
+        .map(|table| {
+            let mut table_general_info = BTableGeneral::to_table(table);
+            if let Some(column_layout) = column_layouts
+                .iter()
+                .find(|column_layout| column_layout.table_name == table_general_info.table_name)
+            {
+                table_general_info.apply_column_layout(column_layout);
+            }
+            table_general_info
+        })
+        .collect();
+    *locked_tables_general_info = new_tables_general_info_structured;
+}
+
+// columns of every table whose foreign key points at the given table
+pub async fn get_foreign_key_references(
+    repository: Arc<BRepository>,
+    tables_general_info: &Vec<BTableGeneral>,
+    table_name: &str,
+) -> Result<Vec<BForeignKeyReference>, sqlx::Error> {
+    let mut foreign_key_references = vec![];
+    for table_general_info in tables_general_info {
+        let columns_info = repository
+            .get_columns_info(&table_general_info.table_name)
+            .await?;
+        for column in columns_info.into_iter().map(BColumn::to_column) {
+            for constraint in column.constraints {
+                if let BConstraint::ForeignKey(referenced_table, referenced_column) = constraint {
+                    if referenced_table == table_name {
+                        foreign_key_references.push(BForeignKeyReference {
+                            table_name: table_general_info.table_name.clone(),
+                            column_name: column.name.clone(),
+                            referenced_column,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    Ok(foreign_key_references)
+}
+
+// every table of the database, read from the database rather than tables_general_info
+pub async fn get_tables_in(repository: Arc<BRepository>) -> Result<Vec<BTableIn>, String> {
+    let mut tables = vec![];
+    for table_general_info in repository
+        .get_general_tables_info()
+        .await
+        .map_err(|error| error.to_string())?
+    {
+        tables.push(get_table_in(repository.clone(), &table_general_info.table_name).await?);
+    }
+    Ok(tables)
+}
+
+// the live definition of a table in the shape used to create it
+pub async fn get_table_in(
+    repository: Arc<BRepository>,
+    table_name: &str,
+) -> Result<BTableIn, String> {
+    let columns = repository
+        .get_columns_info(table_name)
+        .await
+        .map_err(|error| error.to_string())?
+        .into_iter()
+        .map(BColumn::try_to_column)
+        .collect::<Result<Vec<BColumn>, String>>()
+        .map_err(|message| format!("{} in table {}", message, table_name))?;
+    Ok(BTableIn {
+        table_name: table_name.to_string(),
+        columns,
+    })
+}
@@ -0,0 +1,180 @@
+use crate::authentication::hash_password;
+use crate::component::{
+    repository_module::BRepository, BPermission, BRole, BSession, BUser, BusinessComponent,
+};
+use crate::components::BusinessConsole;
+use std::sync::Arc;
+use tokio::sync::Mutex as AsyncMutex;
+use tokio::task;
+
+#[derive(Debug, Clone)]
+pub struct Users {
+    repository: Arc<BRepository>,
+    console: Arc<BusinessConsole>,
+    pub session: Arc<BSession>,
+    pub users: Arc<AsyncMutex<Vec<BUser>>>,
+}
+
+impl BusinessComponent for Users {
+    async fn initialize_component(&self) {
+        // other roles never see the account list
+        if self.session.authorize(BPermission::ManageUsers).is_ok() {
+            self.set_users().await;
+        }
+    }
+}
+
+impl Users {
+    pub fn new(
+        repository: Arc<BRepository>,
+        console: Arc<BusinessConsole>,
+        session: Arc<BSession>,
+    ) -> Self {
+        Self {
+            repository,
+            console,
+            session,
+            users: Arc::new(AsyncMutex::new(vec![])),
+        }
+    }
+
+    async fn set_users(&self) {
+        let users = self
+            .repository
+            .get_users()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(BUser::to_user)
+            .collect();
+        *self.users.lock().await = users;
+    }
+
+    pub async fn add_user(&self, user_name: String, password: String, role: BRole) {
+        if let Err(message) = self.session.authorize(BPermission::ManageUsers) {
+            self.write_to_console(message).await;
+            return;
+        }
+        if user_name.is_empty() || password.is_empty() {
+            self.write_to_console(String::from("User name and password are required"))
+                .await;
+            return;
+        }
+        let password_hash = task::spawn_blocking(move || hash_password(&password))
+            .await
+            .unwrap();
+        if let Err(error) = self
+            .repository
+            .insert_user(&user_name, &password_hash, &role)
+            .await
+        {
+            self.write_to_console(format!("Failed to add user \"{}\": {}", user_name, error))
+                .await;
+        }
+        self.set_users().await;
+    }
+
+    pub async fn set_user_role(&self, user_id: i32, role: BRole) {
+        if let Err(message) = self.session.authorize(BPermission::ManageUsers) {
+            self.write_to_console(message).await;
+            return;
+        }
+        if role != BRole::Admin && self.is_last_admin(user_id).await {
+            self.write_to_console(String::from("The last administrator cannot be demoted"))
+                .await;
+            return;
+        }
+        self.repository
+            .update_user_role(user_id, &role)
+            .await
+            .unwrap();
+        self.set_users().await;
+    }
+
+    pub async fn delete_user(&self, user_id: i32) {
+        if let Err(message) = self.session.authorize(BPermission::ManageUsers) {
+            self.write_to_console(message).await;
+            return;
+        }
+        if user_id == self.session.current_user.id {
+            self.write_to_console(String::from("You cannot delete your own account"))
+                .await;
+            return;
+        }
+        if self.is_last_admin(user_id).await {
+            self.write_to_console(String::from("The last administrator cannot be deleted"))
+                .await;
+            return;
+        }
+        self.repository.delete_user(user_id).await.unwrap();
+        self.set_users().await;
+    }
+
+    async fn is_last_admin(&self, user_id: i32) -> bool {
+        let users = self.users.lock().await;
+        let admins: Vec<&BUser> = users
+            .iter()
+            .filter(|user| user.role == BRole::Admin)
+            .collect();
+        admins.len() == 1 && admins[0].id == user_id
+    }
+
+    async fn write_to_console(&self, message: String) {
+        let console = self.console.clone();
+        task::spawn_blocking(move || {
+            console.write(message);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::test_utils::{create_console, create_database_console, create_repository};
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn test_manage_users(pool: PgPool) {
+        let database_console = create_database_console();
+        let console = create_console(database_console.clone());
+        let repository = create_repository(pool, database_console).await;
+        repository.create_users_table().await.unwrap();
+        repository
+            .insert_user("admin", &hash_password("secret"), &BRole::Admin)
+            .await
+            .unwrap();
+        let admin = BUser::to_user(repository.get_user("admin").await.unwrap().unwrap());
+
+        let users = Users::new(
+            repository.clone(),
+            console.clone(),
+            Arc::new(BSession::new(admin.clone())),
+        );
+        users.initialize_component().await;
+        users
+            .add_user(String::from("ann"), String::from("secret"), BRole::Editor)
+            .await;
+        assert_eq!(users.users.lock().await.len(), 2);
+
+        // the only administrator keeps the role
+        users.set_user_role(admin.id, BRole::Viewer).await;
+        let roles: Vec<BRole> = users
+            .users
+            .lock()
+            .await
+            .iter()
+            .map(|user| user.role.clone())
+            .collect();
+        assert!(roles.contains(&BRole::Admin));
+
+        let editor = BUser::to_user(repository.get_user("ann").await.unwrap().unwrap());
+        let editor_users = Users::new(repository, console, Arc::new(BSession::new(editor)));
+        editor_users
+            .add_user(String::from("bob"), String::from("secret"), BRole::Viewer)
+            .await;
+        users.initialize_component().await;
+        assert_eq!(users.users.lock().await.len(), 2);
+    }
+}
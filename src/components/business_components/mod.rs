@@ -1,6 +0,0 @@
-pub mod component;
-pub mod components;
-mod console;
-mod database;
-mod home;
-mod tables;
@@ -1,5 +0,0 @@
-pub mod table_data;
-pub mod table_info;
-pub mod tables;
-mod test_utils;
-mod utils;
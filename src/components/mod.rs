@@ -1,2 +1 @@
-mod business_components;
 pub mod ui_components;
@@ -0,0 +1,256 @@
+use crate::components::ui_components::{
+    access_rules::events::AccessRulesMessage,
+    component::{Event, UIComponent},
+    events::Message,
+};
+use crm_core::{
+    component::{BAccessLevel, BAccessRule, BRole},
+    components::BusinessAccessRules,
+};
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    widget::{button, container, scrollable, text, Column, PickList, Row},
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct AccessRulesUI {
+    access_rules: Arc<BusinessAccessRules>,
+    saved_access_rules: Vec<BAccessRule>,
+    access_rule_draft: BAccessRule,
+}
+
+impl UIComponent for AccessRulesUI {
+    type EventType = AccessRulesMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::SetAccessRules => {
+                self.saved_access_rules = self.access_rules.access_rules.blocking_lock().clone();
+                Task::none()
+            }
+            Self::EventType::UpdateRole(role) => {
+                self.access_rule_draft.role = role;
+                Task::none()
+            }
+            Self::EventType::UpdateTable(table_name) => {
+                self.access_rule_draft.table_name = table_name;
+                self.access_rule_draft.column_name = None;
+                Task::none()
+            }
+            Self::EventType::UpdateColumn(column_name) => {
+                self.access_rule_draft.column_name = Some(column_name);
+                Task::none()
+            }
+            Self::EventType::ClearColumn => {
+                self.access_rule_draft.column_name = None;
+                Task::none()
+            }
+            Self::EventType::UpdateAccessLevel(access_level) => {
+                self.access_rule_draft.access_level = access_level;
+                Task::none()
+            }
+            Self::EventType::SaveAccessRule => {
+                if self.access_rule_draft.table_name.is_empty() {
+                    return Task::none();
+                }
+                let access_rules = self.access_rules.clone();
+                let access_rule = self.access_rule_draft.clone();
+                Task::perform(
+                    async move {
+                        access_rules.save_access_rule(access_rule).await;
+                    },
+                    |_| Self::EventType::SetAccessRules.message(),
+                )
+            }
+            Self::EventType::DeleteAccessRule(access_rule_id) => {
+                let access_rules = self.access_rules.clone();
+                Task::perform(
+                    async move {
+                        access_rules.delete_access_rule(access_rule_id).await;
+                    },
+                    |_| Self::EventType::SetAccessRules.message(),
+                )
+            }
+        }
+    }
+}
+
+impl AccessRulesUI {
+    pub fn new(access_rules: Arc<BusinessAccessRules>) -> Self {
+        Self {
+            access_rules,
+            saved_access_rules: vec![],
+            access_rule_draft: BAccessRule::default(),
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let access_rules_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(
+                text("Access Rules")
+                    .size(32)
+                    .color(Color::from_rgb(0.9, 0.9, 1.0)),
+            )
+            .push(
+                text("Rules narrow what a role may see or change, a column rule takes precedence over its table rule")
+                    .color(Color::from_rgb(0.6, 0.6, 0.7)),
+            )
+            .push(self.access_rule_editor())
+            .push(scrollable(self.access_rules_section()).height(Length::Fill));
+
+        container(access_rules_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn access_rule_editor<'a>(&'a self) -> Element<'a, Message> {
+        let tables_general_info = self.access_rules.tables_general_info.blocking_lock();
+        let table_names: Vec<String> = tables_general_info
+            .iter()
+            .map(|table| table.table_name.clone())
+            .collect();
+        let column_names: Vec<String> = tables_general_info
+            .iter()
+            .find(|table| table.table_name == self.access_rule_draft.table_name)
+            .map(|table| table.column_names.clone())
+            .unwrap_or_default();
+        let selected_table_name = Some(self.access_rule_draft.table_name.clone())
+            .filter(|table_name| !table_name.is_empty());
+
+        Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(PickList::new(
+                vec![BRole::Admin, BRole::Editor, BRole::Viewer],
+                Some(self.access_rule_draft.role.clone()),
+                |role| AccessRulesMessage::UpdateRole(role).message(),
+            ))
+            .push(
+                PickList::new(table_names, selected_table_name, |table_name| {
+                    AccessRulesMessage::UpdateTable(table_name).message()
+                })
+                .placeholder("Table"),
+            )
+            .push(
+                PickList::new(
+                    column_names,
+                    self.access_rule_draft.column_name.clone(),
+                    |column_name| AccessRulesMessage::UpdateColumn(column_name).message(),
+                )
+                .placeholder("Whole table"),
+            )
+            .push(
+                button("Whole Table")
+                    .style(|_, _| button_style())
+                    .on_press(AccessRulesMessage::ClearColumn.message()),
+            )
+            .push(PickList::new(
+                vec![
+                    BAccessLevel::Hidden,
+                    BAccessLevel::Read,
+                    BAccessLevel::Write,
+                ],
+                Some(self.access_rule_draft.access_level.clone()),
+                |access_level| AccessRulesMessage::UpdateAccessLevel(access_level).message(),
+            ))
+            .push(
+                button("Save Rule")
+                    .style(|_, _| button_style())
+                    .on_press(AccessRulesMessage::SaveAccessRule.message()),
+            )
+            .into()
+    }
+
+    fn access_rules_section<'a>(&'a self) -> Element<'a, Message> {
+        if self.saved_access_rules.is_empty() {
+            return text("No access rules, every role uses its default permissions")
+                .color(Color::from_rgb(0.6, 0.6, 0.7))
+                .into();
+        }
+        self.saved_access_rules
+            .iter()
+            .fold(
+                Column::new().spacing(10),
+                |access_rules_column, access_rule| {
+                    let target = match &access_rule.column_name {
+                        Some(column_name) => format!("{}.{}", access_rule.table_name, column_name),
+                        None => access_rule.table_name.clone(),
+                    };
+                    let mut access_rule_row = Row::new()
+                        .spacing(15)
+                        .align_y(Vertical::Center)
+                        .push(text(access_rule.role.to_string()).width(100))
+                        .push(text(target).width(250))
+                        .push(text(access_rule.access_level.to_string()).width(100));
+                    if let Some(access_rule_id) = access_rule.id {
+                        access_rule_row = access_rule_row.push(
+                            button("Delete").style(|_, _| button_style()).on_press(
+                                AccessRulesMessage::DeleteAccessRule(access_rule_id).message(),
+                            ),
+                        );
+                    }
+                    access_rules_column.push(
+                        container(access_rule_row)
+                            .padding(10)
+                            .width(Length::Fill)
+                            .style(|_| access_rule_card_style()),
+                    )
+                },
+            )
+            .into()
+    }
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn access_rule_card_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.15, 0.15, 0.2))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow::default(),
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
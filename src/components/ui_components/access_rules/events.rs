@@ -0,0 +1,20 @@
+use crate::components::ui_components::{component::Event, events::Message};
+use crm_core::component::{BAccessLevel, BRole};
+
+#[derive(Debug, Clone)]
+pub enum AccessRulesMessage {
+    SetAccessRules,
+    UpdateRole(BRole),
+    UpdateTable(String),
+    UpdateColumn(String),
+    ClearColumn,
+    UpdateAccessLevel(BAccessLevel),
+    SaveAccessRule,
+    DeleteAccessRule(i32),
+}
+
+impl Event for AccessRulesMessage {
+    fn message(self) -> Message {
+        Message::AccessRules(self)
+    }
+}
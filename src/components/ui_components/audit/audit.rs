@@ -0,0 +1,332 @@
+use crate::components::ui_components::{
+    audit::events::AuditMessage,
+    component::{Event, UIComponent},
+    events::Message,
+};
+use crm_core::{
+    component::{BAuditEntry, BAuditFilter, BAuditOperation, BusinessComponent},
+    components::BusinessAudit,
+};
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    widget::{button, container, scrollable, text, text_input, Column, PickList, Row},
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct AuditUI {
+    audit: Arc<BusinessAudit>,
+    audit_entries: Vec<BAuditEntry>,
+    audit_filter: BAuditFilter,
+}
+
+impl UIComponent for AuditUI {
+    type EventType = AuditMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::InitializeComponent => {
+                let audit = self.audit.clone();
+                Task::perform(
+                    async move {
+                        audit.initialize_component().await;
+                    },
+                    |_| Self::EventType::ComponentInitialized.message(),
+                )
+            }
+            Self::EventType::ComponentInitialized => {
+                Task::done(Self::EventType::SetAuditEntries.message())
+            }
+            Self::EventType::SetAuditEntries => {
+                self.audit_entries = self.audit.audit_entries.blocking_lock().clone();
+                Task::none()
+            }
+            Self::EventType::RefreshAuditEntries => {
+                let audit = self.audit.clone();
+                Task::perform(
+                    async move {
+                        audit.set_audit_entries().await;
+                    },
+                    |_| Self::EventType::SetAuditEntries.message(),
+                )
+            }
+            Self::EventType::UpdateTableFilter(table_name) => {
+                self.audit_filter.table_name = Some(table_name);
+                Task::done(Self::EventType::ApplyAuditFilter.message())
+            }
+            Self::EventType::ClearTableFilter => {
+                self.audit_filter.table_name = None;
+                Task::done(Self::EventType::ApplyAuditFilter.message())
+            }
+            Self::EventType::UpdatePrimaryKeyFilter(primary_key_value) => {
+                self.audit_filter.primary_key_value =
+                    Some(primary_key_value).filter(|value| !value.is_empty());
+                Task::none()
+            }
+            Self::EventType::UpdateUserFilter(user_name) => {
+                self.audit_filter.user_name = Some(user_name).filter(|value| !value.is_empty());
+                Task::none()
+            }
+            Self::EventType::ApplyAuditFilter => {
+                let audit = self.audit.clone();
+                let audit_filter = self.audit_filter.clone();
+                Task::perform(
+                    async move {
+                        audit.set_audit_filter(audit_filter).await;
+                    },
+                    |_| Self::EventType::SetAuditEntries.message(),
+                )
+            }
+            Self::EventType::RevertAuditEntry(audit_entry_id) => {
+                let audit = self.audit.clone();
+                Task::perform(
+                    async move {
+                        audit.revert_audit_entry(audit_entry_id).await;
+                    },
+                    |_| Self::EventType::SetAuditEntries.message(),
+                )
+            }
+        }
+    }
+}
+
+impl AuditUI {
+    pub fn new(audit: Arc<BusinessAudit>) -> Self {
+        Self {
+            audit,
+            audit_entries: vec![],
+            audit_filter: BAuditFilter::default(),
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let audit_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(
+                text("Audit Log")
+                    .size(32)
+                    .color(Color::from_rgb(0.9, 0.9, 1.0)),
+            )
+            .push(self.audit_filter_row())
+            .push(scrollable(self.audit_entries_section()).height(Length::Fill));
+
+        container(audit_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn audit_filter_row<'a>(&'a self) -> Element<'a, Message> {
+        let table_names: Vec<String> = self
+            .audit
+            .tables_general_info
+            .blocking_lock()
+            .iter()
+            .map(|table| table.table_name.clone())
+            .collect();
+
+        Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(
+                PickList::new(
+                    table_names,
+                    self.audit_filter.table_name.clone(),
+                    |table_name| AuditMessage::UpdateTableFilter(table_name).message(),
+                )
+                .placeholder("All tables"),
+            )
+            .push(
+                button("All Tables")
+                    .style(|_, _| button_style())
+                    .on_press(AuditMessage::ClearTableFilter.message()),
+            )
+            .push(
+                text_input(
+                    "Row key",
+                    self.audit_filter
+                        .primary_key_value
+                        .as_deref()
+                        .unwrap_or_default(),
+                )
+                .on_input(|value| AuditMessage::UpdatePrimaryKeyFilter(value).message())
+                .on_submit(AuditMessage::ApplyAuditFilter.message())
+                .width(150)
+                .padding(5)
+                .style(|_, _| text_input_style()),
+            )
+            .push(
+                text_input(
+                    "User",
+                    self.audit_filter.user_name.as_deref().unwrap_or_default(),
+                )
+                .on_input(|value| AuditMessage::UpdateUserFilter(value).message())
+                .on_submit(AuditMessage::ApplyAuditFilter.message())
+                .width(150)
+                .padding(5)
+                .style(|_, _| text_input_style()),
+            )
+            .push(
+                button("Filter")
+                    .style(|_, _| button_style())
+                    .on_press(AuditMessage::ApplyAuditFilter.message()),
+            )
+            .push(
+                button("Refresh")
+                    .style(|_, _| button_style())
+                    .on_press(AuditMessage::RefreshAuditEntries.message()),
+            )
+            .into()
+    }
+
+    fn audit_entries_section<'a>(&'a self) -> Element<'a, Message> {
+        if self.audit_entries.is_empty() {
+            return text("No recorded changes")
+                .color(Color::from_rgb(0.6, 0.6, 0.7))
+                .into();
+        }
+        self.audit_entries
+            .iter()
+            .fold(Column::new().spacing(10), |entries_column, audit_entry| {
+                entries_column.push(self.audit_entry_card(audit_entry))
+            })
+            .into()
+    }
+
+    fn audit_entry_card<'a>(&'a self, audit_entry: &'a BAuditEntry) -> Element<'a, Message> {
+        let primary_key = audit_entry
+            .primary_key_column_names
+            .iter()
+            .zip(&audit_entry.primary_key_values)
+            .map(|(column_name, value)| {
+                format!("{} = {}", column_name, value.as_deref().unwrap_or("NULL"))
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        let header = Row::new()
+            .spacing(15)
+            .align_y(Vertical::Center)
+            .push(
+                text(audit_entry.operation.to_string())
+                    .color(operation_color(&audit_entry.operation)),
+            )
+            .push(text(format!(
+                "{} [{}]",
+                audit_entry.table_name, primary_key
+            )))
+            .push(text(audit_entry.user_name.clone()).color(Color::from_rgb(0.6, 0.6, 0.7)))
+            .push(text(audit_entry.changed_at.clone()).color(Color::from_rgb(0.6, 0.6, 0.7)))
+            .push(
+                button("Revert")
+                    .style(|_, _| button_style())
+                    .on_press(AuditMessage::RevertAuditEntry(audit_entry.id).message()),
+            );
+
+        let mut card = Column::new().spacing(5).push(header);
+        for (index, column_name) in audit_entry.column_names.iter().enumerate() {
+            let old_value = audit_entry
+                .old_values
+                .as_ref()
+                .map(|old_values| old_values[index].clone());
+            let new_value = audit_entry
+                .new_values
+                .as_ref()
+                .map(|new_values| new_values[index].clone());
+            // updates only list the columns that changed
+            if audit_entry.operation == BAuditOperation::Update && old_value == new_value {
+                continue;
+            }
+            let display = |value: Option<Option<String>>| match value {
+                Some(Some(value)) => value,
+                Some(None) => String::from("NULL"),
+                None => String::from("—"),
+            };
+            card = card.push(text(format!(
+                "{}: {} → {}",
+                column_name,
+                display(old_value),
+                display(new_value)
+            )));
+        }
+
+        container(card)
+            .padding(10)
+            .width(Length::Fill)
+            .style(|_| audit_entry_card_style())
+            .into()
+    }
+}
+
+fn operation_color(operation: &BAuditOperation) -> Color {
+    match operation {
+        BAuditOperation::Insert => Color::from_rgb(0.3, 0.8, 0.4),
+        BAuditOperation::Update => Color::from_rgb(1.0, 0.8, 0.2),
+        BAuditOperation::Delete => Color::from_rgb(1.0, 0.4, 0.4),
+    }
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn audit_entry_card_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.15, 0.15, 0.2))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow::default(),
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn text_input_style() -> text_input::Style {
+    text_input::Style {
+        background: Background::Color(Color::from_rgb(0.15, 0.15, 0.2)),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 2.0,
+            radius: Radius::from(8.0),
+        },
+        icon: Color::from_rgb(0.9, 0.9, 1.0),
+        placeholder: Color::from_rgb(0.6, 0.6, 0.7),
+        value: Color::from_rgb(0.9, 0.9, 1.0),
+        selection: Color::from_rgba(0.0, 0.7, 1.0, 0.5),
+    }
+}
@@ -0,0 +1,21 @@
+use crate::components::ui_components::{component::Event, events::Message};
+
+#[derive(Debug, Clone)]
+pub enum AuditMessage {
+    InitializeComponent,
+    ComponentInitialized,
+    SetAuditEntries,
+    RefreshAuditEntries,
+    UpdateTableFilter(String),
+    ClearTableFilter,
+    UpdatePrimaryKeyFilter(String),
+    UpdateUserFilter(String),
+    ApplyAuditFilter,
+    RevertAuditEntry(i32),
+}
+
+impl Event for AuditMessage {
+    fn message(self) -> Message {
+        Message::Audit(self)
+    }
+}
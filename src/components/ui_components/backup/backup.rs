@@ -0,0 +1,230 @@
+use crate::components::ui_components::{
+    backup::events::BackupMessage,
+    component::{Event, UIComponent},
+    events::Message,
+};
+use crm_core::{component::BBackupProgress, components::BusinessBackup};
+use futures::channel::mpsc;
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    widget::{button, container, progress_bar, text, text_input, Column, Row},
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+const DEFAULT_BACKUP_PATH: &str = "crm_backup.sql.gz";
+
+#[derive(Debug, Clone)]
+pub struct BackupUI {
+    backup: Arc<BusinessBackup>,
+    path: String,
+    is_running: bool,
+    progress: Option<BBackupProgress>,
+    status: Option<Result<String, String>>,
+}
+
+impl UIComponent for BackupUI {
+    type EventType = BackupMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::UpdatePath(path) => {
+                self.path = path;
+                Task::none()
+            }
+            Self::EventType::CreateBackup => {
+                self.start();
+                let backup = self.backup.clone();
+                let path = self.path.clone();
+                let (sender, receiver) = mpsc::unbounded();
+                Task::batch([
+                    Task::run(receiver, |progress| {
+                        Self::EventType::UpdateProgress(progress).message()
+                    }),
+                    Task::perform(
+                        async move { backup.create_backup(path, sender).await },
+                        |result| Self::EventType::BackupFinished(result).message(),
+                    ),
+                ])
+            }
+            Self::EventType::RestoreBackup => {
+                self.start();
+                let backup = self.backup.clone();
+                let path = self.path.clone();
+                let (sender, receiver) = mpsc::unbounded();
+                Task::batch([
+                    Task::run(receiver, |progress| {
+                        Self::EventType::UpdateProgress(progress).message()
+                    }),
+                    Task::perform(
+                        async move { backup.restore_backup(path, sender).await },
+                        |result| Self::EventType::RestoreFinished(result).message(),
+                    ),
+                ])
+            }
+            Self::EventType::UpdateProgress(progress) => {
+                if self.is_running {
+                    self.progress = Some(progress);
+                }
+                Task::none()
+            }
+            Self::EventType::BackupFinished(result) => {
+                self.finish(result.map(|_| format!("Backup written to \"{}\"", self.path)));
+                Task::none()
+            }
+            Self::EventType::RestoreFinished(result) => {
+                self.finish(result.map(|_| format!("Backup \"{}\" restored", self.path)));
+                Task::none()
+            }
+        }
+    }
+}
+
+impl BackupUI {
+    pub fn new(backup: Arc<BusinessBackup>) -> Self {
+        Self {
+            backup,
+            path: String::from(DEFAULT_BACKUP_PATH),
+            is_running: false,
+            progress: None,
+            status: None,
+        }
+    }
+
+    fn start(&mut self) {
+        self.is_running = true;
+        self.progress = None;
+        self.status = None;
+    }
+
+    fn finish(&mut self, status: Result<String, String>) {
+        self.is_running = false;
+        self.progress = None;
+        self.status = Some(status);
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let mut backup_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(
+                text("Backup")
+                    .size(32)
+                    .color(Color::from_rgb(0.9, 0.9, 1.0)),
+            )
+            .push(
+                text("A restore recreates the tables of the backup, they must not exist yet")
+                    .color(Color::from_rgb(0.6, 0.6, 0.7)),
+            )
+            .push(self.path_row());
+
+        if let Some(progress) = &self.progress {
+            backup_column = backup_column
+                .push(text(format!(
+                    "{} ({}/{})",
+                    progress.table_name,
+                    progress.completed_tables + 1,
+                    progress.total_tables
+                )))
+                .push(
+                    progress_bar(
+                        0.0..=progress.total_tables as f32,
+                        progress.completed_tables as f32,
+                    )
+                    .width(400)
+                    .height(10),
+                );
+        }
+
+        if let Some(status) = &self.status {
+            backup_column = backup_column.push(match status {
+                Ok(message) => text(message.clone()).color(Color::from_rgb(0.3, 0.8, 0.4)),
+                Err(error) => text(error.clone()).color(Color::from_rgb(1.0, 0.4, 0.4)),
+            });
+        }
+
+        container(backup_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn path_row<'a>(&'a self) -> Element<'a, Message> {
+        Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(
+                text_input("Path of the backup archive (.sql.gz)", &self.path)
+                    .on_input(|value| BackupMessage::UpdatePath(value).message())
+                    .width(400)
+                    .padding(5)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(
+                button("Create Backup")
+                    .style(|_, _| button_style())
+                    .on_press_maybe(self.path_message(BackupMessage::CreateBackup)),
+            )
+            .push(
+                button("Restore Backup")
+                    .style(|_, _| button_style())
+                    .on_press_maybe(self.path_message(BackupMessage::RestoreBackup)),
+            )
+            .into()
+    }
+
+    fn path_message(&self, message: BackupMessage) -> Option<Message> {
+        (!self.is_running && !self.path.is_empty()).then(|| message.message())
+    }
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn text_input_style() -> text_input::Style {
+    text_input::Style {
+        background: Background::Color(Color::from_rgb(0.15, 0.15, 0.2)),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 2.0,
+            radius: Radius::from(8.0),
+        },
+        icon: Color::from_rgb(0.9, 0.9, 1.0),
+        placeholder: Color::from_rgb(0.6, 0.6, 0.7),
+        value: Color::from_rgb(0.9, 0.9, 1.0),
+        selection: Color::from_rgba(0.0, 0.7, 1.0, 0.5),
+    }
+}
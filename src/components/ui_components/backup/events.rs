@@ -0,0 +1,18 @@
+use crate::components::ui_components::{component::Event, events::Message};
+use crm_core::component::BBackupProgress;
+
+#[derive(Debug, Clone)]
+pub enum BackupMessage {
+    UpdatePath(String),
+    CreateBackup,
+    RestoreBackup,
+    UpdateProgress(BBackupProgress),
+    BackupFinished(Result<(), String>),
+    RestoreFinished(Result<(), String>),
+}
+
+impl Event for BackupMessage {
+    fn message(self) -> Message {
+        Message::Backup(self)
+    }
+}
@@ -1,21 +1,35 @@
-use crate::components::business_components::{
-    component::BusinessComponent, components::BusinessComponents,
+use crate::components::ui_components::{
+    access_rules::{access_rules::AccessRulesUI, events::AccessRulesMessage},
+    audit::{audit::AuditUI, events::AuditMessage},
+    backup::backup::BackupUI,
+    console::console::ConsoleUI,
+    dashboard::{dashboard::DashboardUI, events::DashboardMessage},
+    dedupe::dedupe::DedupeUI,
+    home::{events::HomeMessage, home::HomeUI},
+    schema_comparison::schema_comparison::SchemaComparisonUI,
+    schema_diagram::{events::SchemaDiagramMessage, schema_diagram::SchemaDiagramUI},
+    schema_file::schema_file::SchemaFileUI,
+    search::search::SearchUI,
+    tables::{events::TablesMessage, tables::TablesUI},
+    users::{events::UsersMessage, users::UsersUI},
 };
 use crate::components::ui_components::{
     component::{Event, UIComponent},
     events::Message,
 };
-use crate::components::ui_components::{
-    console::console::ConsoleUI,
-    home::{events::HomeMessage, home::HomeUI},
-    tables::{events::TablesMessage, tables::TablesUI},
+use crm_core::{
+    component::BPermission,
+    components::{BusinessAuthentication, BusinessComponents, BusinessSession},
 };
 use iced::Task;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub enum ComponentsMessage {
     InitializeComponents(UIComponents),
     ShowOrRemoveConsole,
+    SwitchComponent(CurrentComponent),
+    LogOut,
 }
 
 impl Event for ComponentsMessage {
@@ -24,9 +38,19 @@ impl Event for ComponentsMessage {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CurrentComponent {
     Home,
+    Dashboard,
+    SchemaDiagram,
+    SchemaFile,
+    SchemaComparison,
+    Search,
+    Dedupe,
+    Audit,
+    Users,
+    AccessRules,
+    Backup,
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +58,17 @@ pub struct UIComponents {
     pub home_ui: HomeUI,
     pub tables_ui: TablesUI,
     pub console_ui: ConsoleUI,
+    pub dashboard_ui: DashboardUI,
+    pub schema_diagram_ui: SchemaDiagramUI,
+    pub schema_file_ui: SchemaFileUI,
+    pub schema_comparison_ui: SchemaComparisonUI,
+    pub backup_ui: BackupUI,
+    pub search_ui: SearchUI,
+    pub dedupe_ui: DedupeUI,
+    pub audit_ui: AuditUI,
+    pub users_ui: UsersUI,
+    pub access_rules_ui: AccessRulesUI,
+    pub session: Arc<BusinessSession>,
     pub current_component: CurrentComponent,
     pub show_console: bool,
 }
@@ -47,26 +82,57 @@ impl UIComponent for UIComponents {
                 self.show_console = !self.show_console;
                 Task::none()
             }
+            Self::EventType::SwitchComponent(current_component) => {
+                self.current_component = current_component;
+                Task::none()
+            }
             _ => Task::none(),
         }
     }
 }
 
 impl UIComponents {
-    pub async fn new() -> Self {
-        /* creates repositories */
-        let business_components = BusinessComponents::new().await;
-        Self {
+    pub async fn new(authentication: Arc<BusinessAuthentication>) -> Result<Self, String> {
+        let business_components = BusinessComponents::new(authentication).await?;
+        Ok(Self {
             home_ui: HomeUI::new(business_components.home),
             tables_ui: TablesUI::new(business_components.tables),
             console_ui: ConsoleUI::new(business_components.console.clone()),
+            dashboard_ui: DashboardUI::new(business_components.dashboard),
+            schema_diagram_ui: SchemaDiagramUI::new(business_components.schema_diagram),
+            schema_file_ui: SchemaFileUI::new(business_components.schema_file),
+            schema_comparison_ui: SchemaComparisonUI::new(business_components.schema_comparison),
+            search_ui: SearchUI::new(business_components.search),
+            dedupe_ui: DedupeUI::new(business_components.dedupe),
+            audit_ui: AuditUI::new(business_components.audit),
+            users_ui: UsersUI::new(business_components.users),
+            access_rules_ui: AccessRulesUI::new(business_components.access_rules),
+            backup_ui: BackupUI::new(business_components.backup),
+            session: business_components.session,
             current_component: CurrentComponent::Home,
             show_console: false,
-        }
+        })
     }
 
     pub fn initialize_startup_components_message() -> Task<Message> {
-        Task::done(HomeMessage::InitializeComponent.message())
+        // the audit log has to exist before the first data change is written
+        Task::done(AuditMessage::InitializeComponent.message())
+            .chain(Task::done(HomeMessage::InitializeComponent.message()))
             .chain(Task::done(TablesMessage::InitializeComponent.message()))
+            .chain(Task::done(DashboardMessage::InitializeComponent.message()))
+            .chain(Task::done(
+                SchemaDiagramMessage::InitializeComponent.message(),
+            ))
+            .chain(Task::done(UsersMessage::InitializeComponent.message()))
+            // the rules themselves are loaded while the components are built
+            .chain(Task::done(AccessRulesMessage::SetAccessRules.message()))
+    }
+
+    pub fn can_manage_users(&self) -> bool {
+        self.session.authorize(BPermission::ManageUsers).is_ok()
+    }
+
+    pub fn can_manage_backups(&self) -> bool {
+        self.session.authorize(BPermission::ManageBackups).is_ok()
     }
 }
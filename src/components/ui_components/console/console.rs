@@ -1,19 +1,13 @@
-use crate::components::business_components::components::BusinessConsole;
 use crate::components::ui_components::component::{Event, UIComponent};
 use crate::components::ui_components::console::events::ConsoleMessage;
 use crate::components::ui_components::events::Message;
+use crm_core::components::BusinessConsole;
 use iced::{
-    border,
     border::Radius,
-    font::Font,
-    widget::{
-        button, column, container, row, scrollable, text, text_input, Column, Container, PickList,
-        Row, Scrollable, Text, TextInput,
-    },
-    Alignment, Background, Border, Color, Element, Length, Shadow, Task, Theme, Vector,
+    widget::{button, container, scrollable, Column, Container, Row, Text},
+    Background, Border, Color, Length, Shadow, Task, Vector,
 };
-use std::sync::{Arc, Mutex};
-use tokio::sync::Mutex as AsyncMutex;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SelectedConsole {
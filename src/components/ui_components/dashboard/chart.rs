@@ -0,0 +1,226 @@
+use crm_core::component::{BChart, BChartType};
+use iced::{
+    alignment, mouse,
+    widget::canvas::{self, path::Arc as PathArc, Frame, Geometry, Path, Stroke, Text},
+    Color, Pixels, Point, Radians, Rectangle, Renderer, Size, Theme,
+};
+use std::f32::consts::PI;
+
+const MARGIN: f32 = 30.0;
+
+const PALETTE: [Color; 6] = [
+    Color::from_rgb(0.0, 0.7, 1.0),
+    Color::from_rgb(1.0, 0.4, 0.4),
+    Color::from_rgb(0.3, 0.8, 0.4),
+    Color::from_rgb(1.0, 0.8, 0.2),
+    Color::from_rgb(0.7, 0.4, 1.0),
+    Color::from_rgb(1.0, 0.6, 0.0),
+];
+
+#[derive(Debug, Clone)]
+pub struct ChartCanvas {
+    chart: BChart,
+}
+
+impl ChartCanvas {
+    pub fn new(chart: BChart) -> Self {
+        Self { chart }
+    }
+
+    fn max_value(&self) -> f32 {
+        self.chart
+            .points
+            .iter()
+            .map(|point| point.value as f32)
+            .fold(0.0, f32::max)
+    }
+
+    fn draw_axes(&self, frame: &mut Frame, size: Size) {
+        let axes = Path::new(|builder| {
+            builder.move_to(Point::new(MARGIN, MARGIN / 2.0));
+            builder.line_to(Point::new(MARGIN, size.height - MARGIN));
+            builder.line_to(Point::new(size.width - MARGIN / 2.0, size.height - MARGIN));
+        });
+        frame.stroke(
+            &axes,
+            Stroke::default()
+                .with_color(Color::from_rgb(0.6, 0.6, 0.7))
+                .with_width(1.0),
+        );
+        frame.fill_text(Text {
+            content: format!("{}", self.max_value()),
+            position: Point::new(2.0, MARGIN / 2.0),
+            color: Color::from_rgb(0.9, 0.9, 1.0),
+            size: Pixels(10.0),
+            ..Text::default()
+        });
+    }
+
+    fn draw_label(&self, frame: &mut Frame, label: &str, position: Point) {
+        // long timestamps and names are cut so neighbouring labels stay readable
+        let label: String = label.chars().take(10).collect();
+        frame.fill_text(Text {
+            content: label,
+            position,
+            color: Color::from_rgb(0.9, 0.9, 1.0),
+            size: Pixels(10.0),
+            horizontal_alignment: alignment::Horizontal::Center,
+            ..Text::default()
+        });
+    }
+
+    fn draw_bar_chart(&self, frame: &mut Frame, size: Size) {
+        self.draw_axes(frame, size);
+        let max_value = self.max_value();
+        let plot_width = size.width - MARGIN * 1.5;
+        let plot_height = size.height - MARGIN * 1.5;
+        let slot_width = plot_width / self.chart.points.len() as f32;
+
+        for (index, point) in self.chart.points.iter().enumerate() {
+            let bar_height = if max_value > 0.0 {
+                point.value as f32 / max_value * plot_height
+            } else {
+                0.0
+            };
+            let x = MARGIN + slot_width * index as f32;
+            frame.fill_rectangle(
+                Point::new(x + slot_width * 0.1, size.height - MARGIN - bar_height),
+                Size::new(slot_width * 0.8, bar_height),
+                PALETTE[0],
+            );
+            self.draw_label(
+                frame,
+                &point.label,
+                Point::new(x + slot_width / 2.0, size.height - MARGIN + 5.0),
+            );
+        }
+    }
+
+    fn draw_line_chart(&self, frame: &mut Frame, size: Size) {
+        self.draw_axes(frame, size);
+        let max_value = self.max_value();
+        let plot_width = size.width - MARGIN * 1.5;
+        let plot_height = size.height - MARGIN * 1.5;
+        let step = if self.chart.points.len() > 1 {
+            plot_width / (self.chart.points.len() - 1) as f32
+        } else {
+            0.0
+        };
+
+        let positions: Vec<Point> = self
+            .chart
+            .points
+            .iter()
+            .enumerate()
+            .map(|(index, point)| {
+                let y = if max_value > 0.0 {
+                    point.value as f32 / max_value * plot_height
+                } else {
+                    0.0
+                };
+                Point::new(MARGIN + step * index as f32, size.height - MARGIN - y)
+            })
+            .collect();
+
+        let line = Path::new(|builder| {
+            for (index, position) in positions.iter().enumerate() {
+                if index == 0 {
+                    builder.move_to(*position);
+                } else {
+                    builder.line_to(*position);
+                }
+            }
+        });
+        frame.stroke(
+            &line,
+            Stroke::default().with_color(PALETTE[0]).with_width(2.0),
+        );
+
+        for (position, point) in positions.iter().zip(&self.chart.points) {
+            frame.fill(&Path::circle(*position, 3.0), PALETTE[1]);
+            self.draw_label(
+                frame,
+                &point.label,
+                Point::new(position.x, size.height - MARGIN + 5.0),
+            );
+        }
+    }
+
+    fn draw_pie_chart(&self, frame: &mut Frame, size: Size) {
+        let total: f32 = self
+            .chart
+            .points
+            .iter()
+            .map(|point| point.value as f32)
+            .sum();
+        if total <= 0.0 {
+            return;
+        }
+        let radius = (size.height / 2.0 - MARGIN / 2.0).max(0.0);
+        let center = Point::new(radius + MARGIN / 2.0, size.height / 2.0);
+        let mut start_angle = -PI / 2.0;
+
+        for (index, point) in self.chart.points.iter().enumerate() {
+            let sweep = point.value as f32 / total * 2.0 * PI;
+            let color = PALETTE[index % PALETTE.len()];
+            let slice = Path::new(|builder| {
+                builder.move_to(center);
+                builder.arc(PathArc {
+                    center,
+                    radius,
+                    start_angle: Radians(start_angle),
+                    end_angle: Radians(start_angle + sweep),
+                });
+                builder.close();
+            });
+            frame.fill(&slice, color);
+            start_angle += sweep;
+
+            // legend on the right hand side of the pie
+            let legend_y = MARGIN / 2.0 + index as f32 * 16.0;
+            let legend_x = center.x + radius + MARGIN;
+            frame.fill_rectangle(Point::new(legend_x, legend_y), Size::new(10.0, 10.0), color);
+            frame.fill_text(Text {
+                content: format!("{} ({})", point.label, point.value),
+                position: Point::new(legend_x + 15.0, legend_y),
+                color: Color::from_rgb(0.9, 0.9, 1.0),
+                size: Pixels(11.0),
+                ..Text::default()
+            });
+        }
+    }
+}
+
+impl<Message> canvas::Program<Message> for ChartCanvas {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        if self.chart.points.is_empty() {
+            frame.fill_text(Text {
+                content: String::from("No data"),
+                position: frame.center(),
+                color: Color::from_rgb(0.6, 0.6, 0.7),
+                size: Pixels(14.0),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+            return vec![frame.into_geometry()];
+        }
+
+        match self.chart.definition.chart_type {
+            BChartType::Bar => self.draw_bar_chart(&mut frame, bounds.size()),
+            BChartType::Line => self.draw_line_chart(&mut frame, bounds.size()),
+            BChartType::Pie => self.draw_pie_chart(&mut frame, bounds.size()),
+        }
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,452 @@
+use crate::components::ui_components::{
+    component::{Event, UIComponent},
+    dashboard::{chart::ChartCanvas, events::DashboardMessage},
+    events::Message,
+};
+use crm_core::{
+    component::{
+        BAggregate, BChart, BChartDefinition, BChartType, BDataType, BTableGeneral, BTimeBucket,
+        BusinessComponent,
+    },
+    components::BusinessDashboard,
+};
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    widget::{
+        button, canvas, container, scrollable, text, text_input, Button, Column, PickList, Row,
+    },
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::iter::zip;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct DashboardUI {
+    dashboard: Arc<BusinessDashboard>,
+    charts: Vec<BChart>,
+    chart_form: BChartDefinition,
+    show_chart_form: bool,
+}
+
+impl UIComponent for DashboardUI {
+    type EventType = DashboardMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::InitializeComponent => {
+                let dashboard = self.dashboard.clone();
+                Task::perform(
+                    async move {
+                        dashboard.initialize_component().await;
+                    },
+                    |_| Self::EventType::ComponentInitialized.message(),
+                )
+            }
+            Self::EventType::ComponentInitialized => {
+                Task::done(Self::EventType::SetCharts.message())
+            }
+            Self::EventType::SetCharts => {
+                self.charts = self.dashboard.charts.blocking_lock().clone();
+                Task::none()
+            }
+            Self::EventType::RefreshCharts => {
+                let dashboard = self.dashboard.clone();
+                Task::perform(
+                    async move {
+                        dashboard.set_charts().await;
+                    },
+                    |_| Self::EventType::SetCharts.message(),
+                )
+            }
+            Self::EventType::ShowOrRemoveChartForm => {
+                self.show_chart_form = !self.show_chart_form;
+                Task::none()
+            }
+            Self::EventType::UpdateChartTitle(title) => {
+                self.chart_form.title = title;
+                Task::none()
+            }
+            Self::EventType::UpdateChartType(chart_type) => {
+                self.chart_form.chart_type = chart_type;
+                Task::none()
+            }
+            Self::EventType::UpdateChartTable(table_name) => {
+                self.chart_form.table_name = table_name;
+                self.chart_form.group_by_column = String::new();
+                self.chart_form.aggregate_column = None;
+                self.chart_form.time_bucket = None;
+                Task::none()
+            }
+            Self::EventType::UpdateGroupByColumn(column_name) => {
+                // timestamps are grouped per bucket, grouping by raw instants is never useful
+                self.chart_form.time_bucket =
+                    if self.column_data_type(&column_name) == Some(BDataType::TIMESTAMP) {
+                        Some(BTimeBucket::Week)
+                    } else {
+                        None
+                    };
+                self.chart_form.group_by_column = column_name;
+                Task::none()
+            }
+            Self::EventType::UpdateTimeBucket(time_bucket) => {
+                self.chart_form.time_bucket = Some(time_bucket);
+                Task::none()
+            }
+            Self::EventType::UpdateAggregate(aggregate) => {
+                if aggregate == BAggregate::Count {
+                    self.chart_form.aggregate_column = None;
+                }
+                self.chart_form.aggregate = aggregate;
+                Task::none()
+            }
+            Self::EventType::UpdateAggregateColumn(column_name) => {
+                self.chart_form.aggregate_column = Some(column_name);
+                Task::none()
+            }
+            Self::EventType::SubmitChart => {
+                if !self.chart_form_is_complete() {
+                    return Task::none();
+                }
+                let dashboard = self.dashboard.clone();
+                let chart_definition = self.chart_form.clone();
+                self.chart_form = BChartDefinition::default();
+                self.show_chart_form = false;
+                Task::perform(
+                    async move {
+                        dashboard.add_chart(chart_definition).await;
+                    },
+                    |_| Self::EventType::SetCharts.message(),
+                )
+            }
+            Self::EventType::DeleteChart(chart_id) => {
+                let dashboard = self.dashboard.clone();
+                Task::perform(
+                    async move {
+                        dashboard.delete_chart(chart_id).await;
+                    },
+                    |_| Self::EventType::SetCharts.message(),
+                )
+            }
+        }
+    }
+}
+
+impl DashboardUI {
+    pub fn new(dashboard: Arc<BusinessDashboard>) -> Self {
+        Self {
+            dashboard,
+            charts: vec![],
+            chart_form: BChartDefinition::default(),
+            show_chart_form: false,
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let mut dashboard_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(
+                text("Dashboard")
+                    .size(32)
+                    .color(Color::from_rgb(0.9, 0.9, 1.0)),
+            )
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(self.toggle_chart_form_button())
+                    .push(self.refresh_charts_button()),
+            );
+
+        if self.show_chart_form {
+            dashboard_column = dashboard_column.push(self.chart_form());
+        }
+
+        dashboard_column =
+            dashboard_column.push(scrollable(self.charts_grid()).height(Length::Fill));
+
+        container(dashboard_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn selected_table(&self) -> Option<BTableGeneral> {
+        self.dashboard
+            .tables_general_info
+            .blocking_lock()
+            .iter()
+            .find(|table| table.table_name == self.chart_form.table_name)
+            .cloned()
+    }
+
+    fn column_data_type(&self, column_name: &str) -> Option<BDataType> {
+        self.selected_table().and_then(|table| {
+            zip(table.column_names, table.data_types)
+                .find(|(name, _)| name == column_name)
+                .map(|(_, data_type)| data_type)
+        })
+    }
+
+    fn chart_form_is_complete(&self) -> bool {
+        !self.chart_form.title.is_empty()
+            && !self.chart_form.table_name.is_empty()
+            && !self.chart_form.group_by_column.is_empty()
+            && (self.chart_form.aggregate == BAggregate::Count
+                || self.chart_form.aggregate_column.is_some())
+    }
+
+    fn charts_grid<'a>(&'a self) -> Element<'a, Message> {
+        self.charts
+            .iter()
+            .fold(Row::new().spacing(20), |charts_row, chart| {
+                charts_row.push(self.chart_card(chart))
+            })
+            .wrap()
+            .into()
+    }
+
+    fn chart_card<'a>(&'a self, chart: &BChart) -> Element<'a, Message> {
+        let mut header = Row::new().spacing(10).align_y(Vertical::Center).push(
+            text(chart.definition.title.clone())
+                .size(18)
+                .color(Color::from_rgb(0.9, 0.9, 1.0))
+                .width(Length::Fill),
+        );
+        if let Some(chart_id) = chart.definition.id {
+            header = header.push(
+                button(text("🗑️").size(14))
+                    .style(|_, _| delete_button_style())
+                    .on_press(DashboardMessage::DeleteChart(chart_id).message()),
+            );
+        }
+
+        container(
+            Column::new().spacing(10).push(header).push(
+                canvas(ChartCanvas::new(chart.clone()))
+                    .width(400)
+                    .height(250),
+            ),
+        )
+        .padding(10)
+        .width(420)
+        .style(|_| chart_card_style())
+        .into()
+    }
+
+    fn chart_form<'a>(&'a self) -> Element<'a, Message> {
+        let table_names: Vec<String> = self
+            .dashboard
+            .tables_general_info
+            .blocking_lock()
+            .iter()
+            .map(|table| table.table_name.clone())
+            .collect();
+
+        let mut form = Column::new()
+            .spacing(10)
+            .push(
+                text_input("Chart Title", &self.chart_form.title)
+                    .on_input(|value| DashboardMessage::UpdateChartTitle(value).message())
+                    .padding(5)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(PickList::new(
+                        vec![BChartType::Bar, BChartType::Line, BChartType::Pie],
+                        Some(self.chart_form.chart_type.clone()),
+                        |chart_type| DashboardMessage::UpdateChartType(chart_type).message(),
+                    ))
+                    .push(
+                        PickList::new(
+                            table_names,
+                            Some(self.chart_form.table_name.clone())
+                                .filter(|table_name| !table_name.is_empty()),
+                            |table_name| DashboardMessage::UpdateChartTable(table_name).message(),
+                        )
+                        .placeholder("Table"),
+                    ),
+            );
+
+        if let Some(table) = self.selected_table() {
+            let numeric_column_names: Vec<String> = zip(&table.column_names, &table.data_types)
+                .filter(|(_, data_type)| {
+                    matches!(data_type, BDataType::INTEGER | BDataType::SERIAL)
+                })
+                .map(|(column_name, _)| column_name.clone())
+                .collect();
+
+            let mut group_by_row = Row::new()
+                .spacing(10)
+                .align_y(Vertical::Center)
+                .push(text("Group by"))
+                .push(
+                    PickList::new(
+                        table.column_names.clone(),
+                        Some(self.chart_form.group_by_column.clone())
+                            .filter(|column_name| !column_name.is_empty()),
+                        |column_name| DashboardMessage::UpdateGroupByColumn(column_name).message(),
+                    )
+                    .placeholder("Column"),
+                );
+            if let Some(time_bucket) = &self.chart_form.time_bucket {
+                group_by_row = group_by_row.push(PickList::new(
+                    vec![
+                        BTimeBucket::Day,
+                        BTimeBucket::Week,
+                        BTimeBucket::Month,
+                        BTimeBucket::Year,
+                    ],
+                    Some(time_bucket.clone()),
+                    |time_bucket| DashboardMessage::UpdateTimeBucket(time_bucket).message(),
+                ));
+            }
+
+            let mut aggregate_row = Row::new()
+                .spacing(10)
+                .align_y(Vertical::Center)
+                .push(text("Aggregate"))
+                .push(PickList::new(
+                    vec![
+                        BAggregate::Count,
+                        BAggregate::Sum,
+                        BAggregate::Avg,
+                        BAggregate::Min,
+                        BAggregate::Max,
+                    ],
+                    Some(self.chart_form.aggregate.clone()),
+                    |aggregate| DashboardMessage::UpdateAggregate(aggregate).message(),
+                ));
+            if self.chart_form.aggregate != BAggregate::Count {
+                aggregate_row = aggregate_row.push(
+                    PickList::new(
+                        numeric_column_names,
+                        self.chart_form.aggregate_column.clone(),
+                        |column_name| {
+                            DashboardMessage::UpdateAggregateColumn(column_name).message()
+                        },
+                    )
+                    .placeholder("Column"),
+                );
+            }
+
+            form = form.push(group_by_row).push(aggregate_row);
+        }
+
+        form = form.push(
+            button("Save Chart")
+                .style(|_, _| button_style())
+                .padding(10)
+                .on_press(DashboardMessage::SubmitChart.message()),
+        );
+
+        container(form)
+            .padding(20)
+            .style(|_| chart_card_style())
+            .into()
+    }
+
+    fn toggle_chart_form_button(&self) -> Button<'_, Message> {
+        button(if self.show_chart_form {
+            "Remove chart form"
+        } else {
+            "➕ New Chart"
+        })
+        .style(|_, _| button_style())
+        .padding(10)
+        .on_press(DashboardMessage::ShowOrRemoveChartForm.message())
+    }
+
+    fn refresh_charts_button(&self) -> Button<'_, Message> {
+        button("Refresh")
+            .style(|_, _| button_style())
+            .padding(10)
+            .on_press(DashboardMessage::RefreshCharts.message())
+    }
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn chart_card_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.15, 0.15, 0.2))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0), // Neon cyan border
+            width: 1.5,
+            radius: Radius::from(12.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow {
+            color: Color::from_rgba(0.0, 0.7, 1.0, 0.3),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn delete_button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.8, 0.2, 0.2))), // Soft red background
+        border: Border {
+            color: Color::from_rgb(0.6, 0.1, 0.1), // Dark red border
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn text_input_style() -> text_input::Style {
+    text_input::Style {
+        background: Background::Color(Color::from_rgb(0.15, 0.15, 0.2)),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 2.0,
+            radius: Radius::from(8.0),
+        },
+        icon: Color::from_rgb(0.9, 0.9, 1.0),
+        placeholder: Color::from_rgb(0.6, 0.6, 0.7),
+        value: Color::from_rgb(0.9, 0.9, 1.0),
+        selection: Color::from_rgba(0.0, 0.7, 1.0, 0.5),
+    }
+}
@@ -0,0 +1,26 @@
+use crate::components::ui_components::{component::Event, events::Message};
+use crm_core::component::{BAggregate, BChartType, BTimeBucket};
+
+#[derive(Debug, Clone)]
+pub enum DashboardMessage {
+    InitializeComponent,
+    ComponentInitialized,
+    SetCharts,
+    RefreshCharts,
+    ShowOrRemoveChartForm,
+    UpdateChartTitle(String),
+    UpdateChartType(BChartType),
+    UpdateChartTable(String),
+    UpdateGroupByColumn(String),
+    UpdateTimeBucket(BTimeBucket),
+    UpdateAggregate(BAggregate),
+    UpdateAggregateColumn(String),
+    SubmitChart,
+    DeleteChart(i32),
+}
+
+impl Event for DashboardMessage {
+    fn message(self) -> Message {
+        Message::Dashboard(self)
+    }
+}
@@ -0,0 +1,3 @@
+mod chart;
+pub mod dashboard;
+pub mod events;
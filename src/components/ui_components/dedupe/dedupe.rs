@@ -0,0 +1,473 @@
+use crate::components::ui_components::{
+    component::{Event, UIComponent},
+    dedupe::events::DedupeMessage,
+    events::Message,
+};
+use crm_core::{
+    component::{BDuplicateGroup, BDuplicateKey, BMatchType},
+    components::BusinessDedupe,
+};
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    widget::{button, container, radio, scrollable, text, Button, Column, PickList, Row},
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+// which row is kept and which row each column's value is taken from
+#[derive(Debug, Clone, Default)]
+struct MergeChoice {
+    surviving_row_index: usize,
+    chosen_row_indexes: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DedupeUI {
+    dedupe: Arc<BusinessDedupe>,
+    table_name: Option<String>,
+    duplicate_keys: Vec<BDuplicateKey>,
+    duplicate_groups: Vec<BDuplicateGroup>,
+    merge_choices: Vec<MergeChoice>,
+    has_searched: bool,
+}
+
+impl UIComponent for DedupeUI {
+    type EventType = DedupeMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::SelectTable(table_name) => {
+                self.table_name = Some(table_name);
+                self.duplicate_keys = vec![];
+                self.duplicate_groups = vec![];
+                self.merge_choices = vec![];
+                self.has_searched = false;
+                Task::none()
+            }
+            Self::EventType::AddDuplicateKey => {
+                if let Some(column_name) = self.column_names().first() {
+                    self.duplicate_keys.push(BDuplicateKey {
+                        column_name: column_name.clone(),
+                        match_type: BMatchType::Exact,
+                    });
+                }
+                Task::none()
+            }
+            Self::EventType::UpdateDuplicateKeyColumn(index, column_name) => {
+                if let Some(duplicate_key) = self.duplicate_keys.get_mut(index) {
+                    duplicate_key.column_name = column_name;
+                }
+                Task::none()
+            }
+            Self::EventType::UpdateDuplicateKeyMatchType(index, match_type) => {
+                if let Some(duplicate_key) = self.duplicate_keys.get_mut(index) {
+                    duplicate_key.match_type = match_type;
+                }
+                Task::none()
+            }
+            Self::EventType::RemoveDuplicateKey(index) => {
+                if index < self.duplicate_keys.len() {
+                    self.duplicate_keys.remove(index);
+                }
+                Task::none()
+            }
+            Self::EventType::FindDuplicates => {
+                if let Some(table_name) = self.table_name.clone() {
+                    let dedupe = self.dedupe.clone();
+                    let duplicate_keys = self.duplicate_keys.clone();
+                    Task::perform(
+                        async move {
+                            dedupe.find_duplicates(table_name, duplicate_keys).await;
+                        },
+                        |_| Self::EventType::SetDuplicateGroups.message(),
+                    )
+                } else {
+                    Task::none()
+                }
+            }
+            Self::EventType::SetDuplicateGroups => {
+                self.duplicate_groups = self.dedupe.duplicate_groups.blocking_lock().clone();
+                self.merge_choices = self
+                    .duplicate_groups
+                    .iter()
+                    .map(|duplicate_group| MergeChoice {
+                        surviving_row_index: 0,
+                        chosen_row_indexes: vec![0; duplicate_group.column_names.len()],
+                    })
+                    .collect();
+                self.has_searched = true;
+                Task::none()
+            }
+            Self::EventType::SelectSurvivingRow(group_index, row_index) => {
+                if let (Some(duplicate_group), Some(merge_choice)) = (
+                    self.duplicate_groups.get(group_index),
+                    self.merge_choices.get_mut(group_index),
+                ) {
+                    merge_choice.surviving_row_index = row_index;
+                    // primary keys always follow the surviving row
+                    for (column_index, column_name) in
+                        duplicate_group.column_names.iter().enumerate()
+                    {
+                        if duplicate_group
+                            .primary_key_column_names
+                            .contains(column_name)
+                        {
+                            merge_choice.chosen_row_indexes[column_index] = row_index;
+                        }
+                    }
+                }
+                Task::none()
+            }
+            Self::EventType::ChooseColumnValue(group_index, column_index, row_index) => {
+                if let Some(merge_choice) = self.merge_choices.get_mut(group_index) {
+                    merge_choice.chosen_row_indexes[column_index] = row_index;
+                }
+                Task::none()
+            }
+            Self::EventType::MergeDuplicateGroup(group_index) => {
+                if let (Some(duplicate_group), Some(merge_choice)) = (
+                    self.duplicate_groups.get(group_index),
+                    self.merge_choices.get(group_index),
+                ) {
+                    let merged_row = merged_row(duplicate_group, merge_choice);
+                    let surviving_row_index = merge_choice.surviving_row_index;
+                    let dedupe = self.dedupe.clone();
+                    Task::perform(
+                        async move {
+                            dedupe
+                                .merge_duplicate_group(group_index, surviving_row_index, merged_row)
+                                .await;
+                        },
+                        |_| Self::EventType::SetDuplicateGroups.message(),
+                    )
+                } else {
+                    Task::none()
+                }
+            }
+        }
+    }
+}
+
+impl DedupeUI {
+    pub fn new(dedupe: Arc<BusinessDedupe>) -> Self {
+        Self {
+            dedupe,
+            table_name: None,
+            duplicate_keys: vec![],
+            duplicate_groups: vec![],
+            merge_choices: vec![],
+            has_searched: false,
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let dedupe_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(
+                text("Duplicates")
+                    .size(32)
+                    .color(Color::from_rgb(0.9, 0.9, 1.0)),
+            )
+            .push(self.duplicate_keys_form())
+            .push(scrollable(self.duplicate_groups_section()).height(Length::Fill));
+
+        container(dedupe_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        self.dedupe
+            .tables_general_info
+            .blocking_lock()
+            .iter()
+            .find(|table| Some(&table.table_name) == self.table_name.as_ref())
+            .map(|table| table.column_names.clone())
+            .unwrap_or_default()
+    }
+
+    fn duplicate_keys_form<'a>(&'a self) -> Element<'a, Message> {
+        let table_names: Vec<String> = self
+            .dedupe
+            .tables_general_info
+            .blocking_lock()
+            .iter()
+            .map(|table| table.table_name.clone())
+            .collect();
+        let column_names = self.column_names();
+
+        let mut form = Column::new().spacing(10).push(
+            PickList::new(table_names, self.table_name.clone(), |table_name| {
+                DedupeMessage::SelectTable(table_name).message()
+            })
+            .placeholder("Table"),
+        );
+        for (index, duplicate_key) in self.duplicate_keys.iter().enumerate() {
+            form = form.push(
+                Row::new()
+                    .spacing(10)
+                    .align_y(Vertical::Center)
+                    .push(PickList::new(
+                        column_names.clone(),
+                        Some(duplicate_key.column_name.clone()),
+                        move |column_name| {
+                            DedupeMessage::UpdateDuplicateKeyColumn(index, column_name).message()
+                        },
+                    ))
+                    .push(PickList::new(
+                        vec![
+                            BMatchType::Exact,
+                            BMatchType::Email,
+                            BMatchType::Phone,
+                            BMatchType::Name,
+                        ],
+                        Some(duplicate_key.match_type.clone()),
+                        move |match_type| {
+                            DedupeMessage::UpdateDuplicateKeyMatchType(index, match_type).message()
+                        },
+                    ))
+                    .push(
+                        button("✖")
+                            .style(|_, _| delete_button_style())
+                            .on_press(DedupeMessage::RemoveDuplicateKey(index).message()),
+                    ),
+            );
+        }
+        let mut buttons = Row::new().spacing(10);
+        if self.table_name.is_some() {
+            buttons = buttons.push(
+                button("Add Key Column")
+                    .style(|_, _| button_style())
+                    .padding(10)
+                    .on_press(DedupeMessage::AddDuplicateKey.message()),
+            );
+        }
+        if !self.duplicate_keys.is_empty() {
+            buttons = buttons.push(
+                button("Find Duplicates")
+                    .style(|_, _| button_style())
+                    .padding(10)
+                    .on_press(DedupeMessage::FindDuplicates.message()),
+            );
+        }
+        form.push(buttons).into()
+    }
+
+    fn duplicate_groups_section<'a>(&'a self) -> Element<'a, Message> {
+        if self.has_searched && self.duplicate_groups.is_empty() {
+            return text("No duplicates found")
+                .color(Color::from_rgb(0.6, 0.6, 0.7))
+                .into();
+        }
+        self.duplicate_groups
+            .iter()
+            .zip(&self.merge_choices)
+            .enumerate()
+            .fold(
+                Column::new().spacing(20),
+                |groups_column, (group_index, (duplicate_group, merge_choice))| {
+                    groups_column.push(self.duplicate_group_card(
+                        group_index,
+                        duplicate_group,
+                        merge_choice,
+                    ))
+                },
+            )
+            .into()
+    }
+
+    fn duplicate_group_card<'a>(
+        &'a self,
+        group_index: usize,
+        duplicate_group: &'a BDuplicateGroup,
+        merge_choice: &'a MergeChoice,
+    ) -> Element<'a, Message> {
+        let header = duplicate_group.column_names.iter().fold(
+            Row::new()
+                .spacing(10)
+                .push(container(text("Keep")).width(60)),
+            |header, column_name| {
+                header.push(
+                    container(text(column_name.clone()).color(Color::from_rgb(0.0, 0.7, 1.0)))
+                        .width(150),
+                )
+            },
+        );
+        let mut card = Column::new().spacing(8).push(header);
+
+        for (row_index, row) in duplicate_group.rows.iter().enumerate() {
+            let mut group_row = Row::new().spacing(10).align_y(Vertical::Center).push(
+                container(radio(
+                    "",
+                    row_index,
+                    Some(merge_choice.surviving_row_index),
+                    move |row_index| {
+                        DedupeMessage::SelectSurvivingRow(group_index, row_index).message()
+                    },
+                ))
+                .width(60),
+            );
+            for (column_index, value) in row.iter().enumerate() {
+                group_row = group_row.push(
+                    self.column_value_button(
+                        group_index,
+                        column_index,
+                        row_index,
+                        value,
+                        merge_choice.chosen_row_indexes[column_index] == row_index,
+                        duplicate_group
+                            .primary_key_column_names
+                            .contains(&duplicate_group.column_names[column_index]),
+                    ),
+                );
+            }
+            card = card.push(group_row);
+        }
+
+        let merged_row = merged_row(duplicate_group, merge_choice);
+        card = card.push(
+            merged_row.into_iter().fold(
+                Row::new()
+                    .spacing(10)
+                    .push(container(text("Result")).width(60)),
+                |result_row, value| result_row.push(container(text(value)).width(150)),
+            ),
+        );
+        card = card.push(
+            button("Merge")
+                .style(|_, _| button_style())
+                .padding(10)
+                .on_press(DedupeMessage::MergeDuplicateGroup(group_index).message()),
+        );
+
+        container(card)
+            .padding(15)
+            .width(Length::Fill)
+            .style(|_| group_card_style())
+            .into()
+    }
+
+    fn column_value_button<'a>(
+        &'a self,
+        group_index: usize,
+        column_index: usize,
+        row_index: usize,
+        value: &str,
+        is_chosen: bool,
+        is_primary_key: bool,
+    ) -> Button<'a, Message> {
+        let value_button = button(text(value.to_string()).size(14))
+            .width(150)
+            .style(move |_, _| column_value_button_style(is_chosen));
+        // primary key values are chosen through the surviving row
+        if is_primary_key {
+            value_button
+        } else {
+            value_button.on_press(
+                DedupeMessage::ChooseColumnValue(group_index, column_index, row_index).message(),
+            )
+        }
+    }
+}
+
+fn merged_row(duplicate_group: &BDuplicateGroup, merge_choice: &MergeChoice) -> Vec<String> {
+    merge_choice
+        .chosen_row_indexes
+        .iter()
+        .enumerate()
+        .map(|(column_index, row_index)| duplicate_group.rows[*row_index][column_index].clone())
+        .collect()
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn group_card_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.15, 0.15, 0.2))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 1.5,
+            radius: Radius::from(12.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow {
+            color: Color::from_rgba(0.0, 0.7, 1.0, 0.3),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn column_value_button_style(is_chosen: bool) -> button::Style {
+    button::Style {
+        background: Some(Background::Color(if is_chosen {
+            Color::from_rgba(0.0, 0.7, 1.0, 0.35)
+        } else {
+            Color::from_rgb(0.2, 0.2, 0.25)
+        })),
+        border: Border {
+            color: if is_chosen {
+                Color::from_rgb(0.0, 0.7, 1.0)
+            } else {
+                Color::TRANSPARENT
+            },
+            width: 1.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::from_rgb(0.9, 0.9, 1.0),
+        shadow: Shadow::default(),
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn delete_button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.8, 0.2, 0.2))), // Soft red background
+        border: Border {
+            color: Color::from_rgb(0.6, 0.1, 0.1), // Dark red border
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
@@ -0,0 +1,22 @@
+use crate::components::ui_components::{component::Event, events::Message};
+use crm_core::component::BMatchType;
+
+#[derive(Debug, Clone)]
+pub enum DedupeMessage {
+    SelectTable(String),
+    AddDuplicateKey,
+    UpdateDuplicateKeyColumn(usize, String),
+    UpdateDuplicateKeyMatchType(usize, BMatchType),
+    RemoveDuplicateKey(usize),
+    FindDuplicates,
+    SetDuplicateGroups,
+    SelectSurvivingRow(usize, usize),
+    ChooseColumnValue(usize, usize, usize),
+    MergeDuplicateGroup(usize),
+}
+
+impl Event for DedupeMessage {
+    fn message(self) -> Message {
+        Message::Dedupe(self)
+    }
+}
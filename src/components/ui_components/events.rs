@@ -1,6 +1,11 @@
-use crate::components::ui_components::components::{ComponentsMessage, UIComponents};
+use crate::components::ui_components::components::ComponentsMessage;
 use crate::components::ui_components::{
-    console::events::ConsoleMessage, home::events::HomeMessage, tables::events::TablesMessage,
+    access_rules::events::AccessRulesMessage, audit::events::AuditMessage,
+    backup::events::BackupMessage, console::events::ConsoleMessage,
+    dashboard::events::DashboardMessage, dedupe::events::DedupeMessage, home::events::HomeMessage,
+    login::events::LoginMessage, schema_comparison::events::SchemaComparisonMessage,
+    schema_diagram::events::SchemaDiagramMessage, schema_file::events::SchemaFileMessage,
+    search::events::SearchMessage, tables::events::TablesMessage, users::events::UsersMessage,
 };
 
 #[derive(Debug, Clone)]
@@ -9,4 +14,15 @@ pub enum Message {
     Home(HomeMessage),
     Tables(TablesMessage),
     Console(ConsoleMessage),
+    Dashboard(DashboardMessage),
+    Search(SearchMessage),
+    Dedupe(DedupeMessage),
+    Audit(AuditMessage),
+    Login(LoginMessage),
+    Users(UsersMessage),
+    AccessRules(AccessRulesMessage),
+    SchemaDiagram(SchemaDiagramMessage),
+    SchemaFile(SchemaFileMessage),
+    SchemaComparison(SchemaComparisonMessage),
+    Backup(BackupMessage),
 }
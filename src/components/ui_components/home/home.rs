@@ -1,19 +1,14 @@
-use crate::components::business_components::{
-    component::BusinessComponent, components::BusinessHome,
-};
 use crate::components::ui_components::{
     component::{Event, UIComponent},
     events::Message,
     home::events::HomeMessage,
 };
+use crm_core::{component::BusinessComponent, components::BusinessHome};
 use iced::{
-    widget::{
-        button, column, container, row, scrollable, text, text_input, Column, PickList, Row, Text,
-    },
-    Alignment, Background, Border, Color, Element, Length, Task, Theme,
+    widget::{container, text},
+    Element, Task,
 };
-use std::sync::{Arc, Mutex};
-use tokio::sync::Mutex as AsyncMutex;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct HomeUI {
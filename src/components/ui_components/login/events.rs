@@ -0,0 +1,16 @@
+use crate::components::ui_components::{component::Event, events::Message, login::login::LoginUI};
+
+#[derive(Debug, Clone)]
+pub enum LoginMessage {
+    InitializeComponent(LoginUI),
+    UpdateUserName(String),
+    UpdatePassword(String),
+    LogIn,
+    LoggedIn(Result<(), String>),
+}
+
+impl Event for LoginMessage {
+    fn message(self) -> Message {
+        Message::Login(self)
+    }
+}
@@ -0,0 +1,197 @@
+use crate::components::ui_components::{
+    component::{Event, UIComponent},
+    components::{ComponentsMessage, UIComponents},
+    events::Message,
+    login::events::LoginMessage,
+};
+use crm_core::{component::BusinessComponent, components::BusinessAuthentication};
+use iced::{
+    alignment::Horizontal,
+    border::Radius,
+    widget::{button, container, text, text_input, Column},
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct LoginUI {
+    authentication: Arc<BusinessAuthentication>,
+    // no account exists yet, the form creates the first administrator
+    is_first_run: bool,
+    user_name: String,
+    password: String,
+    error: Option<String>,
+}
+
+impl UIComponent for LoginUI {
+    type EventType = LoginMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::UpdateUserName(user_name) => {
+                self.user_name = user_name;
+                Task::none()
+            }
+            Self::EventType::UpdatePassword(password) => {
+                self.password = password;
+                Task::none()
+            }
+            Self::EventType::LogIn => {
+                let authentication = self.authentication.clone();
+                let is_first_run = self.is_first_run;
+                let user_name = self.user_name.clone();
+                let password = self.password.clone();
+                Task::perform(
+                    async move {
+                        if is_first_run {
+                            authentication.create_first_admin(user_name, password).await
+                        } else {
+                            authentication.log_in(user_name, password).await
+                        }
+                    },
+                    |result| Self::EventType::LoggedIn(result).message(),
+                )
+            }
+            Self::EventType::LoggedIn(Ok(())) => {
+                self.is_first_run = false;
+                self.password = String::new();
+                self.error = None;
+                Task::perform(
+                    UIComponents::new(self.authentication.clone()),
+                    |result| match result {
+                        Ok(components) => {
+                            ComponentsMessage::InitializeComponents(components).message()
+                        }
+                        Err(error) => Self::EventType::LoggedIn(Err(error)).message(),
+                    },
+                )
+            }
+            Self::EventType::LoggedIn(Err(error)) => {
+                self.error = Some(error);
+                Task::none()
+            }
+            _ => Task::none(),
+        }
+    }
+}
+
+impl LoginUI {
+    pub async fn new() -> Self {
+        /* creates repositories */
+        let authentication = BusinessAuthentication::connect().await;
+        authentication.initialize_component().await;
+        let is_first_run = !*authentication.has_users.lock().await;
+        Self {
+            authentication: Arc::new(authentication),
+            is_first_run,
+            user_name: String::new(),
+            password: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let (title, submit_label) = if self.is_first_run {
+            ("Create the administrator account", "Create Account")
+        } else {
+            ("Log in", "Log In")
+        };
+        let mut login_column = Column::new()
+            .spacing(15)
+            .padding(30)
+            .width(350)
+            .align_x(Horizontal::Center)
+            .push(text(title).size(24).color(Color::from_rgb(0.9, 0.9, 1.0)))
+            .push(
+                text_input("User name", &self.user_name)
+                    .on_input(|value| LoginMessage::UpdateUserName(value).message())
+                    .on_submit(LoginMessage::LogIn.message())
+                    .padding(10)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(
+                text_input("Password", &self.password)
+                    .secure(true)
+                    .on_input(|value| LoginMessage::UpdatePassword(value).message())
+                    .on_submit(LoginMessage::LogIn.message())
+                    .padding(10)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(
+                button(text(submit_label))
+                    .style(|_, _| button_style())
+                    .on_press(LoginMessage::LogIn.message()),
+            );
+        if let Some(error) = &self.error {
+            login_column = login_column.push(text(error).color(Color::from_rgb(1.0, 0.4, 0.4)));
+        }
+
+        container(container(login_column).style(|_| login_card_style()))
+            .center_x(Length::Fill)
+            .center_y(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn login_card_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.15, 0.15, 0.2))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow::default(),
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn text_input_style() -> text_input::Style {
+    text_input::Style {
+        background: Background::Color(Color::from_rgb(0.15, 0.15, 0.2)),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 2.0,
+            radius: Radius::from(8.0),
+        },
+        icon: Color::from_rgb(0.9, 0.9, 1.0),
+        placeholder: Color::from_rgb(0.6, 0.6, 0.7),
+        value: Color::from_rgb(0.9, 0.9, 1.0),
+        selection: Color::from_rgba(0.0, 0.7, 1.0, 0.5),
+    }
+}
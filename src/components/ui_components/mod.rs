@@ -1,6 +1,17 @@
+pub mod access_rules;
+pub mod audit;
+pub mod backup;
 pub mod component;
 pub mod components;
 pub mod console;
+pub mod dashboard;
+pub mod dedupe;
 pub mod events;
 pub mod home;
+pub mod login;
+pub mod schema_comparison;
+pub mod schema_diagram;
+pub mod schema_file;
+pub mod search;
 pub mod tables;
+pub mod users;
@@ -0,0 +1,16 @@
+use crate::components::ui_components::{component::Event, events::Message};
+
+#[derive(Debug, Clone)]
+pub enum SchemaComparisonMessage {
+    UpdateSourceDatabaseUrl(String),
+    UpdateTargetDatabaseUrl(String),
+    CompareSchemas,
+    SchemasCompared(Result<(), String>),
+    CopyMigrationScript,
+}
+
+impl Event for SchemaComparisonMessage {
+    fn message(self) -> Message {
+        Message::SchemaComparison(self)
+    }
+}
@@ -0,0 +1,293 @@
+use crate::components::ui_components::{
+    component::{Event, UIComponent},
+    events::Message,
+    schema_comparison::events::SchemaComparisonMessage,
+};
+use crm_core::{
+    component::{BSchemaDiff, BTableIn},
+    components::BusinessSchemaComparison,
+};
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    clipboard,
+    widget::{button, container, scrollable, text, text_input, Column, Row},
+    Background, Border, Color, Element, Font, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct SchemaComparisonUI {
+    schema_comparison: Arc<BusinessSchemaComparison>,
+    source_database_url: String,
+    target_database_url: String,
+    is_comparing: bool,
+    schema_diff: Option<BSchemaDiff>,
+    comparison_error: Option<String>,
+}
+
+impl UIComponent for SchemaComparisonUI {
+    type EventType = SchemaComparisonMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::UpdateSourceDatabaseUrl(database_url) => {
+                self.source_database_url = database_url;
+                Task::none()
+            }
+            Self::EventType::UpdateTargetDatabaseUrl(database_url) => {
+                self.target_database_url = database_url;
+                Task::none()
+            }
+            Self::EventType::CompareSchemas => {
+                self.is_comparing = true;
+                let schema_comparison = self.schema_comparison.clone();
+                let source_database_url = self.source_database_url.clone();
+                let target_database_url = self.target_database_url.clone();
+                Task::perform(
+                    async move {
+                        schema_comparison
+                            .compare_schemas(source_database_url, target_database_url)
+                            .await
+                    },
+                    |result| Self::EventType::SchemasCompared(result).message(),
+                )
+            }
+            Self::EventType::SchemasCompared(result) => {
+                self.is_comparing = false;
+                self.schema_diff = self.schema_comparison.schema_diff.blocking_lock().clone();
+                self.comparison_error = result.err();
+                Task::none()
+            }
+            Self::EventType::CopyMigrationScript => match &self.schema_diff {
+                Some(schema_diff) => clipboard::write(schema_diff.migration_script()),
+                None => Task::none(),
+            },
+        }
+    }
+}
+
+impl SchemaComparisonUI {
+    pub fn new(schema_comparison: Arc<BusinessSchemaComparison>) -> Self {
+        Self {
+            schema_comparison,
+            source_database_url: String::new(),
+            target_database_url: String::new(),
+            is_comparing: false,
+            schema_diff: None,
+            comparison_error: None,
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let mut schema_comparison_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(
+                text("Compare Schemas")
+                    .size(32)
+                    .color(Color::from_rgb(0.9, 0.9, 1.0)),
+            )
+            .push(self.database_urls_row());
+
+        if let Some(comparison_error) = &self.comparison_error {
+            schema_comparison_column = schema_comparison_column
+                .push(text(comparison_error.clone()).color(Color::from_rgb(1.0, 0.4, 0.4)));
+        }
+
+        if let Some(schema_diff) = &self.schema_diff {
+            schema_comparison_column = schema_comparison_column
+                .push(scrollable(self.schema_diff_section(schema_diff)).height(Length::Fill));
+        }
+
+        container(schema_comparison_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn database_urls_row<'a>(&'a self) -> Element<'a, Message> {
+        Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(
+                text_input(
+                    "Source database url (current database)",
+                    &self.source_database_url,
+                )
+                .on_input(|value| SchemaComparisonMessage::UpdateSourceDatabaseUrl(value).message())
+                .width(350)
+                .padding(5)
+                .style(|_, _| text_input_style()),
+            )
+            .push(text("→"))
+            .push(
+                text_input(
+                    "Target database url (current database)",
+                    &self.target_database_url,
+                )
+                .on_input(|value| SchemaComparisonMessage::UpdateTargetDatabaseUrl(value).message())
+                .on_submit(SchemaComparisonMessage::CompareSchemas.message())
+                .width(350)
+                .padding(5)
+                .style(|_, _| text_input_style()),
+            )
+            .push(
+                button(if self.is_comparing {
+                    "Comparing..."
+                } else {
+                    "Compare"
+                })
+                .style(|_, _| button_style())
+                .on_press_maybe(
+                    (!self.is_comparing).then(|| SchemaComparisonMessage::CompareSchemas.message()),
+                ),
+            )
+            .into()
+    }
+
+    fn schema_diff_section<'a>(&'a self, schema_diff: &'a BSchemaDiff) -> Element<'a, Message> {
+        if schema_diff.is_empty() {
+            return text("The schemas are the same").into();
+        }
+
+        let mut diff_column = Column::new().spacing(10);
+        for table_in in &schema_diff.added_tables {
+            diff_column = diff_column.push(diff_card(
+                format!("Added table \"{}\"", table_in.table_name),
+                added_table_lines(table_in),
+            ));
+        }
+        for table_name in &schema_diff.removed_tables {
+            diff_column = diff_column.push(diff_card(
+                format!("Removed table \"{}\"", table_name),
+                vec![],
+            ));
+        }
+        for table_alteration in &schema_diff.changed_tables {
+            diff_column = diff_column.push(diff_card(
+                format!("Changed table \"{}\"", table_alteration.table_name),
+                table_alteration
+                    .table_change_events
+                    .iter()
+                    .map(|table_change_event| table_change_event.to_string())
+                    .collect(),
+            ));
+        }
+
+        diff_column
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_y(Vertical::Center)
+                    .push(text("Migration script").size(18))
+                    .push(
+                        button("Copy Script")
+                            .style(|_, _| button_style())
+                            .on_press(SchemaComparisonMessage::CopyMigrationScript.message()),
+                    ),
+            )
+            .push(
+                container(
+                    text(schema_diff.migration_script())
+                        .font(Font::MONOSPACE)
+                        .size(14),
+                )
+                .padding(10)
+                .width(Length::Fill)
+                .style(|_| diff_card_style()),
+            )
+            .into()
+    }
+}
+
+fn added_table_lines(table_in: &BTableIn) -> Vec<String> {
+    table_in
+        .columns
+        .iter()
+        .map(|column| {
+            let mut line = format!("{} {}", column.name, column.datatype);
+            for constraint in &column.constraints {
+                line.push_str(&format!(" {}", constraint));
+            }
+            line
+        })
+        .collect()
+}
+
+fn diff_card<'a>(title: String, lines: Vec<String>) -> Element<'a, Message> {
+    let card_column = lines.into_iter().fold(
+        Column::new()
+            .spacing(5)
+            .push(text(title).size(18).color(Color::from_rgb(0.9, 0.9, 1.0))),
+        |card_column, line| card_column.push(text(line).size(14)),
+    );
+    container(card_column)
+        .padding(10)
+        .width(Length::Fill)
+        .style(|_| diff_card_style())
+        .into()
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn diff_card_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.15, 0.15, 0.2))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow::default(),
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn text_input_style() -> text_input::Style {
+    text_input::Style {
+        background: Background::Color(Color::from_rgb(0.15, 0.15, 0.2)),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 2.0,
+            radius: Radius::from(8.0),
+        },
+        icon: Color::from_rgb(0.9, 0.9, 1.0),
+        placeholder: Color::from_rgb(0.6, 0.6, 0.7),
+        value: Color::from_rgb(0.9, 0.9, 1.0),
+        selection: Color::from_rgba(0.0, 0.7, 1.0, 0.5),
+    }
+}
@@ -0,0 +1,423 @@
+use crate::components::ui_components::{
+    component::Event, events::Message, schema_diagram::events::SchemaDiagramMessage,
+};
+use crm_core::component::{BConstraint, BDiagramTable, BNodePosition};
+use iced::{
+    alignment, mouse,
+    widget::canvas::{self, event::Status, Frame, Geometry, Path, Stroke, Text},
+    Color, Pixels, Point, Rectangle, Renderer, Size, Theme, Vector,
+};
+
+const MIN_SCALE: f32 = 0.25;
+const MAX_SCALE: f32 = 3.0;
+const TEXT_PADDING: f32 = 8.0;
+
+const NODE_COLOR: Color = Color::from_rgb(0.15, 0.15, 0.2);
+const HEADER_COLOR: Color = Color::from_rgb(0.0, 0.45, 0.6);
+const BORDER_COLOR: Color = Color::from_rgb(0.0, 0.7, 1.0);
+const LINE_COLOR: Color = Color::from_rgb(1.0, 0.8, 0.2);
+const TEXT_COLOR: Color = Color::from_rgb(0.9, 0.9, 1.0);
+const MUTED_TEXT_COLOR: Color = Color::from_rgb(0.6, 0.6, 0.7);
+
+#[derive(Debug, Clone)]
+enum Interaction {
+    None,
+    Panning {
+        start: Point,
+        start_translation: Vector,
+    },
+    // the node follows the cursor locally, the position is saved on release
+    DraggingNode {
+        table_name: String,
+        grab_offset: Vector,
+        position: Point,
+        has_moved: bool,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct DiagramState {
+    translation: Vector,
+    scale: f32,
+    interaction: Interaction,
+}
+
+impl Default for DiagramState {
+    fn default() -> Self {
+        Self {
+            translation: Vector::new(20.0, 20.0),
+            scale: 1.0,
+            interaction: Interaction::None,
+        }
+    }
+}
+
+impl DiagramState {
+    fn to_screen(&self, point: Point) -> Point {
+        Point::new(
+            point.x * self.scale + self.translation.x,
+            point.y * self.scale + self.translation.y,
+        )
+    }
+
+    fn to_diagram(&self, point: Point) -> Point {
+        Point::new(
+            (point.x - self.translation.x) / self.scale,
+            (point.y - self.translation.y) / self.scale,
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DiagramCanvas<'a> {
+    diagram_tables: &'a Vec<BDiagramTable>,
+    node_positions: &'a Vec<BNodePosition>,
+}
+
+impl<'a> DiagramCanvas<'a> {
+    pub fn new(
+        diagram_tables: &'a Vec<BDiagramTable>,
+        node_positions: &'a Vec<BNodePosition>,
+    ) -> Self {
+        Self {
+            diagram_tables,
+            node_positions,
+        }
+    }
+
+    fn node_position(&self, state: &DiagramState, table_name: &str) -> Option<Point> {
+        if let Interaction::DraggingNode {
+            table_name: dragged_table_name,
+            position,
+            ..
+        } = &state.interaction
+        {
+            if dragged_table_name == table_name {
+                return Some(*position);
+            }
+        }
+        self.node_positions
+            .iter()
+            .find(|node_position| node_position.table_name == table_name)
+            .map(|node_position| Point::new(node_position.x, node_position.y))
+    }
+
+    // node bounds in diagram units
+    fn node_bounds(
+        &self,
+        state: &DiagramState,
+        diagram_table: &BDiagramTable,
+    ) -> Option<Rectangle> {
+        self.node_position(state, &diagram_table.table_name)
+            .map(|position| {
+                Rectangle::new(
+                    position,
+                    Size::new(BDiagramTable::NODE_WIDTH, diagram_table.node_height()),
+                )
+            })
+    }
+
+    // the last drawn node is on top, so it is hit first
+    fn node_at(&self, state: &DiagramState, point: Point) -> Option<(String, Point)> {
+        self.diagram_tables.iter().rev().find_map(|diagram_table| {
+            self.node_bounds(state, diagram_table)
+                .filter(|node_bounds| node_bounds.contains(point))
+                .map(|node_bounds| (diagram_table.table_name.clone(), node_bounds.position()))
+        })
+    }
+
+    fn draw_foreign_keys(&self, frame: &mut Frame, state: &DiagramState) {
+        for diagram_table in self.diagram_tables {
+            let Some(node_bounds) = self.node_bounds(state, diagram_table) else {
+                continue;
+            };
+            for (column_index, column) in diagram_table.columns.iter().enumerate() {
+                for constraint in &column.constraints {
+                    let BConstraint::ForeignKey(referenced_table, referenced_column) = constraint
+                    else {
+                        continue;
+                    };
+                    let Some((referenced_diagram_table, referenced_bounds)) = self
+                        .diagram_tables
+                        .iter()
+                        .find(|diagram_table| &diagram_table.table_name == referenced_table)
+                        .and_then(|referenced_diagram_table| {
+                            self.node_bounds(state, referenced_diagram_table).map(
+                                |referenced_bounds| (referenced_diagram_table, referenced_bounds),
+                            )
+                        })
+                    else {
+                        continue;
+                    };
+                    let referenced_column_index = referenced_diagram_table
+                        .columns
+                        .iter()
+                        .position(|column| &column.name == referenced_column)
+                        .unwrap_or(0);
+
+                    // lines leave from the side of the box facing the other table
+                    let is_referenced_left = referenced_bounds.center_x() < node_bounds.center_x();
+                    let start = Point::new(
+                        if is_referenced_left {
+                            node_bounds.x
+                        } else {
+                            node_bounds.x + node_bounds.width
+                        },
+                        row_center_y(node_bounds, column_index),
+                    );
+                    let end = Point::new(
+                        if is_referenced_left {
+                            referenced_bounds.x + referenced_bounds.width
+                        } else {
+                            referenced_bounds.x
+                        },
+                        row_center_y(referenced_bounds, referenced_column_index),
+                    );
+                    let start = state.to_screen(start);
+                    let end = state.to_screen(end);
+                    let middle_x = (start.x + end.x) / 2.0;
+                    let line = Path::new(|builder| {
+                        builder.move_to(start);
+                        builder.line_to(Point::new(middle_x, start.y));
+                        builder.line_to(Point::new(middle_x, end.y));
+                        builder.line_to(end);
+                    });
+                    frame.stroke(
+                        &line,
+                        Stroke::default().with_color(LINE_COLOR).with_width(1.5),
+                    );
+                    frame.fill(&Path::circle(end, 3.5 * state.scale), LINE_COLOR);
+                }
+            }
+        }
+    }
+
+    fn draw_node(&self, frame: &mut Frame, state: &DiagramState, diagram_table: &BDiagramTable) {
+        let Some(node_bounds) = self.node_bounds(state, diagram_table) else {
+            return;
+        };
+        let top_left = state.to_screen(node_bounds.position());
+        let size = Size::new(
+            node_bounds.width * state.scale,
+            node_bounds.height * state.scale,
+        );
+        let header_height = BDiagramTable::HEADER_HEIGHT * state.scale;
+        let row_height = BDiagramTable::ROW_HEIGHT * state.scale;
+
+        frame.fill(&Path::rectangle(top_left, size), NODE_COLOR);
+        frame.fill(
+            &Path::rectangle(top_left, Size::new(size.width, header_height)),
+            HEADER_COLOR,
+        );
+        frame.stroke(
+            &Path::rectangle(top_left, size),
+            Stroke::default().with_color(BORDER_COLOR).with_width(1.5),
+        );
+        frame.fill_text(Text {
+            content: diagram_table.table_name.clone(),
+            position: Point::new(
+                top_left.x + TEXT_PADDING * state.scale,
+                top_left.y + header_height / 2.0,
+            ),
+            color: TEXT_COLOR,
+            size: Pixels(14.0 * state.scale),
+            vertical_alignment: alignment::Vertical::Center,
+            ..Text::default()
+        });
+
+        for (column_index, column) in diagram_table.columns.iter().enumerate() {
+            let row_y = top_left.y + header_height + row_height * (column_index as f32 + 0.5);
+            let markers: Vec<&str> = column
+                .constraints
+                .iter()
+                .map(|constraint| match constraint {
+                    BConstraint::PrimaryKey => "PK",
+                    BConstraint::ForeignKey(_, _) => "FK",
+                })
+                .collect();
+            frame.fill_text(Text {
+                content: format!("{} {}", markers.join(" "), column.name)
+                    .trim()
+                    .to_string(),
+                position: Point::new(top_left.x + TEXT_PADDING * state.scale, row_y),
+                color: TEXT_COLOR,
+                size: Pixels(12.0 * state.scale),
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+            frame.fill_text(Text {
+                content: column.datatype.to_string(),
+                position: Point::new(top_left.x + size.width - TEXT_PADDING * state.scale, row_y),
+                color: MUTED_TEXT_COLOR,
+                size: Pixels(11.0 * state.scale),
+                horizontal_alignment: alignment::Horizontal::Right,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+        }
+    }
+}
+
+fn row_center_y(node_bounds: Rectangle, column_index: usize) -> f32 {
+    node_bounds.y
+        + BDiagramTable::HEADER_HEIGHT
+        + BDiagramTable::ROW_HEIGHT * (column_index as f32 + 0.5)
+}
+
+impl<'a> canvas::Program<Message> for DiagramCanvas<'a> {
+    type State = DiagramState;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (Status, Option<Message>) {
+        let canvas::Event::Mouse(mouse_event) = event else {
+            return (Status::Ignored, None);
+        };
+        let cursor_position = cursor.position_in(bounds);
+
+        match mouse_event {
+            mouse::Event::ButtonPressed(mouse::Button::Left) => {
+                let Some(cursor_position) = cursor_position else {
+                    return (Status::Ignored, None);
+                };
+                let diagram_position = state.to_diagram(cursor_position);
+                state.interaction = match self.node_at(state, diagram_position) {
+                    Some((table_name, node_position)) => Interaction::DraggingNode {
+                        table_name,
+                        grab_offset: diagram_position - node_position,
+                        position: node_position,
+                        has_moved: false,
+                    },
+                    None => Interaction::Panning {
+                        start: cursor_position,
+                        start_translation: state.translation,
+                    },
+                };
+                (Status::Captured, None)
+            }
+            mouse::Event::CursorMoved { .. } => {
+                // the cursor can leave the canvas while dragging, so the
+                // position is taken relative to the bounds without clamping
+                let Some(cursor_position) = cursor.position_from(bounds.position()) else {
+                    return (Status::Ignored, None);
+                };
+                let diagram_position = state.to_diagram(cursor_position);
+                match &mut state.interaction {
+                    Interaction::None => return (Status::Ignored, None),
+                    Interaction::Panning {
+                        start,
+                        start_translation,
+                    } => {
+                        state.translation = *start_translation + (cursor_position - *start);
+                    }
+                    Interaction::DraggingNode {
+                        grab_offset,
+                        position,
+                        has_moved,
+                        ..
+                    } => {
+                        *position = diagram_position - *grab_offset;
+                        *has_moved = true;
+                    }
+                }
+                (Status::Captured, None)
+            }
+            mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                let interaction = std::mem::replace(&mut state.interaction, Interaction::None);
+                match interaction {
+                    Interaction::None => (Status::Ignored, None),
+                    Interaction::Panning { .. } => (Status::Captured, None),
+                    Interaction::DraggingNode {
+                        table_name,
+                        position,
+                        has_moved,
+                        ..
+                    } => {
+                        let message = if has_moved {
+                            SchemaDiagramMessage::NodeMoved(table_name, position.x, position.y)
+                        } else {
+                            SchemaDiagramMessage::OpenTable(table_name)
+                        };
+                        (Status::Captured, Some(message.message()))
+                    }
+                }
+            }
+            mouse::Event::WheelScrolled { delta } => {
+                let Some(cursor_position) = cursor_position else {
+                    return (Status::Ignored, None);
+                };
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+                let scale = (state.scale * (1.0 + lines * 0.1)).clamp(MIN_SCALE, MAX_SCALE);
+                // zooming keeps the point under the cursor in place
+                let diagram_position = state.to_diagram(cursor_position);
+                state.scale = scale;
+                state.translation = Vector::new(
+                    cursor_position.x - diagram_position.x * scale,
+                    cursor_position.y - diagram_position.y * scale,
+                );
+                (Status::Captured, None)
+            }
+            _ => (Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        if self.diagram_tables.is_empty() {
+            frame.fill_text(Text {
+                content: String::from("No tables"),
+                position: frame.center(),
+                color: MUTED_TEXT_COLOR,
+                size: Pixels(14.0),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                ..Text::default()
+            });
+            return vec![frame.into_geometry()];
+        }
+
+        self.draw_foreign_keys(&mut frame, state);
+        for diagram_table in self.diagram_tables {
+            self.draw_node(&mut frame, state, diagram_table);
+        }
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        match state.interaction {
+            Interaction::Panning { .. } | Interaction::DraggingNode { .. } => {
+                mouse::Interaction::Grabbing
+            }
+            Interaction::None => match cursor.position_in(bounds) {
+                Some(cursor_position)
+                    if self
+                        .node_at(state, state.to_diagram(cursor_position))
+                        .is_some() =>
+                {
+                    mouse::Interaction::Pointer
+                }
+                Some(_) => mouse::Interaction::Grab,
+                None => mouse::Interaction::default(),
+            },
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::components::ui_components::{component::Event, events::Message};
+
+#[derive(Debug, Clone)]
+pub enum SchemaDiagramMessage {
+    InitializeComponent,
+    ComponentInitialized,
+    SetSchemaDiagram,
+    RefreshSchemaDiagram,
+    AutoLayout,
+    NodeMoved(String, f32, f32),
+    OpenTable(String),
+}
+
+impl Event for SchemaDiagramMessage {
+    fn message(self) -> Message {
+        Message::SchemaDiagram(self)
+    }
+}
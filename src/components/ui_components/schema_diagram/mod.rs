@@ -0,0 +1,3 @@
+mod diagram;
+pub mod events;
+pub mod schema_diagram;
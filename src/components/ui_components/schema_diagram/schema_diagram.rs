@@ -0,0 +1,202 @@
+use crate::components::ui_components::{
+    component::{Event, UIComponent},
+    components::{ComponentsMessage, CurrentComponent},
+    events::Message,
+    schema_diagram::{diagram::DiagramCanvas, events::SchemaDiagramMessage},
+    tables::events::TablesMessage,
+};
+use crm_core::{
+    component::{BDiagramTable, BNodePosition, BusinessComponent},
+    components::BusinessSchemaDiagram,
+};
+use iced::{
+    border::Radius,
+    widget::{button, canvas, container, text, Button, Column, Row},
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct SchemaDiagramUI {
+    schema_diagram: Arc<BusinessSchemaDiagram>,
+    diagram_tables: Vec<BDiagramTable>,
+    node_positions: Vec<BNodePosition>,
+}
+
+impl UIComponent for SchemaDiagramUI {
+    type EventType = SchemaDiagramMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::InitializeComponent => {
+                let schema_diagram = self.schema_diagram.clone();
+                Task::perform(
+                    async move {
+                        schema_diagram.initialize_component().await;
+                    },
+                    |_| Self::EventType::ComponentInitialized.message(),
+                )
+            }
+            Self::EventType::ComponentInitialized => {
+                Task::done(Self::EventType::SetSchemaDiagram.message())
+            }
+            Self::EventType::SetSchemaDiagram => {
+                self.diagram_tables = self.schema_diagram.diagram_tables.blocking_lock().clone();
+                self.node_positions = self.schema_diagram.node_positions.blocking_lock().clone();
+                Task::none()
+            }
+            Self::EventType::RefreshSchemaDiagram => {
+                let schema_diagram = self.schema_diagram.clone();
+                Task::perform(
+                    async move {
+                        schema_diagram.set_schema_diagram().await;
+                    },
+                    |_| Self::EventType::SetSchemaDiagram.message(),
+                )
+            }
+            Self::EventType::AutoLayout => {
+                let schema_diagram = self.schema_diagram.clone();
+                Task::perform(
+                    async move {
+                        schema_diagram.auto_layout_nodes().await;
+                    },
+                    |_| Self::EventType::SetSchemaDiagram.message(),
+                )
+            }
+            Self::EventType::NodeMoved(table_name, x, y) => {
+                // moved right away so the node doesn't jump back while the position is saved
+                if let Some(node_position) = self
+                    .node_positions
+                    .iter_mut()
+                    .find(|node_position| node_position.table_name == table_name)
+                {
+                    node_position.x = x;
+                    node_position.y = y;
+                }
+                let schema_diagram = self.schema_diagram.clone();
+                Task::perform(
+                    async move {
+                        schema_diagram.move_node(&table_name, x, y).await;
+                    },
+                    |_| Self::EventType::SetSchemaDiagram.message(),
+                )
+            }
+            Self::EventType::OpenTable(table_name) => {
+                Task::done(ComponentsMessage::SwitchComponent(CurrentComponent::Home).message())
+                    .chain(Task::done(
+                        TablesMessage::GetSingleTableInfo(table_name).message(),
+                    ))
+            }
+        }
+    }
+}
+
+impl SchemaDiagramUI {
+    pub fn new(schema_diagram: Arc<BusinessSchemaDiagram>) -> Self {
+        Self {
+            schema_diagram,
+            diagram_tables: vec![],
+            node_positions: vec![],
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let help = "Drag tables to move them, drag the background to pan, scroll to zoom \
+                    and click a table to open it";
+        let schema_diagram_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(
+                text("Schema Diagram")
+                    .size(32)
+                    .color(Color::from_rgb(0.9, 0.9, 1.0)),
+            )
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(self.auto_layout_button())
+                    .push(self.refresh_button()),
+            )
+            .push(text(help).size(14).color(Color::from_rgb(0.6, 0.6, 0.7)))
+            .push(
+                container(
+                    canvas(DiagramCanvas::new(
+                        &self.diagram_tables,
+                        &self.node_positions,
+                    ))
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+                )
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .style(|_| diagram_container_style()),
+            );
+
+        container(schema_diagram_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn auto_layout_button(&self) -> Button<'_, Message> {
+        button("Auto Layout")
+            .style(|_, _| button_style())
+            .padding(10)
+            .on_press(SchemaDiagramMessage::AutoLayout.message())
+    }
+
+    fn refresh_button(&self) -> Button<'_, Message> {
+        button("Refresh")
+            .style(|_, _| button_style())
+            .padding(10)
+            .on_press(SchemaDiagramMessage::RefreshSchemaDiagram.message())
+    }
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn diagram_container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.08, 0.08, 0.12))),
+        border: Border {
+            color: Color::from_rgb(0.3, 0.3, 0.4),
+            width: 1.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow::default(),
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
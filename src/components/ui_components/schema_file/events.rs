@@ -0,0 +1,19 @@
+use crate::components::ui_components::{component::Event, events::Message};
+
+#[derive(Debug, Clone)]
+pub enum SchemaFileMessage {
+    UpdatePath(String),
+    ExportSchema,
+    SchemaExported(Result<(), String>),
+    LoadSchemaFile,
+    SchemaFileLoaded(Result<(), String>),
+    ApplySchemaPlan,
+    SchemaPlanApplied(Result<(), String>),
+    DiscardSchemaPlan,
+}
+
+impl Event for SchemaFileMessage {
+    fn message(self) -> Message {
+        Message::SchemaFile(self)
+    }
+}
@@ -0,0 +1,300 @@
+use crate::components::ui_components::{
+    component::{Event, UIComponent},
+    events::Message,
+    schema_file::events::SchemaFileMessage,
+};
+use crm_core::{
+    component::{BSchemaPlan, BTableIn},
+    components::BusinessSchemaFile,
+};
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    widget::{button, container, scrollable, text, text_input, Column, Row},
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+const DEFAULT_SCHEMA_FILE_PATH: &str = "schema.toml";
+
+#[derive(Debug, Clone)]
+pub struct SchemaFileUI {
+    schema_file: Arc<BusinessSchemaFile>,
+    path: String,
+    schema_plan: Option<BSchemaPlan>,
+    status: Option<Result<String, String>>,
+}
+
+impl UIComponent for SchemaFileUI {
+    type EventType = SchemaFileMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::UpdatePath(path) => {
+                self.path = path;
+                Task::none()
+            }
+            Self::EventType::ExportSchema => {
+                let schema_file = self.schema_file.clone();
+                let path = self.path.clone();
+                Task::perform(
+                    async move { schema_file.export_schema(path).await },
+                    |result| Self::EventType::SchemaExported(result).message(),
+                )
+            }
+            Self::EventType::SchemaExported(result) => {
+                self.status = Some(result.map(|_| format!("Schema exported to \"{}\"", self.path)));
+                Task::none()
+            }
+            Self::EventType::LoadSchemaFile => {
+                let schema_file = self.schema_file.clone();
+                let path = self.path.clone();
+                Task::perform(
+                    async move { schema_file.load_schema_file(path).await },
+                    |result| Self::EventType::SchemaFileLoaded(result).message(),
+                )
+            }
+            Self::EventType::SchemaFileLoaded(result) => {
+                self.schema_plan = self.schema_file.schema_plan.blocking_lock().clone();
+                self.status = match result {
+                    Ok(()) => None,
+                    Err(error) => Some(Err(error)),
+                };
+                Task::none()
+            }
+            Self::EventType::ApplySchemaPlan => {
+                let schema_file = self.schema_file.clone();
+                Task::perform(
+                    async move { schema_file.apply_schema_plan().await },
+                    |result| Self::EventType::SchemaPlanApplied(result).message(),
+                )
+            }
+            Self::EventType::SchemaPlanApplied(result) => {
+                self.schema_plan = self.schema_file.schema_plan.blocking_lock().clone();
+                self.status = Some(result.map(|_| String::from("Schema changes applied")));
+                Task::none()
+            }
+            Self::EventType::DiscardSchemaPlan => {
+                self.schema_plan = None;
+                self.status = None;
+                let schema_file = self.schema_file.clone();
+                Task::future(async move {
+                    schema_file.discard_schema_plan().await;
+                })
+                .discard()
+            }
+        }
+    }
+}
+
+impl SchemaFileUI {
+    pub fn new(schema_file: Arc<BusinessSchemaFile>) -> Self {
+        Self {
+            schema_file,
+            path: String::from(DEFAULT_SCHEMA_FILE_PATH),
+            schema_plan: None,
+            status: None,
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let mut schema_file_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(
+                text("Schema File")
+                    .size(32)
+                    .color(Color::from_rgb(0.9, 0.9, 1.0)),
+            )
+            .push(self.path_row());
+
+        if let Some(status) = &self.status {
+            schema_file_column = schema_file_column.push(match status {
+                Ok(message) => text(message.clone()).color(Color::from_rgb(0.3, 0.8, 0.4)),
+                Err(error) => text(error.clone()).color(Color::from_rgb(1.0, 0.4, 0.4)),
+            });
+        }
+
+        if let Some(schema_plan) = &self.schema_plan {
+            schema_file_column = schema_file_column
+                .push(scrollable(self.schema_plan_section(schema_plan)).height(Length::Fill));
+        }
+
+        container(schema_file_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn path_row<'a>(&'a self) -> Element<'a, Message> {
+        Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(
+                text_input("Path of the schema file (.toml)", &self.path)
+                    .on_input(|value| SchemaFileMessage::UpdatePath(value).message())
+                    .width(400)
+                    .padding(5)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(
+                button("Export Schema")
+                    .style(|_, _| button_style())
+                    .on_press_maybe(self.path_message(SchemaFileMessage::ExportSchema)),
+            )
+            .push(
+                button("Load Schema File")
+                    .style(|_, _| button_style())
+                    .on_press_maybe(self.path_message(SchemaFileMessage::LoadSchemaFile)),
+            )
+            .into()
+    }
+
+    fn path_message(&self, message: SchemaFileMessage) -> Option<Message> {
+        (!self.path.is_empty()).then(|| message.message())
+    }
+
+    fn schema_plan_section<'a>(&'a self, schema_plan: &'a BSchemaPlan) -> Element<'a, Message> {
+        let mut plan_column = Column::new().spacing(10);
+
+        if schema_plan.is_empty() {
+            plan_column = plan_column.push(text("The database already matches the schema file"));
+        }
+        for table_in in &schema_plan.create_tables {
+            plan_column = plan_column.push(plan_card(
+                format!("Create table \"{}\"", table_in.table_name),
+                create_table_lines(table_in),
+            ));
+        }
+        for table_alteration in &schema_plan.alter_tables {
+            plan_column = plan_column.push(plan_card(
+                format!("Alter table \"{}\"", table_alteration.table_name),
+                table_alteration
+                    .table_change_events
+                    .iter()
+                    .map(|table_change_event| table_change_event.to_string())
+                    .collect(),
+            ));
+        }
+        if !schema_plan.untouched_tables.is_empty() {
+            plan_column = plan_column.push(
+                text(format!(
+                    "Not in the file, left as they are: {}",
+                    schema_plan.untouched_tables.join(", ")
+                ))
+                .color(Color::from_rgb(0.6, 0.6, 0.7)),
+            );
+        }
+
+        plan_column
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        button("Apply Changes")
+                            .style(|_, _| button_style())
+                            .on_press_maybe(
+                                (!schema_plan.is_empty())
+                                    .then(|| SchemaFileMessage::ApplySchemaPlan.message()),
+                            ),
+                    )
+                    .push(
+                        button("Discard")
+                            .style(|_, _| button_style())
+                            .on_press(SchemaFileMessage::DiscardSchemaPlan.message()),
+                    ),
+            )
+            .into()
+    }
+}
+
+fn create_table_lines(table_in: &BTableIn) -> Vec<String> {
+    table_in
+        .columns
+        .iter()
+        .map(|column| {
+            let mut line = format!("{} {}", column.name, column.datatype);
+            for constraint in &column.constraints {
+                line.push_str(&format!(" {}", constraint));
+            }
+            line
+        })
+        .collect()
+}
+
+fn plan_card<'a>(title: String, lines: Vec<String>) -> Element<'a, Message> {
+    let card_column = lines.into_iter().fold(
+        Column::new()
+            .spacing(5)
+            .push(text(title).size(18).color(Color::from_rgb(0.9, 0.9, 1.0))),
+        |card_column, line| card_column.push(text(line).size(14)),
+    );
+    container(card_column)
+        .padding(10)
+        .width(Length::Fill)
+        .style(|_| plan_card_style())
+        .into()
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn plan_card_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.15, 0.15, 0.2))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow::default(),
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn text_input_style() -> text_input::Style {
+    text_input::Style {
+        background: Background::Color(Color::from_rgb(0.15, 0.15, 0.2)),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 2.0,
+            radius: Radius::from(8.0),
+        },
+        icon: Color::from_rgb(0.9, 0.9, 1.0),
+        placeholder: Color::from_rgb(0.6, 0.6, 0.7),
+        value: Color::from_rgb(0.9, 0.9, 1.0),
+        selection: Color::from_rgba(0.0, 0.7, 1.0, 0.5),
+    }
+}
@@ -0,0 +1,15 @@
+use crate::components::ui_components::{component::Event, events::Message};
+
+#[derive(Debug, Clone)]
+pub enum SearchMessage {
+    UpdateSearchQuery(String),
+    Search,
+    SetSearchResults,
+    OpenSearchResult(String, Vec<String>, Vec<String>),
+}
+
+impl Event for SearchMessage {
+    fn message(self) -> Message {
+        Message::Search(self)
+    }
+}
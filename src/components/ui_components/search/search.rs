@@ -0,0 +1,261 @@
+use crate::components::ui_components::{
+    component::{Event, UIComponent},
+    components::{ComponentsMessage, CurrentComponent},
+    events::Message,
+    search::events::SearchMessage,
+    tables::events::TablesMessage,
+};
+use crm_core::{
+    component::{BSearchResult, BSearchResultRow},
+    components::BusinessSearch,
+};
+use iced::{
+    border::Radius,
+    widget::{button, container, scrollable, text, text_input, Button, Column, Row},
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct SearchUI {
+    search: Arc<BusinessSearch>,
+    search_query: String,
+    search_results: Vec<BSearchResult>,
+    has_searched: bool,
+}
+
+impl UIComponent for SearchUI {
+    type EventType = SearchMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::UpdateSearchQuery(search_query) => {
+                self.search_query = search_query;
+                Task::none()
+            }
+            Self::EventType::Search => {
+                let search = self.search.clone();
+                let search_query = self.search_query.clone();
+                Task::perform(
+                    async move {
+                        search.search(search_query).await;
+                    },
+                    |_| Self::EventType::SetSearchResults.message(),
+                )
+            }
+            Self::EventType::SetSearchResults => {
+                self.search_results = self.search.search_results.blocking_lock().clone();
+                self.has_searched = true;
+                Task::none()
+            }
+            Self::EventType::OpenSearchResult(table_name, column_names, row) => {
+                Task::done(ComponentsMessage::SwitchComponent(CurrentComponent::Home).message())
+                    .chain(Task::done(
+                        TablesMessage::OpenTableRow(table_name, column_names, row).message(),
+                    ))
+            }
+        }
+    }
+}
+
+impl SearchUI {
+    pub fn new(search: Arc<BusinessSearch>) -> Self {
+        Self {
+            search,
+            search_query: String::new(),
+            search_results: vec![],
+            has_searched: false,
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let search_row = Row::new()
+            .spacing(10)
+            .push(
+                text_input(
+                    "Search every table for a name, email, phone...",
+                    &self.search_query,
+                )
+                .on_input(|value| SearchMessage::UpdateSearchQuery(value).message())
+                .on_submit(SearchMessage::Search.message())
+                .padding(10)
+                .width(Length::Fill)
+                .style(|_, _| text_input_style()),
+            )
+            .push(
+                button("Search")
+                    .style(|_, _| button_style())
+                    .padding(10)
+                    .on_press(SearchMessage::Search.message()),
+            );
+
+        let search_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(
+                text("Search")
+                    .size(32)
+                    .color(Color::from_rgb(0.9, 0.9, 1.0)),
+            )
+            .push(search_row)
+            .push(scrollable(self.search_results_section()).height(Length::Fill));
+
+        container(search_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn search_results_section<'a>(&'a self) -> Element<'a, Message> {
+        if self.has_searched && self.search_results.is_empty() {
+            return text("No matching rows")
+                .color(Color::from_rgb(0.6, 0.6, 0.7))
+                .into();
+        }
+        self.search_results
+            .iter()
+            .fold(
+                Column::new().spacing(20),
+                |results_column, search_result| {
+                    results_column.push(self.search_result_card(search_result))
+                },
+            )
+            .into()
+    }
+
+    fn search_result_card<'a>(&'a self, search_result: &'a BSearchResult) -> Element<'a, Message> {
+        let mut card = Column::new().spacing(8).push(
+            text(format!(
+                "{} ({} rows)",
+                search_result.table_name,
+                search_result.rows.len()
+            ))
+            .size(20)
+            .color(Color::from_rgb(0.0, 0.7, 1.0)),
+        );
+        for search_result_row in &search_result.rows {
+            card = card.push(self.search_result_row_button(search_result, search_result_row));
+        }
+        container(card)
+            .padding(15)
+            .width(Length::Fill)
+            .style(|_| result_card_style())
+            .into()
+    }
+
+    fn search_result_row_button<'a>(
+        &'a self,
+        search_result: &'a BSearchResult,
+        search_result_row: &'a BSearchResultRow,
+    ) -> Button<'a, Message> {
+        // matched columns first so the hit is visible without scrolling sideways
+        let mut cells: Vec<String> = search_result
+            .column_names
+            .iter()
+            .zip(&search_result_row.values)
+            .filter(|(column_name, _)| search_result_row.matched_column_names.contains(column_name))
+            .map(|(column_name, value)| format!("{}: {}", column_name, value))
+            .collect();
+        cells.extend(
+            search_result
+                .column_names
+                .iter()
+                .zip(&search_result_row.values)
+                .filter(|(column_name, _)| {
+                    !search_result_row.matched_column_names.contains(column_name)
+                })
+                .map(|(column_name, value)| format!("{}: {}", column_name, value)),
+        );
+        button(text(cells.join("   ")).size(14))
+            .width(Length::Fill)
+            .style(|_, _| result_row_button_style())
+            .on_press(
+                SearchMessage::OpenSearchResult(
+                    search_result.table_name.clone(),
+                    search_result.column_names.clone(),
+                    search_result_row.values.clone(),
+                )
+                .message(),
+            )
+    }
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn result_card_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.15, 0.15, 0.2))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 1.5,
+            radius: Radius::from(12.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow {
+            color: Color::from_rgba(0.0, 0.7, 1.0, 0.3),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn result_row_button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.2, 0.2, 0.25))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 0.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::from_rgb(0.9, 0.9, 1.0),
+        shadow: Shadow::default(),
+    }
+}
+
+fn text_input_style() -> text_input::Style {
+    text_input::Style {
+        background: Background::Color(Color::from_rgb(0.15, 0.15, 0.2)),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 2.0,
+            radius: Radius::from(8.0),
+        },
+        icon: Color::from_rgb(0.9, 0.9, 1.0),
+        placeholder: Color::from_rgb(0.6, 0.6, 0.7),
+        value: Color::from_rgb(0.9, 0.9, 1.0),
+        selection: Color::from_rgba(0.0, 0.7, 1.0, 0.5),
+    }
+}
@@ -1,26 +1,21 @@
-use crate::components::business_components::component::{
-    BColumn, BConstraint, BDataType, BTableGeneral, BTableIn, BusinessComponent,
-};
-use crate::components::business_components::components::BusinessTables;
 use crate::components::ui_components::{
     component::{Event, UIComponent},
     events::Message,
     tables::events::CreateTableFormMessage,
     tables::foreign_key_dropdown::{ForeignKeyDropDownUI, ForeignKeyDropdownEvents},
 };
+use crm_core::component::{BColumn, BConstraint, BDataType, BTableIn};
+use crm_core::components::BusinessTables;
 use iced::{
     alignment,
-    alignment::{Alignment, Vertical},
+    alignment::Vertical,
     border::Radius,
     widget::{
-        button, checkbox, column, container, row, scrollable, text, text_input, Button, Checkbox,
-        Column, PickList, Row, Text,
+        button, checkbox, container, row, scrollable, text, text_input, Column, PickList, Row,
     },
-    Background, Border, Color, Element, Length, Shadow, Task, Theme, Vector,
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
 };
-use std::iter::zip;
 use std::sync::Arc;
-use tokio::sync::Mutex as AsyncMutex;
 
 #[derive(Debug, Clone)]
 pub struct CreateTableFormForeignKeyDropdownEvents;
@@ -102,7 +97,7 @@ impl UIComponent for CreateTableFormUI {
                     if let Some(existing_index) = column.constraints.iter().position(|constraint| {
                         matches!(
                             constraint,
-                            BConstraint::ForeignKey(existing_table_name, existing_column_name)
+                            BConstraint::ForeignKey(_existing_table_name, _existing_column_name)
                         )
                     }) {
                         // Remove the foreign key constraint if it exists
@@ -128,7 +123,7 @@ impl UIComponent for CreateTableFormUI {
                     if let Some(existing_index) = column.constraints.iter().position(|constraint| {
                         matches!(
                             constraint,
-                            BConstraint::ForeignKey(existing_table_name, existing_column_name)
+                            BConstraint::ForeignKey(_existing_table_name, _existing_column_name)
                         )
                     }) {
                         column.constraints.remove(existing_index);
@@ -142,7 +137,7 @@ impl UIComponent for CreateTableFormUI {
                 self.create_table_input.table_name = input;
                 Task::none()
             }
-            Self::EventType::TableCreated(table_name) => {
+            Self::EventType::TableCreated(_table_name) => {
                 self.create_table_input = BTableIn::default();
                 Task::none()
             }
@@ -158,7 +153,7 @@ impl UIComponent for CreateTableFormUI {
                 )
             }
             Self::EventType::ShowOrRemoveCreateTableForm => {
-                if self.create_table_input.columns.len() == 0 {
+                if self.create_table_input.columns.is_empty() {
                     for _ in 0..1 {
                         self.create_table_input.columns.push(BColumn {
                             name: String::from("id"),
@@ -324,7 +319,7 @@ impl CreateTableFormUI {
         });
 
         // Foreign key dropdown
-        let foreign_key_dropdown = self.render_foreign_key_button(index, &column);
+        let foreign_key_dropdown = self.render_foreign_key_button(index, column);
         let remove_button = button("❌ Remove")
             .style(|_, _| delete_button_style())
             .on_press(<CreateTableFormUI as UIComponent>::EventType::message(
@@ -351,21 +346,19 @@ impl CreateTableFormUI {
         column: &BColumn,
     ) -> Element<'a, Message> {
         // Button to show the foreign key tables
-        let button_text = if let Some(foreign_key_constraint) = column
+        let button_text = if let Some((referenced_table_name, referenced_column_name)) = column
             .constraints
             .iter()
-            .find(|constraint| matches!(constraint, BConstraint::ForeignKey(_, _)))
-        {
-            if let BConstraint::ForeignKey(referenced_table_name, referenced_column_name) =
-                foreign_key_constraint
-            {
-                text(format!(
-                    "🔗 {}.{}",
-                    referenced_table_name, referenced_column_name
-                ))
-            } else {
-                text("➕ Set Foreign Key")
-            }
+            .find_map(|constraint| match constraint {
+                BConstraint::ForeignKey(referenced_table_name, referenced_column_name) => {
+                    Some((referenced_table_name, referenced_column_name))
+                }
+                _ => None,
+            }) {
+            text(format!(
+                "🔗 {}.{}",
+                referenced_table_name, referenced_column_name
+            ))
         } else {
             text("➕ Set Foreign Key")
         };
@@ -412,22 +405,6 @@ fn container_style() -> container::Style {
     }
 }
 
-fn constraints_container_style() -> container::Style {
-    container::Style {
-        background: Some(Background::Color(Color::from_rgb(0.95, 0.95, 0.95))),
-        border: Border {
-            color: Color::from_rgb(0.85, 0.85, 0.85),
-            width: 1.0,
-            radius: Radius::from(5.0),
-        },
-        text_color: Some(Color::BLACK),
-        shadow: Shadow {
-            color: Color::from_rgba(0.0, 0.0, 0.0, 0.05),
-            offset: Vector::new(0.0, 1.0),
-            blur_radius: 2.0,
-        },
-    }
-}
 fn button_style() -> button::Style {
     button::Style {
         background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
@@ -445,57 +422,6 @@ fn button_style() -> button::Style {
     }
 }
 
-fn table_button_style() -> button::Style {
-    button::Style {
-        background: Some(Background::Color(Color::from_rgb(0.2, 0.4, 0.8))), // Blue background
-        border: Border {
-            color: Color::from_rgb(0.1, 0.3, 0.6), // Darker blue border
-            width: 2.0,
-            radius: Radius::from(6.0),
-        },
-        text_color: Color::WHITE, // White text for contrast
-        shadow: Shadow {
-            color: Color::from_rgba(0.0, 0.0, 0.0, 0.5), // Slight shadow for depth
-            offset: Vector::new(0.0, 2.0),
-            blur_radius: 10.0,
-        },
-    }
-}
-
-fn column_button_style() -> button::Style {
-    button::Style {
-        background: Some(Background::Color(Color::from_rgb(0.4, 0.8, 0.2))), // Green background
-        border: Border {
-            color: Color::from_rgb(0.3, 0.6, 0.1), // Darker green border
-            width: 1.5,
-            radius: Radius::from(5.0),
-        },
-        text_color: Color::BLACK, // Black text for contrast
-        shadow: Shadow {
-            color: Color::from_rgba(0.0, 0.0, 0.0, 0.3), // Subtle shadow
-            offset: Vector::new(0.0, 1.0),
-            blur_radius: 5.0,
-        },
-    }
-}
-
-fn dropdown_style() -> container::Style {
-    container::Style {
-        background: Some(Background::Color(Color::from_rgb(0.2, 0.2, 0.2))), // Dark background
-        border: Border {
-            color: Color::from_rgb(0.0, 0.6, 0.6), // Aqua border color
-            width: 2.0,
-            radius: Radius::from(5.0),
-        },
-        text_color: Some(Color::WHITE), // White text color
-        shadow: Shadow {
-            color: Color::from_rgba(0.0, 0.0, 0.0, 0.5), // Slight shadow for depth
-            offset: Vector::new(0.0, 2.0),
-            blur_radius: 10.0,
-        },
-    }
-}
-
 fn delete_button_style() -> button::Style {
     button::Style {
         background: Some(Background::Color(Color::from_rgb(0.8, 0.2, 0.2))), // Soft red background
@@ -1,12 +1,9 @@
-use crate::components::business_components::{
-    component::{BDataType, BTableChangeEvents, BTableGeneral, BTableIn, BTableInfo},
-    components::BusinessTables,
-};
 use crate::components::ui_components::{
     component::Event,
     events::Message,
     tables::{table_data::events::TableDataMessage, table_info::events::TableInfoMessage},
 };
+use crm_core::component::{BDataType, BTableChange, BTableIn};
 
 #[derive(Debug, Clone)]
 pub enum TablesMessage {
@@ -19,6 +16,7 @@ pub enum TablesMessage {
     UndisplayTableInfo,
     SingleTableInfo(TableInfoMessage),
     SingleTableData(TableDataMessage),
+    OpenTableRow(String, Vec<String>, Vec<String>),
     CreateTableForm(CreateTableFormMessage),
     InitializeComponent,
     SetTables,
@@ -26,6 +24,14 @@ pub enum TablesMessage {
     RequestDeleteTable(String),
     ConfirmDeleteTable,
     CancelDeleteTable,
+    RequestDuplicateTable(String),
+    UpdateDuplicateTableName(String),
+    ToggleDuplicateWithData(bool),
+    ConfirmDuplicateTable,
+    CancelDuplicateTable,
+    TableDuplicated(Result<(), String>),
+    TableChanged(BTableChange),
+    TableChangeApplied,
 }
 
 impl Event for TablesMessage {
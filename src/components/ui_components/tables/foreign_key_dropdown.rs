@@ -1,24 +1,11 @@
-use crate::components::business_components::component::{
-    BColumn, BConstraint, BDataType, BTableGeneral, BTableIn, BusinessComponent,
-};
-use crate::components::ui_components::{
-    component::{Event, UIComponent},
-    events::Message,
-    tables::events::CreateTableFormMessage,
-};
+use crate::components::ui_components::events::Message;
+use crm_core::component::{BColumn, BTableGeneral};
 use iced::{
-    alignment,
-    alignment::{Alignment, Vertical},
     border::Radius,
-    widget::{
-        button, checkbox, column, container, row, scrollable, text, text_input, Button, Checkbox,
-        Column, PickList, Row, Text, TextInput,
-    },
-    Background, Border, Color, Element, Length, Shadow, Task, Theme, Vector,
+    widget::{button, container, scrollable, text, Button, Column, PickList},
+    Background, Border, Color, Element, Length, Shadow, Vector,
 };
 use std::iter::zip;
-use std::sync::{Arc, Mutex};
-use tokio::sync::Mutex as AsyncMutex;
 
 pub trait ForeignKeyDropdownEvents {
     fn add_foreign_key(
@@ -77,7 +64,7 @@ impl<T: ForeignKeyDropdownEvents> ForeignKeyDropDownUI<T> {
             .style(|_, _| table_button_style())
             .on_press(
                 self.events
-                    .toggle_foreign_key_table(self.index.clone(), table.table_name.clone()),
+                    .toggle_foreign_key_table(self.index, table.table_name.clone()),
             );
 
         if self.active_foreign_key_table_within_dropdown == Some(table.table_name.clone()) {
@@ -96,16 +83,13 @@ impl<T: ForeignKeyDropdownEvents> ForeignKeyDropDownUI<T> {
             zip(&table.column_names, &table.data_types),
             &table.is_unique,
         )
-        .filter(|((_, &ref datatype), is_unique)| *datatype == self.column.datatype && **is_unique)
+        .filter(|((_, datatype), is_unique)| **datatype == self.column.datatype && **is_unique)
         .map(|((name, _), _)| name.clone())
         .collect();
         let selected: Option<String> = None;
         PickList::new(options, selected, move |column_name| {
-            self.events.add_foreign_key(
-                self.index.clone(),
-                table.table_name.clone(),
-                column_name.clone(),
-            )
+            self.events
+                .add_foreign_key(self.index, table.table_name.clone(), column_name.clone())
         })
         .into()
     }
@@ -113,57 +97,7 @@ impl<T: ForeignKeyDropdownEvents> ForeignKeyDropDownUI<T> {
     fn remove_foreign_key_button(&self) -> Button<'_, Message> {
         button("Remove Foreign Key")
             .style(|_, _| delete_button_style())
-            .on_press(self.events.remove_foreign_key(self.index.clone()))
-    }
-}
-
-fn container_style() -> container::Style {
-    container::Style {
-        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.1))), // Background color
-        border: Border {
-            color: Color::TRANSPARENT,
-            width: 1.5,
-            radius: Radius::from(5.0),
-        },
-        text_color: Some(Color::WHITE), // Text color for the content inside the container
-        shadow: Shadow {
-            color: Color::BLACK,
-            offset: Vector::new(0.0, 2.0),
-            blur_radius: 5.0,
-        },
-    }
-}
-
-fn constraints_container_style() -> container::Style {
-    container::Style {
-        background: Some(Background::Color(Color::from_rgb(0.95, 0.95, 0.95))),
-        border: Border {
-            color: Color::from_rgb(0.85, 0.85, 0.85),
-            width: 1.0,
-            radius: Radius::from(5.0),
-        },
-        text_color: Some(Color::BLACK),
-        shadow: Shadow {
-            color: Color::from_rgba(0.0, 0.0, 0.0, 0.05),
-            offset: Vector::new(0.0, 1.0),
-            blur_radius: 2.0,
-        },
-    }
-}
-fn button_style() -> button::Style {
-    button::Style {
-        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
-        border: Border {
-            color: Color::from_rgb(0.0, 0.6, 0.5),
-            width: 2.0,
-            radius: Radius::from(5.0),
-        },
-        text_color: Color::WHITE,
-        shadow: Shadow {
-            color: Color::BLACK,
-            offset: Vector::new(0.0, 3.0),
-            blur_radius: 5.0,
-        },
+            .on_press(self.events.remove_foreign_key(self.index))
     }
 }
 
@@ -201,55 +135,6 @@ fn table_button_style() -> button::Style {
     }
 }
 
-fn column_button_style() -> button::Style {
-    button::Style {
-        background: Some(Background::Color(Color::from_rgb(0.4, 0.8, 0.2))), // Green background
-        border: Border {
-            color: Color::from_rgb(0.3, 0.6, 0.1), // Darker green border
-            width: 1.5,
-            radius: Radius::from(5.0),
-        },
-        text_color: Color::BLACK, // Black text for contrast
-        shadow: Shadow {
-            color: Color::from_rgba(0.0, 0.0, 0.0, 0.3), // Subtle shadow
-            offset: Vector::new(0.0, 1.0),
-            blur_radius: 5.0,
-        },
-    }
-}
-
-fn create_button_style() -> button::Style {
-    button::Style {
-        background: Some(Background::Color(Color::from_rgb(0.0, 0.5, 0.9))),
-        border: Border {
-            color: Color::from_rgb(0.0, 0.4, 0.7),
-            width: 2.0,
-            radius: Radius::from(8.0),
-        },
-        text_color: Color::WHITE,
-        shadow: Shadow {
-            color: Color::BLACK,
-            offset: Vector::new(0.0, 3.0),
-            blur_radius: 7.0,
-        },
-    }
-}
-
-fn text_input_style() -> text_input::Style {
-    text_input::Style {
-        background: Background::Color(Color::from_rgb(0.2, 0.2, 0.2)), // Darker input background
-        border: Border {
-            width: 1.5,
-            color: Color::from_rgb(0.0, 0.74, 0.84),
-            radius: Radius::from(5.0),
-        },
-        placeholder: Color::from_rgb(0.6, 0.6, 0.6), // Color for placeholder text
-        value: Color::WHITE,                         // Color for input text
-        selection: Color::from_rgb(0.0, 0.74, 0.84), // Color for selected text
-        icon: Color::from_rgb(0.8, 0.8, 0.8),        // Color for any input icons
-    }
-}
-
 fn dropdown_style() -> container::Style {
     container::Style {
         background: Some(Background::Color(Color::from_rgb(0.2, 0.2, 0.2))), // Dark background
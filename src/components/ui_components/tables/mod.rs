@@ -1,6 +1,6 @@
 mod create_table_form;
 pub mod events;
 mod foreign_key_dropdown;
-mod table_data;
-mod table_info;
+pub mod table_data;
+pub mod table_info;
 pub mod tables;
@@ -1,20 +1,89 @@
-use crate::components::business_components::{
-    component::{BDataType, BTableChangeEvents, BTableGeneral, BTableIn, BTableInsertedData},
-    components::BusinessTables,
-};
 use crate::components::ui_components::{
     component::Event, events::Message, tables::events::TablesMessage,
 };
+use crm_core::component::{BDataType, BFilterOperator, BSortDirection};
+use iced::widget::scrollable;
 
 #[derive(Debug, Clone)]
 pub enum TableDataMessage {
     GetTableData(String),
+    OpenTableRow(String, Vec<String>, Vec<String>),
+    ScrollToHighlightedRow,
     SetTableData,
+    SetRefreshedTableData,
+    SetColumnLayout,
     UpdateCell(usize, usize, String),
     DeleteRow(usize),
     AddRow,
     UpdateTableData,
     ResetTableData,
+    SetTableViews,
+    SelectTableView(String),
+    ClearTableView,
+    ShowOrRemoveTableViewEditor,
+    ToggleViewColumn(String),
+    MoveViewColumnUp(usize),
+    MoveViewColumnDown(usize),
+    AddViewFilter,
+    UpdateViewFilterColumn(usize, String),
+    UpdateViewFilterOperator(usize, BFilterOperator),
+    UpdateViewFilterValue(usize, String),
+    RemoveViewFilter(usize),
+    AddViewSort,
+    UpdateViewSortColumn(usize, String),
+    UpdateViewSortDirection(usize, BSortDirection),
+    RemoveViewSort(usize),
+    ApplyTableView,
+    UpdateTableViewName(String),
+    SaveTableView,
+    RenameTableView,
+    DeleteTableView,
+    SetDefaultTableView,
+    UpdateShiftPressed(bool),
+    ToggleRowSelection(usize, bool),
+    SelectAllRows(bool),
+    UpdateBulkEditColumn(String),
+    UpdateBulkEditValue(String),
+    ApplyBulkEdit,
+    DeleteSelectedRows,
+    SelectCell(usize, usize),
+    CopySelectedCells(bool),
+    PasteCells(bool),
+    PasteClipboardContent(bool, Option<String>),
+    MoveCellFocus(CellMove),
+    RevertFocusedCell,
+    AddRowAndFocus,
+    DeleteFocusedRow,
+    GridScrolled(scrollable::Viewport),
+    StartColumnResize(String),
+    ResizeColumn(f32),
+    StopColumnResize,
+    UpdateComputedColumnName(String),
+    UpdateComputedColumnDataType(BDataType),
+    UpdateComputedColumnExpression(String),
+    AddComputedColumn,
+    RemoveComputedColumn(String),
+    PromoteComputedColumn(String),
+    ComputedColumnsChanged(Result<(), String>),
+    OpenRecordForm,
+    CloseRecordForm,
+    ShowRecord(usize),
+    SetChildRecords,
+    ShowDetailTables(usize),
+    SetDetailTables,
+    UpdateDetailCell(usize, usize, usize, String),
+    AddDetailRow(usize),
+    UpdateDetailTableData(usize),
+}
+
+#[derive(Debug, Clone)]
+pub enum CellMove {
+    Up,
+    Down,
+    Left,
+    Right,
+    Next,
+    Previous,
 }
 
 impl Event for TableDataMessage {
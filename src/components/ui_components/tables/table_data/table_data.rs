@@ -1,35 +1,101 @@
-use crate::components::business_components::{
-    component::{
-        BColumn, BConstraint, BDataType, BRowColumnValue, BTableData, BTableDataChangeEvents,
-        BTableGeneral, BTableIn, BTableInsertedData, BusinessComponent,
-    },
-    components::BusinessTables,
-};
 use crate::components::ui_components::component::{Event, UIComponent};
 use crate::components::ui_components::{
-    events::Message, tables::table_data::events::TableDataMessage,
+    events::Message,
+    tables::table_data::events::{CellMove, TableDataMessage},
+};
+use crm_core::component::{
+    BAccessLevel, BChangeStatus, BChildRecords, BDataType, BDetailTableData, BFilterOperator,
+    BSortDirection, BTableData, BTableDataChangeStatus, BTableInsertedData, BTableView,
+    BTableViews, BViewFilter, BViewSort,
 };
 use iced::{
-    alignment,
-    alignment::{Alignment, Vertical},
+    alignment::Vertical,
     border::Radius,
-    futures::join,
+    clipboard, mouse,
     widget::{
-        button, checkbox, column, container, pick_list, row, scrollable, text, text_input, Button,
-        Checkbox, Column, PickList, Row, Scrollable, Text, TextInput,
+        button, checkbox, column, container, horizontal_rule, mouse_area, pick_list, rule,
+        scrollable, stack, text, text_input, Button, Column, PickList, Row, Space, TextInput,
     },
-    Background, Border, Color, Element, Length, Shadow, Task, Theme, Vector,
+    Background, Border, Color, Element, Length, Shadow, Size, Task, Vector,
 };
-use regex::Regex;
+use std::collections::{BTreeSet, HashMap};
 use std::iter::zip;
-use std::sync::{Arc, Mutex};
-use tokio::sync::Mutex as AsyncMutex;
+use std::sync::Arc;
+
+const TABLE_ROWS_SCROLLABLE_ID: &str = "table_data_rows";
+const TABLE_HEADER_SCROLLABLE_ID: &str = "table_data_header";
+const ROW_HEIGHT: f32 = 44.0;
+const HEADER_HEIGHT: f32 = 30.0;
+const SELECTION_COLUMN_WIDTH: f32 = 30.0;
+const DEFAULT_COLUMN_WIDTH: f32 = 150.0;
+const MIN_COLUMN_WIDTH: f32 = 60.0;
+const RESIZE_HANDLE_WIDTH: f32 = 6.0;
+const DELETE_ROW_BUTTON_WIDTH: f32 = 120.0;
+// rows and columns rendered beyond the viewport so fast scrolling shows no gaps
+const OVERSCAN: usize = 5;
+
+#[derive(Debug, Clone)]
+struct ColumnResize {
+    column_name: String,
+    // set by the first cursor move, a press doesn't report its position
+    start_x: Option<f32>,
+    start_width: f32,
+}
 
 #[derive(Debug, Clone)]
 pub struct TableDataUI {
     table_data: Arc<BTableData>,
+    table_views: Arc<BTableViews>,
     table_inserted_data: Option<BTableInsertedData>,
     selected_table_name: Option<String>,
+    saved_table_views: Vec<BTableView>,
+    table_view_draft: BTableView,
+    table_view_name_input: String,
+    show_table_view_editor: bool,
+    // column names and values of a row opened from a search result
+    highlighted_row: Option<(Vec<String>, Vec<String>)>,
+    table_data_conflicts: Vec<String>,
+    selected_row_indexes: BTreeSet<usize>,
+    // anchor of a shift-click range
+    last_selected_row_index: Option<usize>,
+    is_shift_pressed: bool,
+    bulk_edit_column: Option<String>,
+    bulk_edit_value: String,
+    // (row index, column index) where a cell range starts and where it ends,
+    // the focused cell is where a paste starts
+    cell_range_anchor: Option<(usize, usize)>,
+    focused_cell: Option<(usize, usize)>,
+    clipboard_error: Option<String>,
+    // until the first viewport is reported a large window is assumed
+    grid_offset: scrollable::AbsoluteOffset,
+    grid_size: Size,
+    column_widths: HashMap<String, f32>,
+    column_resize: Option<ColumnResize>,
+    table_data_change_status: BTableDataChangeStatus,
+    computed_column_name: String,
+    computed_column_data_type: BDataType,
+    computed_column_expression: String,
+    computed_column_error: Option<String>,
+    // the row shown in the form view instead of the grid
+    record_form_row_index: Option<usize>,
+    child_records: Vec<BChildRecords>,
+    // the master row whose child tables are listed under the grid
+    detail_row_index: Option<usize>,
+    detail_tables: Vec<DetailTable>,
+}
+
+// a child table as displayed, with its pending edits applied
+#[derive(Debug, Clone)]
+struct DetailTable {
+    detail_table_data: BDetailTableData,
+    table_inserted_data: BTableInsertedData,
+    change_summary: String,
+}
+
+// a grid line is either a displayed row or a loaded row queued for delete
+enum GridRow<'a> {
+    Current(usize),
+    Deleted(&'a Vec<String>),
 }
 
 impl UIComponent for TableDataUI {
@@ -48,13 +114,41 @@ impl UIComponent for TableDataUI {
             }
             Self::EventType::GetTableData(table_name) => {
                 self.selected_table_name = Some(table_name.clone());
-                let table_data = self.table_data.clone();
+                self.column_widths = HashMap::new();
+                self.highlighted_row = None;
+                let table_views = self.table_views.clone();
+                Task::perform(
+                    async move {
+                        table_views.open_table(table_name).await;
+                    },
+                    |_| Self::EventType::SetTableData.message(),
+                )
+            }
+            Self::EventType::OpenTableRow(table_name, column_names, row) => {
+                self.selected_table_name = Some(table_name.clone());
+                self.highlighted_row = Some((column_names, row));
+                let table_views = self.table_views.clone();
                 Task::perform(
                     async move {
-                        table_data.set_table_data(table_name).await;
+                        table_views.open_table_without_view(table_name).await;
                     },
                     |_| Self::EventType::SetTableData.message(),
                 )
+                .chain(Task::done(
+                    Self::EventType::ScrollToHighlightedRow.message(),
+                ))
+            }
+            Self::EventType::ScrollToHighlightedRow => {
+                if let Some(row_index) = self.highlighted_row_index() {
+                    // rows share one height, so the row's offset is known without rendering it
+                    self.grid_offset.y = self.grid_position(row_index) as f32 * ROW_HEIGHT;
+                    scrollable::scroll_to(
+                        scrollable::Id::new(TABLE_ROWS_SCROLLABLE_ID),
+                        self.grid_offset,
+                    )
+                } else {
+                    Task::none()
+                }
             }
             Self::EventType::ResetTableData => {
                 if let Some(table_name) = self.selected_table_name.as_ref() {
@@ -64,13 +158,422 @@ impl UIComponent for TableDataUI {
                 }
             }
             Self::EventType::SetTableData => {
+                self.clear_row_selection();
+                self.clear_cell_range();
+                let previous_table_name = self
+                    .table_inserted_data
+                    .as_ref()
+                    .map(|table_inserted_data| table_inserted_data.table_name.clone());
+                let previous_table_view_id = self.table_view_draft.id;
                 let updated_table_inserted_data =
                     self.table_data.table_inserted_data.blocking_lock();
 
                 self.table_inserted_data = updated_table_inserted_data.clone();
+                drop(updated_table_inserted_data);
+                self.set_table_data_change_status();
+                self.table_data_conflicts = vec![];
+                self.saved_table_views = self.table_views.table_views.blocking_lock().clone();
+                self.table_view_draft =
+                    if let Some(table_view) = self.table_data.table_view.blocking_lock().clone() {
+                        table_view
+                    } else {
+                        self.default_table_view()
+                    };
+                self.table_view_name_input = self.table_view_draft.view_name.clone();
+                // rows may have moved, the master row is selected again
+                self.detail_row_index = None;
+                self.detail_tables = vec![];
+                // another table or view starts at its first row and column
+                let is_other_table_or_view = self
+                    .table_inserted_data
+                    .as_ref()
+                    .map(|table_inserted_data| table_inserted_data.table_name.clone())
+                    != previous_table_name
+                    || self.table_view_draft.id != previous_table_view_id;
+                let scroll_task = if is_other_table_or_view {
+                    self.scroll_grid_to(scrollable::AbsoluteOffset { x: 0.0, y: 0.0 })
+                } else {
+                    Task::none()
+                };
+                Task::batch([scroll_task, self.reload_record_form()])
+            }
+            // rows saved elsewhere were reloaded, local edits are re-applied on top
+            Self::EventType::SetRefreshedTableData => {
+                self.table_inserted_data = self.table_data.get_current_table_inserted_data();
+                self.set_table_data_change_status();
+                self.table_data_conflicts =
+                    self.table_data.table_data_conflicts.blocking_lock().clone();
+                // rows may have moved, a stale selection would hit the wrong ones
+                self.clear_row_selection();
+                self.clear_cell_range();
+                self.reload_record_form()
+            }
+            // the column order or visibility changed, an unsaved view follows the layout
+            Self::EventType::SetColumnLayout => {
+                let task = self.update(Self::EventType::SetRefreshedTableData);
+                if self.table_view_draft.id.is_none() {
+                    self.table_view_draft.column_names = self.default_table_view().column_names;
+                }
+                task
+            }
+            Self::EventType::SetTableViews => {
+                self.saved_table_views = self.table_views.table_views.blocking_lock().clone();
+                // keep the draft's edits, only pick up the stored name and default flag
+                if let Some(saved_table_view) = self.saved_table_views.iter().find(|table_view| {
+                    table_view.id.is_some() && table_view.id == self.table_view_draft.id
+                }) {
+                    self.table_view_draft.view_name = saved_table_view.view_name.clone();
+                    self.table_view_draft.is_default = saved_table_view.is_default;
+                } else if self.table_view_draft.id.is_some() {
+                    self.table_view_draft = self.default_table_view();
+                }
+                self.table_view_name_input = self.table_view_draft.view_name.clone();
+                Task::none()
+            }
+            Self::EventType::SelectTableView(view_name) => {
+                if let Some(table_view) = self
+                    .saved_table_views
+                    .iter()
+                    .find(|table_view| table_view.view_name == view_name)
+                    .cloned()
+                {
+                    let table_views = self.table_views.clone();
+                    Task::perform(
+                        async move {
+                            table_views.apply_table_view(Some(table_view)).await;
+                        },
+                        |_| Self::EventType::SetTableData.message(),
+                    )
+                } else {
+                    Task::none()
+                }
+            }
+            Self::EventType::ClearTableView => {
+                let table_views = self.table_views.clone();
+                Task::perform(
+                    async move {
+                        table_views.apply_table_view(None).await;
+                    },
+                    |_| Self::EventType::SetTableData.message(),
+                )
+            }
+            Self::EventType::ShowOrRemoveTableViewEditor => {
+                self.show_table_view_editor = !self.show_table_view_editor;
+                Task::none()
+            }
+            Self::EventType::UpdateComputedColumnName(column_name) => {
+                self.computed_column_name = column_name;
+                Task::none()
+            }
+            Self::EventType::UpdateComputedColumnDataType(data_type) => {
+                self.computed_column_data_type = data_type;
+                Task::none()
+            }
+            Self::EventType::UpdateComputedColumnExpression(expression) => {
+                self.computed_column_expression = expression;
+                Task::none()
+            }
+            Self::EventType::AddComputedColumn => {
+                let table_data = self.table_data.clone();
+                let column_name = self.computed_column_name.clone();
+                let data_type = self.computed_column_data_type.clone();
+                let expression = self.computed_column_expression.clone();
+                Task::perform(
+                    async move {
+                        table_data
+                            .add_computed_column(column_name, data_type, expression)
+                            .await
+                    },
+                    |result| Self::EventType::ComputedColumnsChanged(result).message(),
+                )
+            }
+            Self::EventType::RemoveComputedColumn(column_name) => {
+                let table_data = self.table_data.clone();
+                Task::perform(
+                    async move { table_data.remove_computed_column(column_name).await },
+                    |result| Self::EventType::ComputedColumnsChanged(result).message(),
+                )
+            }
+            Self::EventType::PromoteComputedColumn(column_name) => {
+                let table_data = self.table_data.clone();
+                Task::perform(
+                    async move { table_data.promote_computed_column(column_name).await },
+                    |result| Self::EventType::ComputedColumnsChanged(result).message(),
+                )
+            }
+            Self::EventType::ComputedColumnsChanged(result) => match result {
+                Ok(()) => {
+                    self.computed_column_name = String::new();
+                    self.computed_column_expression = String::new();
+                    self.computed_column_error = None;
+                    self.update(Self::EventType::SetTableData)
+                }
+                Err(error) => {
+                    self.computed_column_error = Some(error);
+                    Task::none()
+                }
+            },
+            Self::EventType::OpenRecordForm => {
+                let row_index = self
+                    .focused_cell
+                    .map(|(row_index, _)| row_index)
+                    .or(self.selected_row_indexes.first().copied())
+                    .unwrap_or(0);
+                self.update(Self::EventType::ShowRecord(row_index))
+            }
+            Self::EventType::CloseRecordForm => {
+                self.record_form_row_index = None;
+                self.child_records = vec![];
+                Task::none()
+            }
+            Self::EventType::ShowRecord(row_index) => {
+                let Some(table_inserted_data) = self.table_inserted_data.as_ref() else {
+                    return Task::none();
+                };
+                let Some(row) = table_inserted_data.rows.get(row_index) else {
+                    return Task::none();
+                };
+                self.record_form_row_index = Some(row_index);
+                self.child_records = vec![];
+                let table_data = self.table_data.clone();
+                let column_names = table_inserted_data.column_names.clone();
+                let row_values = row.clone();
+                Task::perform(
+                    async move { table_data.set_child_records(column_names, row_values).await },
+                    |_| Self::EventType::SetChildRecords.message(),
+                )
+            }
+            Self::EventType::SetChildRecords => {
+                self.child_records = self.table_data.child_records.blocking_lock().clone();
+                Task::none()
+            }
+            Self::EventType::ShowDetailTables(row_index) => {
+                let Some(table_inserted_data) = self.table_inserted_data.as_ref() else {
+                    return Task::none();
+                };
+                let Some(row) = table_inserted_data.rows.get(row_index) else {
+                    return Task::none();
+                };
+                self.detail_row_index = Some(row_index);
+                let table_data = self.table_data.clone();
+                let column_names = table_inserted_data.column_names.clone();
+                let row_values = row.clone();
+                Task::perform(
+                    async move { table_data.set_detail_tables(column_names, row_values).await },
+                    |_| Self::EventType::SetDetailTables.message(),
+                )
+            }
+            Self::EventType::SetDetailTables => {
+                self.set_detail_tables();
+                Task::none()
+            }
+            Self::EventType::UpdateDetailCell(detail_index, row_index, col_index, new_value) => {
+                if !self.is_detail_column_writable(detail_index, col_index) {
+                    return Task::none();
+                }
+                let detail_table = &self.detail_tables[detail_index];
+                detail_table
+                    .detail_table_data
+                    .table_data
+                    .add_modify_row_column_value_event(
+                        row_index,
+                        detail_table.table_inserted_data.column_names[col_index].clone(),
+                        new_value,
+                    );
+                self.set_detail_tables();
+                Task::none()
+            }
+            Self::EventType::AddDetailRow(detail_index) => {
+                if let Some(detail_table) = self.detail_tables.get(detail_index) {
+                    detail_table.detail_table_data.add_insert_row_event();
+                }
+                self.set_detail_tables();
+                Task::none()
+            }
+            Self::EventType::UpdateDetailTableData(detail_index) => {
+                let Some(detail_table) = self.detail_tables.get(detail_index) else {
+                    return Task::none();
+                };
+                let table_data = detail_table.detail_table_data.table_data.clone();
+                Task::perform(async move { table_data.update_table_data().await }, |_| {
+                    Self::EventType::SetDetailTables.message()
+                })
+            }
+            Self::EventType::ToggleViewColumn(column_name) => {
+                let column_names = &mut self.table_view_draft.column_names;
+                if let Some(index) = column_names.iter().position(|name| *name == column_name) {
+                    column_names.remove(index);
+                } else {
+                    column_names.push(column_name);
+                }
+                Task::none()
+            }
+            Self::EventType::MoveViewColumnUp(index) => {
+                if index > 0 && index < self.table_view_draft.column_names.len() {
+                    self.table_view_draft.column_names.swap(index, index - 1);
+                }
+                Task::none()
+            }
+            Self::EventType::MoveViewColumnDown(index) => {
+                if index + 1 < self.table_view_draft.column_names.len() {
+                    self.table_view_draft.column_names.swap(index, index + 1);
+                }
+                Task::none()
+            }
+            Self::EventType::AddViewFilter => {
+                if let Some(column_name) = self.all_column_names().first() {
+                    self.table_view_draft.filters.push(BViewFilter {
+                        column_name: column_name.clone(),
+                        ..BViewFilter::default()
+                    });
+                }
+                Task::none()
+            }
+            Self::EventType::UpdateViewFilterColumn(index, column_name) => {
+                if let Some(filter) = self.table_view_draft.filters.get_mut(index) {
+                    filter.column_name = column_name;
+                }
+                Task::none()
+            }
+            Self::EventType::UpdateViewFilterOperator(index, operator) => {
+                if let Some(filter) = self.table_view_draft.filters.get_mut(index) {
+                    filter.operator = operator;
+                }
+                Task::none()
+            }
+            Self::EventType::UpdateViewFilterValue(index, value) => {
+                if let Some(filter) = self.table_view_draft.filters.get_mut(index) {
+                    filter.value = value;
+                }
+                Task::none()
+            }
+            Self::EventType::RemoveViewFilter(index) => {
+                if index < self.table_view_draft.filters.len() {
+                    self.table_view_draft.filters.remove(index);
+                }
+                Task::none()
+            }
+            Self::EventType::AddViewSort => {
+                if let Some(column_name) = self.all_column_names().first() {
+                    self.table_view_draft.sorts.push(BViewSort {
+                        column_name: column_name.clone(),
+                        ..BViewSort::default()
+                    });
+                }
+                Task::none()
+            }
+            Self::EventType::UpdateViewSortColumn(index, column_name) => {
+                if let Some(sort) = self.table_view_draft.sorts.get_mut(index) {
+                    sort.column_name = column_name;
+                }
+                Task::none()
+            }
+            Self::EventType::UpdateViewSortDirection(index, direction) => {
+                if let Some(sort) = self.table_view_draft.sorts.get_mut(index) {
+                    sort.direction = direction;
+                }
+                Task::none()
+            }
+            Self::EventType::RemoveViewSort(index) => {
+                if index < self.table_view_draft.sorts.len() {
+                    self.table_view_draft.sorts.remove(index);
+                }
+                Task::none()
+            }
+            Self::EventType::ApplyTableView => {
+                let table_views = self.table_views.clone();
+                let table_view = self.table_view_draft.clone();
+                Task::perform(
+                    async move {
+                        table_views.apply_table_view(Some(table_view)).await;
+                    },
+                    |_| Self::EventType::SetTableData.message(),
+                )
+            }
+            Self::EventType::UpdateTableViewName(view_name) => {
+                self.table_view_name_input = view_name;
                 Task::none()
             }
+            Self::EventType::SaveTableView => {
+                if self.table_view_name_input.is_empty() {
+                    return Task::none();
+                }
+                let mut table_view = self.table_view_draft.clone();
+                // saving under a new name creates a separate view
+                if table_view.view_name != self.table_view_name_input {
+                    table_view.id = None;
+                    table_view.is_default = false;
+                    table_view.view_name = self.table_view_name_input.clone();
+                }
+                let table_views = self.table_views.clone();
+                Task::perform(
+                    async move {
+                        table_views.save_table_view(table_view).await;
+                    },
+                    |_| Self::EventType::SetTableData.message(),
+                )
+            }
+            Self::EventType::RenameTableView => {
+                if let Some(table_view_id) = self.table_view_draft.id {
+                    if self.table_view_name_input.is_empty() {
+                        return Task::none();
+                    }
+                    let table_views = self.table_views.clone();
+                    let view_name = self.table_view_name_input.clone();
+                    Task::perform(
+                        async move {
+                            table_views
+                                .rename_table_view(table_view_id, view_name)
+                                .await;
+                        },
+                        |_| Self::EventType::SetTableViews.message(),
+                    )
+                } else {
+                    Task::none()
+                }
+            }
+            Self::EventType::DeleteTableView => {
+                if let Some(table_view_id) = self.table_view_draft.id {
+                    let table_views = self.table_views.clone();
+                    Task::perform(
+                        async move {
+                            table_views.delete_table_view(table_view_id).await;
+                        },
+                        |_| Self::EventType::SetTableData.message(),
+                    )
+                } else {
+                    Task::none()
+                }
+            }
+            Self::EventType::SetDefaultTableView => {
+                if let Some(table_view_id) = self.table_view_draft.id {
+                    let table_views = self.table_views.clone();
+                    // toggles, a second press clears the default
+                    let default_table_view_id = if self.table_view_draft.is_default {
+                        None
+                    } else {
+                        Some(table_view_id)
+                    };
+                    Task::perform(
+                        async move {
+                            table_views
+                                .set_default_table_view(default_table_view_id)
+                                .await;
+                        },
+                        |_| Self::EventType::SetTableViews.message(),
+                    )
+                } else {
+                    Task::none()
+                }
+            }
             Self::EventType::UpdateCell(row_index, col_index, new_value) => {
+                if !self.is_column_writable(col_index) {
+                    return Task::none();
+                }
+                if !self.is_shift_pressed {
+                    self.cell_range_anchor = Some((row_index, col_index));
+                }
+                self.focused_cell = Some((row_index, col_index));
                 if let Some(table_inserted_data) = self.table_inserted_data.as_mut() {
                     if let Some(row_data) = table_inserted_data.rows.get_mut(row_index) {
                         if let Some(cell) = row_data.get_mut(col_index) {
@@ -86,49 +589,621 @@ impl UIComponent for TableDataUI {
                         }
                     }
                 }
+                self.set_table_data_change_status();
                 Task::none()
             }
             Self::EventType::DeleteRow(row_index) => {
+                if !self.is_table_writable() {
+                    return Task::none();
+                }
+                let Some(table_inserted_data) = self.table_inserted_data.as_mut() else {
+                    return Task::none();
+                };
+                if row_index >= table_inserted_data.rows.len() {
+                    return Task::none();
+                }
+                self.table_data.add_delete_row_event(row_index);
+                table_inserted_data.rows.remove(row_index);
+                let rows_count = table_inserted_data.rows.len();
+                self.focused_cell = clamped_cell(self.focused_cell, rows_count);
+                self.cell_range_anchor = clamped_cell(self.cell_range_anchor, rows_count);
+                self.set_table_data_change_status();
+                self.clear_row_selection();
+                Task::none()
+            }
+            Self::EventType::UpdateShiftPressed(is_shift_pressed) => {
+                self.is_shift_pressed = is_shift_pressed;
+                Task::none()
+            }
+            Self::EventType::ToggleRowSelection(row_index, is_selected) => {
+                let row_indexes = match self.last_selected_row_index {
+                    Some(last_row_index) if self.is_shift_pressed => {
+                        last_row_index.min(row_index)..=last_row_index.max(row_index)
+                    }
+                    _ => row_index..=row_index,
+                };
+                for row_index in row_indexes {
+                    if is_selected {
+                        self.selected_row_indexes.insert(row_index);
+                    } else {
+                        self.selected_row_indexes.remove(&row_index);
+                    }
+                }
+                self.last_selected_row_index = Some(row_index);
+                if is_selected && !self.is_shift_pressed {
+                    self.update(Self::EventType::ShowDetailTables(row_index))
+                } else {
+                    Task::none()
+                }
+            }
+            Self::EventType::SelectAllRows(is_selected) => {
+                let rows_count = self
+                    .table_inserted_data
+                    .as_ref()
+                    .map_or(0, |table_inserted_data| table_inserted_data.rows.len());
+                self.clear_row_selection();
+                if is_selected {
+                    self.selected_row_indexes = (0..rows_count).collect();
+                }
+                Task::none()
+            }
+            Self::EventType::UpdateBulkEditColumn(column_name) => {
+                self.bulk_edit_column = Some(column_name);
+                Task::none()
+            }
+            Self::EventType::UpdateBulkEditValue(value) => {
+                self.bulk_edit_value = value;
+                Task::none()
+            }
+            Self::EventType::ApplyBulkEdit => {
+                let Some(column_name) = self.bulk_edit_column.clone() else {
+                    return Task::none();
+                };
+                let row_indexes: Vec<usize> = self.selected_row_indexes.iter().cloned().collect();
+                self.table_data.add_bulk_modify_column_value_events(
+                    &row_indexes,
+                    column_name,
+                    self.bulk_edit_value.clone(),
+                );
+                self.table_inserted_data = self.table_data.get_current_table_inserted_data();
+                self.set_table_data_change_status();
+                Task::none()
+            }
+            Self::EventType::DeleteSelectedRows => {
+                if !self.is_table_writable() {
+                    return Task::none();
+                }
+                let row_indexes: Vec<usize> = self.selected_row_indexes.iter().cloned().collect();
+                self.table_data.add_bulk_delete_row_events(&row_indexes);
+                self.table_inserted_data = self.table_data.get_current_table_inserted_data();
+                self.set_table_data_change_status();
+                self.clear_row_selection();
+                self.clear_cell_range();
+                Task::none()
+            }
+            Self::EventType::SelectCell(row_index, col_index) => {
+                if !self.is_shift_pressed || self.cell_range_anchor.is_none() {
+                    self.cell_range_anchor = Some((row_index, col_index));
+                }
+                self.focused_cell = Some((row_index, col_index));
+                self.clipboard_error = None;
+                if self.detail_row_index == Some(row_index) {
+                    return Task::none();
+                }
+                self.update(Self::EventType::ShowDetailTables(row_index))
+            }
+            // a focused input already copied its own text selection within one cell
+            Self::EventType::CopySelectedCells(is_copied_by_input) => {
+                let (row_indexes, col_indexes) = self.cell_range();
+                let is_single_cell = row_indexes.len() == 1 && col_indexes.len() == 1;
+                if row_indexes.is_empty() || (is_copied_by_input && is_single_cell) {
+                    return Task::none();
+                }
+                let rows = &self.table_inserted_data.as_ref().unwrap().rows;
+                let contents = row_indexes
+                    .iter()
+                    .map(|&row_index| {
+                        col_indexes
+                            .iter()
+                            .map(|&col_index| {
+                                rows[row_index][col_index].replace(['\t', '\n', '\r'], " ")
+                            })
+                            .collect::<Vec<String>>()
+                            .join("\t")
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                clipboard::write(contents)
+            }
+            Self::EventType::PasteCells(is_pasted_by_input) => {
+                if self.focused_cell.is_none() {
+                    return Task::none();
+                }
+                clipboard::read().map(move |contents| {
+                    TableDataMessage::PasteClipboardContent(is_pasted_by_input, contents).message()
+                })
+            }
+            Self::EventType::PasteClipboardContent(is_pasted_by_input, contents) => {
+                let (Some((row_index, col_index)), Some(contents)) = (self.focused_cell, contents)
+                else {
+                    return Task::none();
+                };
+                let pasted_rows = parse_tsv(&contents);
+                let is_single_value = pasted_rows.len() == 1 && pasted_rows[0].len() == 1;
+                if pasted_rows.is_empty() || (is_pasted_by_input && is_single_value) {
+                    return Task::none();
+                }
+                let table_inserted_data = self.table_inserted_data.clone().unwrap();
+                let visible_column_indexes =
+                    self.visible_column_indexes(&table_inserted_data.column_names);
+                let Some(first_position) = visible_column_indexes
+                    .iter()
+                    .position(|&visible_col_index| visible_col_index == col_index)
+                else {
+                    return Task::none();
+                };
+
+                // nothing is applied unless every value fits its column
+                let mut invalid_values = invalid_pasted_values(
+                    &pasted_rows,
+                    &visible_column_indexes[first_position..],
+                    &table_inserted_data.column_names,
+                    &table_inserted_data.data_types,
+                );
+                let new_rows_count =
+                    (row_index + pasted_rows.len()).saturating_sub(table_inserted_data.rows.len());
+                if new_rows_count > 0 && !self.is_table_writable() {
+                    invalid_values.push(String::from("The pasted rows don't fit into the table"));
+                }
+                if !invalid_values.is_empty() {
+                    self.clipboard_error = Some(invalid_values.join(", "));
+                    return Task::none();
+                }
+                self.clipboard_error = None;
+
+                let mut tasks = vec![];
+                for _ in 0..new_rows_count {
+                    tasks.push(self.update(TableDataMessage::AddRow));
+                }
+                for (pasted_row_index, pasted_row) in pasted_rows.into_iter().enumerate() {
+                    for (&target_col_index, value) in
+                        zip(&visible_column_indexes[first_position..], pasted_row)
+                    {
+                        tasks.push(self.update(TableDataMessage::UpdateCell(
+                            row_index + pasted_row_index,
+                            target_col_index,
+                            value,
+                        )));
+                    }
+                }
+                // the pasted block becomes the selected range
+                self.cell_range_anchor = Some((row_index, col_index));
+                Task::batch(tasks)
+            }
+            Self::EventType::MoveCellFocus(cell_move) => self.move_cell_focus(cell_move),
+            Self::EventType::GridScrolled(viewport) => {
+                self.grid_offset = viewport.absolute_offset();
+                self.grid_size = viewport.bounds().size();
+                scrollable::scroll_to(
+                    scrollable::Id::new(TABLE_HEADER_SCROLLABLE_ID),
+                    scrollable::AbsoluteOffset {
+                        x: self.grid_offset.x,
+                        y: 0.0,
+                    },
+                )
+            }
+            Self::EventType::StartColumnResize(column_name) => {
+                self.column_resize = Some(ColumnResize {
+                    start_width: self.column_width(&column_name),
+                    column_name,
+                    start_x: None,
+                });
+                Task::none()
+            }
+            Self::EventType::ResizeColumn(cursor_x) => {
+                if let Some(column_resize) = self.column_resize.as_mut() {
+                    match column_resize.start_x {
+                        Some(start_x) => {
+                            let column_width = (column_resize.start_width + cursor_x - start_x)
+                                .max(MIN_COLUMN_WIDTH);
+                            self.column_widths
+                                .insert(column_resize.column_name.clone(), column_width);
+                        }
+                        None => column_resize.start_x = Some(cursor_x),
+                    }
+                }
+                Task::none()
+            }
+            Self::EventType::StopColumnResize => {
+                self.column_resize = None;
+                Task::none()
+            }
+            Self::EventType::RevertFocusedCell => {
+                let Some((row_index, col_index)) = self.focused_cell else {
+                    return Task::none();
+                };
+                if !self.is_column_writable(col_index) {
+                    return Task::none();
+                }
                 if let Some(table_inserted_data) = self.table_inserted_data.as_mut() {
-                    self.table_data.add_delete_row_event(row_index);
-                    table_inserted_data.rows.remove(row_index);
+                    let column_name = table_inserted_data.column_names[col_index].clone();
+                    if let Some(value) = self
+                        .table_data
+                        .revert_row_column_value(row_index, &column_name)
+                    {
+                        if let Some(cell) = table_inserted_data
+                            .rows
+                            .get_mut(row_index)
+                            .and_then(|row_data| row_data.get_mut(col_index))
+                        {
+                            *cell = value;
+                        }
+                    }
                 }
+                self.set_table_data_change_status();
                 Task::none()
             }
+            Self::EventType::AddRowAndFocus => {
+                if !self.is_table_writable() {
+                    return Task::none();
+                }
+                let add_row_task = self.update(TableDataMessage::AddRow);
+                let table_inserted_data = self.table_inserted_data.as_ref().unwrap();
+                let visible_column_indexes =
+                    self.visible_column_indexes(&table_inserted_data.column_names);
+                let row_index = table_inserted_data.rows.len() - 1;
+                match self
+                    .focused_cell
+                    .map(|(_, col_index)| col_index)
+                    .or(visible_column_indexes.first().cloned())
+                {
+                    Some(col_index) => {
+                        Task::batch([add_row_task, self.focus_cell(row_index, col_index, false)])
+                    }
+                    None => add_row_task,
+                }
+            }
+            Self::EventType::DeleteFocusedRow => {
+                let Some((row_index, col_index)) = self.focused_cell else {
+                    return Task::none();
+                };
+                if !self.is_table_writable() {
+                    return Task::none();
+                }
+                let loaded_rows_count = self
+                    .table_inserted_data
+                    .as_ref()
+                    .map_or(0, |table_inserted_data| table_inserted_data.rows.len());
+                if row_index >= loaded_rows_count {
+                    self.clear_cell_range();
+                    return Task::none();
+                }
+                let delete_row_task = self.update(TableDataMessage::DeleteRow(row_index));
+                let rows_count = self
+                    .table_inserted_data
+                    .as_ref()
+                    .map_or(0, |table_inserted_data| table_inserted_data.rows.len());
+                if rows_count == 0 {
+                    self.clear_cell_range();
+                    delete_row_task
+                } else {
+                    // the row below moves up into the focused position
+                    Task::batch([
+                        delete_row_task,
+                        self.focus_cell(row_index.min(rows_count - 1), col_index, false),
+                    ])
+                }
+            }
             Self::EventType::AddRow => {
+                if !self.is_table_writable() {
+                    return Task::none();
+                }
                 if let Some(table_inserted_data) = self.table_inserted_data.as_mut() {
                     let values: Vec<String> = table_inserted_data
                         .column_names
                         .iter()
-                        .map(|col_name| String::new())
+                        .map(|_col_name| String::new())
                         .collect();
 
                     self.table_data.add_insert_row_event(values.clone());
 
-                    table_inserted_data.rows.push(values);
-                }
+                    table_inserted_data.rows.push(values);
+                }
+                self.set_table_data_change_status();
+
+                Task::none()
+            }
+        }
+    }
+}
+
+impl TableDataUI {
+    pub fn new(table_data: Arc<BTableData>, table_views: Arc<BTableViews>) -> Self {
+        Self {
+            table_data,
+            table_views,
+            table_inserted_data: None,
+            selected_table_name: None,
+            saved_table_views: vec![],
+            table_view_draft: BTableView::default(),
+            table_view_name_input: String::new(),
+            show_table_view_editor: false,
+            highlighted_row: None,
+            table_data_conflicts: vec![],
+            selected_row_indexes: BTreeSet::new(),
+            last_selected_row_index: None,
+            is_shift_pressed: false,
+            bulk_edit_column: None,
+            bulk_edit_value: String::new(),
+            cell_range_anchor: None,
+            focused_cell: None,
+            clipboard_error: None,
+            grid_offset: scrollable::AbsoluteOffset { x: 0.0, y: 0.0 },
+            grid_size: Size::new(1600.0, 900.0),
+            column_widths: HashMap::new(),
+            column_resize: None,
+            table_data_change_status: BTableDataChangeStatus::default(),
+            computed_column_name: String::new(),
+            computed_column_data_type: BDataType::TEXT,
+            computed_column_expression: String::new(),
+            computed_column_error: None,
+            record_form_row_index: None,
+            child_records: vec![],
+            detail_row_index: None,
+            detail_tables: vec![],
+        }
+    }
+
+    fn set_detail_tables(&mut self) {
+        self.detail_tables = self
+            .table_data
+            .detail_tables
+            .blocking_lock()
+            .iter()
+            .filter_map(|detail_table_data| {
+                Some(DetailTable {
+                    table_inserted_data: detail_table_data
+                        .table_data
+                        .get_current_table_inserted_data()?,
+                    change_summary: detail_table_data
+                        .table_data
+                        .get_table_data_change_status()
+                        .unwrap_or_default()
+                        .summary(),
+                    detail_table_data: detail_table_data.clone(),
+                })
+            })
+            .collect();
+    }
+
+    // the foreign key itself stays on the parent row
+    fn is_detail_column_writable(&self, detail_index: usize, col_index: usize) -> bool {
+        self.detail_tables
+            .get(detail_index)
+            .is_some_and(|detail_table| {
+                let column_name = &detail_table.table_inserted_data.column_names[col_index];
+                let table_data = &detail_table.detail_table_data.table_data;
+                *column_name
+                    != detail_table
+                        .detail_table_data
+                        .foreign_key_reference
+                        .column_name
+                    && table_data.session.column_access_level(
+                        &detail_table.table_inserted_data.table_name,
+                        column_name,
+                    ) == BAccessLevel::Write
+                    && !table_data.is_computed_column(column_name)
+                    && !table_data.is_generated_column(column_name)
+            })
+    }
+
+    fn set_table_data_change_status(&mut self) {
+        self.table_data_change_status = self
+            .table_data
+            .get_table_data_change_status()
+            .unwrap_or_default();
+    }
+
+    // rows queued for delete stay in the grid at their loaded position
+    fn grid_rows(&self, rows_count: usize) -> Vec<GridRow<'_>> {
+        let mut deleted_rows = self.table_data_change_status.deleted_rows.iter().peekable();
+        let mut grid_rows = vec![];
+        for row_index in 0..=rows_count {
+            while let Some((_, row)) =
+                deleted_rows.next_if(|(next_row_index, _)| *next_row_index <= row_index)
+            {
+                grid_rows.push(GridRow::Deleted(row));
+            }
+            if row_index < rows_count {
+                grid_rows.push(GridRow::Current(row_index));
+            }
+        }
+        grid_rows
+    }
+
+    fn grid_position(&self, row_index: usize) -> usize {
+        row_index
+            + self
+                .table_data_change_status
+                .deleted_rows
+                .iter()
+                .filter(|(next_row_index, _)| *next_row_index <= row_index)
+                .count()
+    }
+
+    pub fn is_resizing_column(&self) -> bool {
+        self.column_resize.is_some()
+    }
+
+    // moving with shift held extends the selected range from its anchor
+    fn focus_cell(
+        &mut self,
+        row_index: usize,
+        col_index: usize,
+        extend_range: bool,
+    ) -> Task<Message> {
+        if !extend_range || self.cell_range_anchor.is_none() {
+            self.cell_range_anchor = Some((row_index, col_index));
+        }
+        self.focused_cell = Some((row_index, col_index));
+        // typing into a cell reached by keyboard replaces its value
+        self.scroll_cell_into_view(row_index, col_index)
+            .chain(text_input::focus(cell_input_id(row_index, col_index)))
+            .chain(text_input::select_all(cell_input_id(row_index, col_index)))
+    }
+
+    fn move_cell_focus(&mut self, cell_move: CellMove) -> Task<Message> {
+        let Some(table_inserted_data) = self.table_inserted_data.as_ref() else {
+            return Task::none();
+        };
+        let rows_count = table_inserted_data.rows.len();
+        let visible_column_indexes = self.visible_column_indexes(&table_inserted_data.column_names);
+        if rows_count == 0 || visible_column_indexes.is_empty() {
+            return Task::none();
+        }
+        let Some((row_index, col_index)) = self.focused_cell else {
+            return self.focus_cell(0, visible_column_indexes[0], false);
+        };
+        let position = visible_column_indexes
+            .iter()
+            .position(|&visible_col_index| visible_col_index == col_index)
+            .unwrap_or(0);
+        let extend_range =
+            self.is_shift_pressed && !matches!(cell_move, CellMove::Next | CellMove::Previous);
+        let (row_index, position) = moved_cell(
+            &cell_move,
+            row_index,
+            position,
+            rows_count,
+            visible_column_indexes.len(),
+        );
+        self.focus_cell(row_index, visible_column_indexes[position], extend_range)
+    }
+
+    // the form stays on its row as far as the reloaded rows reach, its child records are reloaded
+    fn reload_record_form(&mut self) -> Task<Message> {
+        let rows_count = self
+            .table_inserted_data
+            .as_ref()
+            .map_or(0, |table_inserted_data| table_inserted_data.rows.len());
+        match self.record_form_row_index {
+            Some(_) if rows_count == 0 => self.update(TableDataMessage::CloseRecordForm),
+            Some(row_index) => {
+                self.update(TableDataMessage::ShowRecord(row_index.min(rows_count - 1)))
+            }
+            None => Task::none(),
+        }
+    }
+
+    fn clear_row_selection(&mut self) {
+        self.selected_row_indexes.clear();
+        self.last_selected_row_index = None;
+    }
+
+    fn clear_cell_range(&mut self) {
+        self.cell_range_anchor = None;
+        self.focused_cell = None;
+    }
+
+    // row indexes and column indexes in display order covered by the selected range
+    fn cell_range(&self) -> (Vec<usize>, Vec<usize>) {
+        let (
+            Some((anchor_row_index, anchor_col_index)),
+            Some((focused_row_index, focused_col_index)),
+            Some(table_inserted_data),
+        ) = (
+            self.cell_range_anchor,
+            self.focused_cell,
+            self.table_inserted_data.as_ref(),
+        )
+        else {
+            return (vec![], vec![]);
+        };
+        let visible_column_indexes = self.visible_column_indexes(&table_inserted_data.column_names);
+        let position = |col_index: usize| {
+            visible_column_indexes
+                .iter()
+                .position(|&visible_col_index| visible_col_index == col_index)
+        };
+        let (Some(anchor_position), Some(focused_position)) =
+            (position(anchor_col_index), position(focused_col_index))
+        else {
+            return (vec![], vec![]);
+        };
+        let last_row_index = table_inserted_data.rows.len().saturating_sub(1);
+        let row_indexes = (anchor_row_index.min(focused_row_index)
+            ..=anchor_row_index.max(focused_row_index).min(last_row_index))
+            .collect();
+        let col_indexes = visible_column_indexes
+            [anchor_position.min(focused_position)..=anchor_position.max(focused_position)]
+            .to_vec();
+        (row_indexes, col_indexes)
+    }
+
+    fn highlighted_row_index(&self) -> Option<usize> {
+        let (column_names, row) = self.highlighted_row.as_ref()?;
+        self.table_data
+            .find_row_index_by_key(self.table_inserted_data.as_ref()?, column_names, row)
+    }
+
+    fn all_column_names(&self) -> Vec<String> {
+        self.table_inserted_data
+            .as_ref()
+            .map(|table_inserted_data| table_inserted_data.column_names.clone())
+            .unwrap_or_default()
+    }
 
-                Task::none()
-            }
+    // an unsaved view showing every column the layout does not hide, used when no view is applied
+    fn default_table_view(&self) -> BTableView {
+        let hidden_column_names = self.table_data.hidden_column_names.blocking_lock();
+        BTableView {
+            table_name: self.get_table_name().unwrap_or_default(),
+            column_names: self
+                .all_column_names()
+                .into_iter()
+                .filter(|column_name| !hidden_column_names.contains(column_name))
+                .collect(),
+            ..BTableView::default()
         }
     }
-}
 
-impl TableDataUI {
-    pub fn new(table_data: Arc<BTableData>) -> Self {
-        Self {
-            table_data,
-            table_inserted_data: None,
-            selected_table_name: None,
-        }
+    // indexes into the loaded columns in the order the view displays them
+    fn visible_column_indexes(&self, column_names: &Vec<String>) -> Vec<usize> {
+        self.table_view_draft
+            .column_names
+            .iter()
+            .filter_map(|view_column_name| {
+                column_names
+                    .iter()
+                    .position(|column_name| column_name == view_column_name)
+            })
+            .collect()
+    }
+
+    fn is_table_writable(&self) -> bool {
+        self.get_table_name().is_some_and(|table_name| {
+            self.table_data.session.table_access_level(&table_name) == BAccessLevel::Write
+        })
+    }
+
+    fn is_column_writable(&self, col_index: usize) -> bool {
+        self.table_inserted_data
+            .as_ref()
+            .is_some_and(|table_inserted_data| {
+                let column_name = &table_inserted_data.column_names[col_index];
+                self.table_data
+                    .session
+                    .column_access_level(&table_inserted_data.table_name, column_name)
+                    == BAccessLevel::Write
+                    && !self.table_data.is_computed_column(column_name)
+                    && !self.table_data.is_generated_column(column_name)
+            })
     }
 
     pub fn get_table_name(&self) -> Option<String> {
-        if let Some(table_inserted_data) = self.table_inserted_data.as_ref() {
-            Some(table_inserted_data.table_name.clone())
-        } else {
-            None
-        }
+        self.table_inserted_data
+            .as_ref()
+            .map(|table_inserted_data| table_inserted_data.table_name.clone())
     }
     pub fn content<'a>(&'a self) -> Element<'a, Message> {
         // Combine the picklist, table content, and update button into a single column
@@ -136,8 +1211,28 @@ impl TableDataUI {
             .spacing(20)
             .push(text("Table Data Viewer").size(32).style(|_| text_style()))
             .push(self.create_picklist())
-            .push(self.create_table_content())
-            .push(self.update_table_data_button()) // Add the button at the bottom
+            .push(self.table_view_editor())
+            .push(self.table_data_conflicts_section())
+            .push(self.clipboard_error_section())
+            .push(self.bulk_edit_section())
+            .push(if self.record_form_row_index.is_some() {
+                self.record_form()
+            } else {
+                column![self.create_table_content(), self.detail_tables_section()]
+                    .spacing(20)
+                    .into()
+            })
+            .push(
+                Row::new()
+                    .spacing(20)
+                    .align_y(Vertical::Center)
+                    .push(self.update_table_data_button()) // Add the button at the bottom
+                    .push(
+                        text(self.table_data_change_status.summary())
+                            .size(16)
+                            .style(|_| text_style()),
+                    ),
+            )
             .push(self.reset_table_data_button())
             .into()
     }
@@ -150,15 +1245,407 @@ impl TableDataUI {
             .map(|info| info.table_name.clone())
             .collect();
 
-        PickList::new(
-            table_names.clone(),
-            self.selected_table_name.clone(),
-            |selected| TableDataMessage::GetTableData(selected.to_string()).message(),
+        let mut picklists = Row::new().spacing(10).align_y(Vertical::Center).push(
+            PickList::new(
+                table_names.clone(),
+                self.selected_table_name.clone(),
+                |selected| TableDataMessage::GetTableData(selected.to_string()).message(),
+            )
+            .style(|_, _| picklist_style()),
+        );
+
+        if self.table_inserted_data.is_some() {
+            let view_names: Vec<String> = self
+                .saved_table_views
+                .iter()
+                .map(|table_view| {
+                    if table_view.is_default {
+                        format!("{} ★", table_view.view_name)
+                    } else {
+                        table_view.view_name.clone()
+                    }
+                })
+                .collect();
+            let selected_view_name = self
+                .saved_table_views
+                .iter()
+                .zip(&view_names)
+                .find(|(table_view, _)| {
+                    table_view.id.is_some() && table_view.id == self.table_view_draft.id
+                })
+                .map(|(_, view_name)| view_name.clone());
+            let saved_table_views = self.saved_table_views.clone();
+
+            picklists = picklists
+                .push(
+                    PickList::new(view_names.clone(), selected_view_name, move |selected| {
+                        // map the displayed label back to the stored view name
+                        let index = view_names
+                            .iter()
+                            .position(|view_name| *view_name == selected)
+                            .unwrap();
+                        TableDataMessage::SelectTableView(
+                            saved_table_views[index].view_name.clone(),
+                        )
+                        .message()
+                    })
+                    .placeholder("Saved views")
+                    .style(|_, _| picklist_style()),
+                )
+                .push(
+                    button(text("Clear View").size(14))
+                        .on_press(TableDataMessage::ClearTableView.message())
+                        .style(|_, _| reset_table_data_button_style()),
+                )
+                .push(
+                    button(
+                        text(if self.show_table_view_editor {
+                            "Hide View Editor"
+                        } else {
+                            "Edit View"
+                        })
+                        .size(14),
+                    )
+                    .on_press(TableDataMessage::ShowOrRemoveTableViewEditor.message())
+                    .style(|_, _| reset_table_data_button_style()),
+                )
+                .push(
+                    button(
+                        text(if self.record_form_row_index.is_some() {
+                            "Back to Grid"
+                        } else {
+                            "Form View"
+                        })
+                        .size(14),
+                    )
+                    .on_press(if self.record_form_row_index.is_some() {
+                        TableDataMessage::CloseRecordForm.message()
+                    } else {
+                        TableDataMessage::OpenRecordForm.message()
+                    })
+                    .style(|_, _| reset_table_data_button_style()),
+                );
+        }
+        picklists.into()
+    }
+
+    fn table_view_editor<'a>(&'a self) -> Element<'a, Message> {
+        if !self.show_table_view_editor || self.table_inserted_data.is_none() {
+            return Column::new().into();
+        }
+        let column_names = self.all_column_names();
+
+        let mut view_actions = Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(
+                text_input("View Name", &self.table_view_name_input)
+                    .on_input(|value| TableDataMessage::UpdateTableViewName(value).message())
+                    .width(200)
+                    .padding(5)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(self.table_view_button("Save", TableDataMessage::SaveTableView))
+            .push(self.table_view_button("Apply", TableDataMessage::ApplyTableView));
+        if self.table_view_draft.id.is_some() {
+            view_actions = view_actions
+                .push(self.table_view_button("Rename", TableDataMessage::RenameTableView))
+                .push(self.table_view_button(
+                    if self.table_view_draft.is_default {
+                        "Unset Default"
+                    } else {
+                        "Set as Default"
+                    },
+                    TableDataMessage::SetDefaultTableView,
+                ))
+                .push(
+                    button(text("Delete View").size(14))
+                        .on_press(TableDataMessage::DeleteTableView.message())
+                        .style(|_, _| delete_table_row_button_style()),
+                );
+        }
+
+        // visible columns first in display order, hidden columns after
+        let mut columns_section = Column::new().spacing(5).push(text("Columns").size(18));
+        for (index, column_name) in self.table_view_draft.column_names.iter().enumerate() {
+            columns_section = columns_section.push(
+                Row::new()
+                    .spacing(5)
+                    .align_y(Vertical::Center)
+                    .push(self.view_column_checkbox(column_name, true))
+                    .push(self.table_view_button("↑", TableDataMessage::MoveViewColumnUp(index)))
+                    .push(self.table_view_button("↓", TableDataMessage::MoveViewColumnDown(index))),
+            );
+        }
+        for column_name in column_names
+            .iter()
+            .filter(|column_name| !self.table_view_draft.column_names.contains(column_name))
+        {
+            columns_section = columns_section.push(self.view_column_checkbox(column_name, false));
+        }
+
+        let mut filters_section = Column::new().spacing(5).push(text("Filters").size(18));
+        for (index, filter) in self.table_view_draft.filters.iter().enumerate() {
+            let mut filter_row = Row::new()
+                .spacing(5)
+                .align_y(Vertical::Center)
+                .push(PickList::new(
+                    column_names.clone(),
+                    Some(filter.column_name.clone()),
+                    move |column_name| {
+                        TableDataMessage::UpdateViewFilterColumn(index, column_name).message()
+                    },
+                ))
+                .push(PickList::new(
+                    vec![
+                        BFilterOperator::Equals,
+                        BFilterOperator::NotEquals,
+                        BFilterOperator::Contains,
+                        BFilterOperator::GreaterThan,
+                        BFilterOperator::LessThan,
+                        BFilterOperator::IsEmpty,
+                    ],
+                    Some(filter.operator.clone()),
+                    move |operator| {
+                        TableDataMessage::UpdateViewFilterOperator(index, operator).message()
+                    },
+                ));
+            if filter.operator != BFilterOperator::IsEmpty {
+                filter_row = filter_row.push(
+                    text_input("Value", &filter.value)
+                        .on_input(move |value| {
+                            TableDataMessage::UpdateViewFilterValue(index, value).message()
+                        })
+                        .width(150)
+                        .padding(5)
+                        .style(|_, _| text_input_style()),
+                );
+            }
+            filters_section = filters_section.push(
+                filter_row
+                    .push(self.table_view_button("✖", TableDataMessage::RemoveViewFilter(index))),
+            );
+        }
+        filters_section = filters_section
+            .push(self.table_view_button("Add Filter", TableDataMessage::AddViewFilter));
+
+        let mut sorts_section = Column::new().spacing(5).push(text("Sort").size(18));
+        for (index, sort) in self.table_view_draft.sorts.iter().enumerate() {
+            sorts_section = sorts_section.push(
+                Row::new()
+                    .spacing(5)
+                    .align_y(Vertical::Center)
+                    .push(PickList::new(
+                        column_names.clone(),
+                        Some(sort.column_name.clone()),
+                        move |column_name| {
+                            TableDataMessage::UpdateViewSortColumn(index, column_name).message()
+                        },
+                    ))
+                    .push(PickList::new(
+                        vec![BSortDirection::Ascending, BSortDirection::Descending],
+                        Some(sort.direction.clone()),
+                        move |direction| {
+                            TableDataMessage::UpdateViewSortDirection(index, direction).message()
+                        },
+                    ))
+                    .push(self.table_view_button("✖", TableDataMessage::RemoveViewSort(index))),
+            );
+        }
+        sorts_section =
+            sorts_section.push(self.table_view_button("Add Sort", TableDataMessage::AddViewSort));
+
+        container(
+            Column::new()
+                .spacing(15)
+                .push(view_actions)
+                .push(
+                    Row::new()
+                        .spacing(30)
+                        .push(columns_section)
+                        .push(filters_section)
+                        .push(sorts_section),
+                )
+                .push(self.computed_columns_section()),
         )
-        .style(|_, _| picklist_style())
+        .padding(15)
+        .style(|_| table_container_style())
         .into()
     }
 
+    // read only columns postgres evaluates from the others, they can be filtered and sorted
+    fn computed_columns_section<'a>(&'a self) -> Element<'a, Message> {
+        let mut computed_columns_section = Column::new()
+            .spacing(5)
+            .push(text("Computed Columns").size(18));
+        for computed_column in self.table_data.computed_columns.blocking_lock().iter() {
+            computed_columns_section = computed_columns_section.push(
+                Row::new()
+                    .spacing(5)
+                    .align_y(Vertical::Center)
+                    .push(text(format!(
+                        "{} {} = {}",
+                        computed_column.column_name,
+                        computed_column.data_type,
+                        computed_column.expression
+                    )))
+                    .push(self.table_view_button(
+                        "Store as Column",
+                        TableDataMessage::PromoteComputedColumn(
+                            computed_column.column_name.clone(),
+                        ),
+                    ))
+                    .push(self.table_view_button(
+                        "✖",
+                        TableDataMessage::RemoveComputedColumn(computed_column.column_name.clone()),
+                    )),
+            );
+        }
+        computed_columns_section = computed_columns_section.push(
+            Row::new()
+                .spacing(5)
+                .align_y(Vertical::Center)
+                .push(
+                    text_input("Name", &self.computed_column_name)
+                        .on_input(|value| {
+                            TableDataMessage::UpdateComputedColumnName(value).message()
+                        })
+                        .width(150)
+                        .padding(5)
+                        .style(|_, _| text_input_style()),
+                )
+                .push(PickList::new(
+                    vec![BDataType::TEXT, BDataType::INTEGER, BDataType::TIMESTAMP],
+                    Some(self.computed_column_data_type.clone()),
+                    |data_type| TableDataMessage::UpdateComputedColumnDataType(data_type).message(),
+                ))
+                .push(
+                    text_input(
+                        "Expression, e.g. amount * probability",
+                        &self.computed_column_expression,
+                    )
+                    .on_input(|value| {
+                        TableDataMessage::UpdateComputedColumnExpression(value).message()
+                    })
+                    .on_submit(TableDataMessage::AddComputedColumn.message())
+                    .width(350)
+                    .padding(5)
+                    .style(|_, _| text_input_style()),
+                )
+                .push(
+                    self.table_view_button(
+                        "Add Computed Column",
+                        TableDataMessage::AddComputedColumn,
+                    ),
+                ),
+        );
+        if let Some(computed_column_error) = &self.computed_column_error {
+            computed_columns_section = computed_columns_section
+                .push(text(computed_column_error).color(Color::from_rgb(1.0, 0.4, 0.4)));
+        }
+        computed_columns_section.into()
+    }
+
+    fn view_column_checkbox<'a>(
+        &'a self,
+        column_name: &str,
+        is_visible: bool,
+    ) -> Element<'a, Message> {
+        let column_name = column_name.to_string();
+        checkbox(column_name.clone(), is_visible)
+            .on_toggle(move |_| TableDataMessage::ToggleViewColumn(column_name.clone()).message())
+            .into()
+    }
+
+    fn table_view_button<'a>(
+        &'a self,
+        label: &'a str,
+        message: TableDataMessage,
+    ) -> Button<'a, Message> {
+        button(text(label).size(14))
+            .on_press(message.message())
+            .padding(5)
+            .style(|_, _| reset_table_data_button_style())
+    }
+
+    fn table_data_conflicts_section<'a>(&'a self) -> Element<'a, Message> {
+        self.table_data_conflicts
+            .iter()
+            .fold(Column::new().spacing(5), |conflicts_column, conflict| {
+                conflicts_column.push(text(conflict).color(Color::from_rgb(1.0, 0.4, 0.4)))
+            })
+            .into()
+    }
+
+    fn clipboard_error_section<'a>(&'a self) -> Element<'a, Message> {
+        if let Some(clipboard_error) = self.clipboard_error.as_ref() {
+            text(clipboard_error)
+                .color(Color::from_rgb(1.0, 0.4, 0.4))
+                .into()
+        } else {
+            Column::new().into()
+        }
+    }
+
+    fn bulk_edit_section<'a>(&'a self) -> Element<'a, Message> {
+        if self.selected_row_indexes.is_empty() {
+            return Row::new().into();
+        }
+        let writable_column_names: Vec<String> = self
+            .all_column_names()
+            .into_iter()
+            .enumerate()
+            .filter(|(col_index, _)| self.is_column_writable(*col_index))
+            .map(|(_, column_name)| column_name)
+            .collect();
+        Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(
+                text(format!("{} selected", self.selected_row_indexes.len()))
+                    .style(|_| text_style()),
+            )
+            .push(
+                PickList::new(
+                    writable_column_names,
+                    self.bulk_edit_column.clone(),
+                    |column_name| TableDataMessage::UpdateBulkEditColumn(column_name).message(),
+                )
+                .placeholder("Column")
+                .style(|_, _| picklist_style()),
+            )
+            .push(
+                text_input("Value", &self.bulk_edit_value)
+                    .on_input(|value| TableDataMessage::UpdateBulkEditValue(value).message())
+                    .width(200)
+                    .padding(5)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(
+                button(text("Set for Selected").size(14))
+                    .on_press_maybe(
+                        self.bulk_edit_column
+                            .is_some()
+                            .then(|| TableDataMessage::ApplyBulkEdit.message()),
+                    )
+                    .style(|_, _| reset_table_data_button_style()),
+            )
+            .push(
+                button(text("Delete Selected").size(14))
+                    .on_press_maybe(
+                        self.is_table_writable()
+                            .then(|| TableDataMessage::DeleteSelectedRows.message()),
+                    )
+                    .style(|_, _| delete_table_row_button_style()),
+            )
+            .push(
+                button(text("Clear Selection").size(14))
+                    .on_press(TableDataMessage::SelectAllRows(false).message())
+                    .style(|_, _| reset_table_data_button_style()),
+            )
+            .into()
+    }
+
     fn create_table_content<'a>(&'a self) -> Element<'a, Message> {
         if let Some(ref table_inserted_data) = self.table_inserted_data {
             let table_with_header = Column::new()
@@ -179,53 +1666,284 @@ impl TableDataUI {
         }
     }
 
+    // only the rows and columns inside the viewport get widgets, spaces of the
+    // skipped rows and columns keep the scrollable's content size
     fn table_column_names_and_rows<'a>(
         &'a self,
         column_names: &Vec<String>,
         rows: &[Vec<String>],
-    ) -> Scrollable<'a, Message> {
-        let mut table_column_names_and_rows = Column::new();
-
-        let column_names = column_names.iter().enumerate().fold(
-            Row::new().spacing(10),
-            |row, (col_index, col_name)| {
-                row.push(
-                    container(text(col_name.clone()).size(16).style(|_| text_style())).width(100), // Ensure each column takes equal space
+    ) -> Column<'a, Message> {
+        let visible_column_indexes = self.visible_column_indexes(column_names);
+        let column_widths: Vec<f32> = visible_column_indexes
+            .iter()
+            .map(|&col_index| self.column_width(&column_names[col_index]))
+            .collect();
+        let (first_position, last_position) = rendered_column_positions(
+            self.grid_offset.x - SELECTION_COLUMN_WIDTH,
+            self.grid_size.width,
+            &column_widths,
+        );
+        let left_width: f32 = column_widths[..first_position].iter().sum();
+        let right_width: f32 = column_widths[last_position..].iter().sum();
+        let rendered_column_indexes = &visible_column_indexes[first_position..last_position];
+
+        let are_all_rows_selected =
+            !rows.is_empty() && self.selected_row_indexes.len() == rows.len();
+        let header = rendered_column_indexes
+            .iter()
+            .fold(
+                Row::new()
+                    .height(HEADER_HEIGHT)
+                    .align_y(Vertical::Center)
+                    .push(
+                        container(
+                            checkbox("", are_all_rows_selected).on_toggle(|is_selected| {
+                                TableDataMessage::SelectAllRows(is_selected).message()
+                            }),
+                        )
+                        .width(SELECTION_COLUMN_WIDTH),
+                    )
+                    .push(Space::with_width(left_width)),
+                |header, &col_index| header.push(self.column_header(&column_names[col_index])),
+            )
+            // room for the rows' delete buttons and scrollbar, so both scroll equally far
+            .push(Space::with_width(
+                right_width + DELETE_ROW_BUTTON_WIDTH + 20.0,
+            ));
+
+        let grid_rows = self.grid_rows(rows.len());
+        let (first_position, last_position) =
+            rendered_row_indexes(self.grid_offset.y, self.grid_size.height, grid_rows.len());
+        let (range_row_indexes, range_col_indexes) = self.cell_range();
+        let highlighted_row_index = self.highlighted_row_index();
+        let mut table_rows =
+            Column::new().push(Space::with_height(first_position as f32 * ROW_HEIGHT));
+        for grid_row in &grid_rows[first_position..last_position] {
+            let row_index = match grid_row {
+                GridRow::Current(row_index) => *row_index,
+                GridRow::Deleted(row) => {
+                    table_rows = table_rows.push(self.deleted_table_row(
+                        row,
+                        rendered_column_indexes,
+                        left_width,
+                    ));
+                    continue;
+                }
+            };
+            let table_row = self
+                .create_table_row(
+                    &rows[row_index],
+                    row_index,
+                    rendered_column_indexes,
+                    (&range_row_indexes, &range_col_indexes),
+                )
+                .push(Space::with_width(right_width))
+                .push(self.delete_row_button(row_index));
+            let table_row = Row::new()
+                .height(ROW_HEIGHT)
+                .align_y(Vertical::Center)
+                .push(
+                    container(
+                        checkbox("", self.selected_row_indexes.contains(&row_index)).on_toggle(
+                            move |is_selected| {
+                                TableDataMessage::ToggleRowSelection(row_index, is_selected)
+                                    .message()
+                            },
+                        ),
+                    )
+                    .width(SELECTION_COLUMN_WIDTH),
+                )
+                .push(Space::with_width(left_width))
+                .push(table_row);
+            table_rows = if highlighted_row_index == Some(row_index) {
+                table_rows.push(container(table_row).style(|_| highlighted_row_style()))
+            } else {
+                table_rows.push(table_row)
+            };
+        }
+        table_rows = table_rows.push(Space::with_height(
+            (grid_rows.len() - last_position) as f32 * ROW_HEIGHT,
+        ));
+
+        // the header follows the rows horizontally but never scrolls away
+        Column::new()
+            .push(
+                scrollable(header)
+                    .id(scrollable::Id::new(TABLE_HEADER_SCROLLABLE_ID))
+                    .direction(scrollable::Direction::Horizontal(
+                        scrollable::Scrollbar::new().width(0).scroller_width(0),
+                    )),
+            )
+            .push(
+                scrollable(table_rows)
+                    .id(scrollable::Id::new(TABLE_ROWS_SCROLLABLE_ID))
+                    .direction(scrollable::Direction::Both {
+                        vertical: scrollable::Scrollbar::new(),
+                        horizontal: scrollable::Scrollbar::new(),
+                    })
+                    .on_scroll(|viewport| TableDataMessage::GridScrolled(viewport).message())
+                    .height(Length::Fill),
+            )
+    }
+
+    fn deleted_table_row<'a>(
+        &'a self,
+        row: &[String],
+        rendered_column_indexes: &[usize],
+        left_width: f32,
+    ) -> Row<'a, Message> {
+        let column_names = &self.table_inserted_data.as_ref().unwrap().column_names;
+        rendered_column_indexes.iter().fold(
+            Row::new()
+                .height(ROW_HEIGHT)
+                .align_y(Vertical::Center)
+                .push(Space::with_width(SELECTION_COLUMN_WIDTH + left_width)),
+            |deleted_row, &col_index| {
+                // text has no strike-through, a rule is laid over the value instead
+                deleted_row.push(
+                    container(stack![
+                        container(text(row[col_index].clone()).size(16).style(|_| {
+                            text::Style {
+                                color: Some(Color::from_rgb(1.0, 0.4, 0.4)),
+                            }
+                        }))
+                        .center_y(Length::Fill),
+                        container(horizontal_rule(1).style(|_| deleted_row_rule_style()))
+                            .center_y(Length::Fill),
+                    ])
+                    .width(self.column_width(&column_names[col_index]))
+                    .height(Length::Fill)
+                    .padding([0, 10])
+                    .clip(true)
+                    .style(|_| cell_style(false, &BChangeStatus::Unchanged)),
                 )
             },
-        );
-        table_column_names_and_rows = table_column_names_and_rows.push(column_names);
+        )
+    }
+
+    fn column_header<'a>(&'a self, column_name: &str) -> Row<'a, Message> {
+        let column_width = self.column_width(column_name);
+        Row::new()
+            .align_y(Vertical::Center)
+            .push(
+                container(
+                    text(column_name.to_string())
+                        .size(16)
+                        .style(|_| text_style()),
+                )
+                .width(column_width - RESIZE_HANDLE_WIDTH)
+                .padding([0, 5])
+                .clip(true),
+            )
+            .push(
+                mouse_area(
+                    container(Space::new(RESIZE_HANDLE_WIDTH, HEADER_HEIGHT))
+                        .style(|_| resize_handle_style()),
+                )
+                .on_press(TableDataMessage::StartColumnResize(column_name.to_string()).message())
+                .interaction(mouse::Interaction::ResizingHorizontally),
+            )
+    }
+
+    fn column_width(&self, column_name: &str) -> f32 {
+        self.column_widths
+            .get(column_name)
+            .cloned()
+            .unwrap_or(DEFAULT_COLUMN_WIDTH)
+    }
 
-        for (row_index, row) in rows.iter().enumerate() {
-            table_column_names_and_rows =
-                table_column_names_and_rows.push(self.create_table_row(row, row_index));
+    // scrolls just far enough for the cell to be fully visible
+    fn scroll_cell_into_view(&mut self, row_index: usize, col_index: usize) -> Task<Message> {
+        let Some(table_inserted_data) = self.table_inserted_data.as_ref() else {
+            return Task::none();
+        };
+        let mut grid_offset = self.grid_offset;
+        let row_start = self.grid_position(row_index) as f32 * ROW_HEIGHT;
+        if row_start < grid_offset.y {
+            grid_offset.y = row_start;
+        } else if row_start + ROW_HEIGHT > grid_offset.y + self.grid_size.height {
+            grid_offset.y = row_start + ROW_HEIGHT - self.grid_size.height;
+        }
+        let column_names = &table_inserted_data.column_names;
+        let mut column_start = SELECTION_COLUMN_WIDTH;
+        for visible_col_index in self.visible_column_indexes(column_names) {
+            let column_width = self.column_width(&column_names[visible_col_index]);
+            if visible_col_index == col_index {
+                if column_start < grid_offset.x {
+                    grid_offset.x = column_start;
+                } else if column_start + column_width > grid_offset.x + self.grid_size.width {
+                    grid_offset.x = column_start + column_width - self.grid_size.width;
+                }
+                break;
+            }
+            column_start += column_width;
         }
+        if grid_offset == self.grid_offset {
+            return Task::none();
+        }
+        // the offset is kept right away so the next view already has the cell's widget
+        self.scroll_grid_to(grid_offset)
+    }
 
-        scrollable(table_column_names_and_rows)
-            .direction(scrollable::Direction::Both {
-                vertical: scrollable::Scrollbar::new(),
-                horizontal: scrollable::Scrollbar::new(),
-            })
-            .height(Length::Fill)
+    fn scroll_grid_to(&mut self, grid_offset: scrollable::AbsoluteOffset) -> Task<Message> {
+        self.grid_offset = grid_offset;
+        Task::batch([
+            scrollable::scroll_to(scrollable::Id::new(TABLE_ROWS_SCROLLABLE_ID), grid_offset),
+            scrollable::scroll_to(
+                scrollable::Id::new(TABLE_HEADER_SCROLLABLE_ID),
+                scrollable::AbsoluteOffset {
+                    x: grid_offset.x,
+                    y: 0.0,
+                },
+            ),
+        ])
     }
 
-    fn create_table_row<'a>(&'a self, row: &[String], row_index: usize) -> Row<'a, Message> {
-        let mut table_row = Row::new().spacing(10).align_y(Vertical::Center);
-        for (col_index, value) in row.iter().enumerate() {
+    fn create_table_row<'a>(
+        &'a self,
+        row: &[String],
+        row_index: usize,
+        rendered_column_indexes: &[usize],
+        (range_row_indexes, range_col_indexes): (&[usize], &[usize]),
+    ) -> Row<'a, Message> {
+        let column_names = &self.table_inserted_data.as_ref().unwrap().column_names;
+        let mut table_row = Row::new().align_y(Vertical::Center);
+        for &col_index in rendered_column_indexes {
+            let value = row[col_index].as_str();
+            let is_in_range =
+                range_row_indexes.contains(&row_index) && range_col_indexes.contains(&col_index);
+            let column_width = self.column_width(&column_names[col_index]);
+            let change_status = self
+                .table_data_change_status
+                .cell_statuses
+                .get(row_index)
+                .and_then(|cell_statuses| cell_statuses.get(col_index))
+                .cloned()
+                .unwrap_or(BChangeStatus::Unchanged);
+            // text inputs capture presses, the release still reaches the mouse area
             table_row = table_row.push(
-                container(self.create_table_column_value(row_index, col_index, value.as_str()))
-                    .width(100) // Match width with header columns
-                    .align_y(Vertical::Center),
+                mouse_area(
+                    container(self.create_table_column_value(row_index, col_index, value))
+                        .width(column_width) // Match width with header columns
+                        .padding([0, 5])
+                        .align_y(Vertical::Center)
+                        .style(move |_| cell_style(is_in_range, &change_status)),
+                )
+                .on_release(TableDataMessage::SelectCell(row_index, col_index).message()),
             );
         }
-        table_row.push(self.delete_row_button(row_index))
+        table_row
     }
 
     fn delete_row_button<'a>(&'a self, row_index: usize) -> Button<'a, Message> {
         button(
             text("Delete Row").size(16).style(|_| text_style()), // Style the button text
         )
-        .on_press(<TableDataUI as UIComponent>::EventType::DeleteRow(row_index).message()) // Trigger the event
+        .width(DELETE_ROW_BUTTON_WIDTH)
+        .on_press_maybe(
+            self.is_table_writable()
+                .then(|| <TableDataUI as UIComponent>::EventType::DeleteRow(row_index).message()),
+        ) // Trigger the event
         .padding(10)
         .style(|_, _| delete_table_row_button_style()) // App
     }
@@ -234,7 +1952,10 @@ impl TableDataUI {
         button(
             text("Add Row").size(16).style(|_| text_style()), // Style the button text
         )
-        .on_press(<TableDataUI as UIComponent>::EventType::AddRow.message()) // Trigger the event
+        .on_press_maybe(
+            self.is_table_writable()
+                .then(|| <TableDataUI as UIComponent>::EventType::AddRow.message()),
+        ) // Trigger the event
         .padding(10)
         .style(|_, _| add_table_row_button_style()) // App
     }
@@ -263,15 +1984,292 @@ impl TableDataUI {
         col_index: usize,
         value: &str,
     ) -> TextInput<'a, Message> {
+        // read-only cells are rendered as disabled inputs
+        let is_column_writable = self.is_column_writable(col_index);
         text_input("", value)
-            .on_input(move |new_value| {
+            .id(cell_input_id(row_index, col_index))
+            .on_input_maybe(is_column_writable.then_some(move |new_value| {
                 <TableDataUI as UIComponent>::EventType::UpdateCell(row_index, col_index, new_value)
                     .message()
-            })
+            }))
             .padding(5)
             .style(|_, _| text_input_style())
     }
 
+    fn record_form<'a>(&'a self) -> Element<'a, Message> {
+        let (Some(table_inserted_data), Some(row_index)) = (
+            self.table_inserted_data.as_ref(),
+            self.record_form_row_index,
+        ) else {
+            return self.create_no_data_message();
+        };
+        let Some(row) = table_inserted_data.rows.get(row_index) else {
+            return self.create_no_data_message();
+        };
+        let rows_count = table_inserted_data.rows.len();
+
+        let navigation = Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(
+                button(text("◀ Previous").size(14))
+                    .on_press_maybe(
+                        (row_index > 0)
+                            .then(|| TableDataMessage::ShowRecord(row_index - 1).message()),
+                    )
+                    .style(|_, _| reset_table_data_button_style()),
+            )
+            .push(
+                text(format!("Record {} of {}", row_index + 1, rows_count))
+                    .size(16)
+                    .style(|_| text_style()),
+            )
+            .push(
+                button(text("Next ▶").size(14))
+                    .on_press_maybe(
+                        (row_index + 1 < rows_count)
+                            .then(|| TableDataMessage::ShowRecord(row_index + 1).message()),
+                    )
+                    .style(|_, _| reset_table_data_button_style()),
+            );
+
+        let fields = self
+            .visible_column_indexes(&table_inserted_data.column_names)
+            .into_iter()
+            .fold(Column::new().spacing(10), |fields, col_index| {
+                fields.push(self.record_form_field(
+                    row_index,
+                    col_index,
+                    &table_inserted_data.column_names[col_index],
+                    &table_inserted_data.data_types[col_index],
+                    &row[col_index],
+                ))
+            });
+
+        let child_records = self.child_records.iter().fold(
+            Column::new().spacing(20),
+            |child_records_section, child_records| {
+                child_records_section.push(self.child_records_table(child_records))
+            },
+        );
+
+        container(
+            scrollable(
+                Column::new()
+                    .spacing(20)
+                    .push(navigation)
+                    .push(fields)
+                    .push(child_records),
+            )
+            .height(Length::Fill),
+        )
+        .padding(20)
+        .style(|_| table_container_style())
+        .into()
+    }
+
+    fn record_form_field<'a>(
+        &'a self,
+        row_index: usize,
+        col_index: usize,
+        column_name: &str,
+        data_type: &BDataType,
+        value: &str,
+    ) -> Element<'a, Message> {
+        let is_column_writable = self.is_column_writable(col_index);
+        let field: Element<'a, Message> = match data_type {
+            BDataType::BOOLEAN => checkbox("", is_true_value(value))
+                .on_toggle_maybe(is_column_writable.then_some(move |is_checked: bool| {
+                    TableDataMessage::UpdateCell(row_index, col_index, is_checked.to_string())
+                        .message()
+                }))
+                .into(),
+            _ => {
+                let is_valid_value = data_type.is_valid_value(value);
+                text_input(&data_type_hint(data_type), value)
+                    .on_input_maybe(is_column_writable.then_some(move |new_value| {
+                        TableDataMessage::UpdateCell(row_index, col_index, new_value).message()
+                    }))
+                    .width(400)
+                    .padding(5)
+                    .style(move |_, _| {
+                        if is_valid_value {
+                            text_input_style()
+                        } else {
+                            invalid_text_input_style()
+                        }
+                    })
+                    .into()
+            }
+        };
+
+        Row::new()
+            .spacing(20)
+            .align_y(Vertical::Center)
+            .push(
+                text(format!("{} ({})", column_name, data_type))
+                    .width(250)
+                    .style(|_| text_style()),
+            )
+            .push(field)
+            .into()
+    }
+
+    fn child_records_table<'a>(&'a self, child_records: &'a BChildRecords) -> Element<'a, Message> {
+        let table_inserted_data = &child_records.table_inserted_data;
+        let header = table_inserted_data.column_names.iter().fold(
+            Row::new().spacing(5),
+            |header, column_name| {
+                header.push(
+                    text(column_name)
+                        .size(14)
+                        .width(DEFAULT_COLUMN_WIDTH)
+                        .style(|_| text_style()),
+                )
+            },
+        );
+        let rows = table_inserted_data
+            .rows
+            .iter()
+            .fold(Column::new().spacing(5), |rows, row| {
+                rows.push(row.iter().fold(Row::new().spacing(5), |cells, value| {
+                    cells.push(
+                        text(value)
+                            .width(DEFAULT_COLUMN_WIDTH)
+                            .style(|_| text_style()),
+                    )
+                }))
+            });
+
+        Column::new()
+            .spacing(5)
+            .push(
+                text(format!(
+                    "{}.{} → {} ({} rows)",
+                    child_records.foreign_key_reference.table_name,
+                    child_records.foreign_key_reference.column_name,
+                    child_records.foreign_key_reference.referenced_column,
+                    table_inserted_data.rows.len()
+                ))
+                .size(18)
+                .style(|_| text_style()),
+            )
+            .push(header)
+            .push(horizontal_rule(1))
+            .push(rows)
+            .into()
+    }
+
+    fn detail_tables_section<'a>(&'a self) -> Element<'a, Message> {
+        if self.detail_tables.is_empty() {
+            return Column::new().into();
+        }
+        let detail_tables = self.detail_tables.iter().enumerate().fold(
+            Column::new().spacing(20),
+            |detail_tables, (detail_index, detail_table)| {
+                detail_tables.push(self.detail_table(detail_index, detail_table))
+            },
+        );
+        container(scrollable(detail_tables).height(300))
+            .padding(10)
+            .style(|_| table_container_style())
+            .into()
+    }
+
+    fn detail_table<'a>(
+        &'a self,
+        detail_index: usize,
+        detail_table: &'a DetailTable,
+    ) -> Element<'a, Message> {
+        let foreign_key_reference = &detail_table.detail_table_data.foreign_key_reference;
+        let table_inserted_data = &detail_table.table_inserted_data;
+        let is_table_writable = detail_table
+            .detail_table_data
+            .table_data
+            .session
+            .table_access_level(&table_inserted_data.table_name)
+            == BAccessLevel::Write;
+
+        let title = Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(
+                text(format!(
+                    "{} where {} = {}",
+                    foreign_key_reference.table_name,
+                    foreign_key_reference.column_name,
+                    detail_table.detail_table_data.referenced_value
+                ))
+                .size(18)
+                .style(|_| text_style()),
+            )
+            .push(
+                button(text("Add Row").size(14))
+                    .on_press_maybe(
+                        is_table_writable
+                            .then(|| TableDataMessage::AddDetailRow(detail_index).message()),
+                    )
+                    .style(|_, _| add_table_row_button_style()),
+            )
+            .push(
+                button(text("Save").size(14))
+                    .on_press(TableDataMessage::UpdateDetailTableData(detail_index).message())
+                    .style(|_, _| update_table_data_button_style()),
+            )
+            .push(
+                text(&detail_table.change_summary)
+                    .size(14)
+                    .style(|_| text_style()),
+            );
+
+        let header = table_inserted_data.column_names.iter().fold(
+            Row::new().spacing(5),
+            |header, column_name| {
+                header.push(
+                    text(column_name)
+                        .size(14)
+                        .width(DEFAULT_COLUMN_WIDTH)
+                        .style(|_| text_style()),
+                )
+            },
+        );
+        let rows = table_inserted_data.rows.iter().enumerate().fold(
+            Column::new().spacing(5),
+            |rows, (row_index, row)| {
+                rows.push(row.iter().enumerate().fold(
+                    Row::new().spacing(5),
+                    |cells, (col_index, value)| {
+                        let is_column_writable =
+                            self.is_detail_column_writable(detail_index, col_index);
+                        cells.push(
+                            text_input("", value)
+                                .on_input_maybe(is_column_writable.then_some(move |new_value| {
+                                    TableDataMessage::UpdateDetailCell(
+                                        detail_index,
+                                        row_index,
+                                        col_index,
+                                        new_value,
+                                    )
+                                    .message()
+                                }))
+                                .width(DEFAULT_COLUMN_WIDTH)
+                                .padding(5)
+                                .style(|_, _| text_input_style()),
+                        )
+                    },
+                ))
+            },
+        );
+
+        Column::new()
+            .spacing(5)
+            .push(title)
+            .push(header)
+            .push(horizontal_rule(1))
+            .push(rows)
+            .into()
+    }
+
     fn create_no_data_message<'a>(&'a self) -> Element<'a, Message> {
         container(text("Select a table").size(16).style(|_| text_style()))
             .padding(20)
@@ -279,6 +2277,114 @@ impl TableDataUI {
     }
 }
 
+fn cell_input_id(row_index: usize, col_index: usize) -> text_input::Id {
+    text_input::Id::new(format!("table_data_cell_{}_{}", row_index, col_index))
+}
+
+// positions into the displayed columns, the end is exclusive; the viewport starts
+// after the selection column
+fn rendered_column_positions(
+    viewport_start: f32,
+    viewport_width: f32,
+    column_widths: &[f32],
+) -> (usize, usize) {
+    let viewport_end = viewport_start + viewport_width;
+    let mut column_start = 0.0;
+    let mut first_position = column_widths.len();
+    let mut last_position = column_widths.len();
+    for (position, column_width) in column_widths.iter().enumerate() {
+        let column_end = column_start + column_width;
+        if first_position == column_widths.len() && column_end > viewport_start {
+            first_position = position;
+        }
+        if column_start >= viewport_end {
+            last_position = position;
+            break;
+        }
+        column_start = column_end;
+    }
+    (
+        first_position.saturating_sub(OVERSCAN),
+        (last_position + OVERSCAN).min(column_widths.len()),
+    )
+}
+
+fn rendered_row_indexes(offset_y: f32, viewport_height: f32, rows_count: usize) -> (usize, usize) {
+    let first_row_index = (offset_y / ROW_HEIGHT) as usize;
+    let last_row_index = ((offset_y + viewport_height) / ROW_HEIGHT).ceil() as usize;
+    (
+        first_row_index.saturating_sub(OVERSCAN).min(rows_count),
+        (last_row_index + OVERSCAN).min(rows_count),
+    )
+}
+
+// a cell past the last row, e.g. after a delete, moves up to the last row
+fn clamped_cell(cell: Option<(usize, usize)>, rows_count: usize) -> Option<(usize, usize)> {
+    let (row_index, col_index) = cell?;
+    (rows_count > 0).then(|| (row_index.min(rows_count - 1), col_index))
+}
+
+// the row index and column position a move reaches within a non empty grid,
+// Tab and Shift+Tab wrap around to the neighbouring row
+fn moved_cell(
+    cell_move: &CellMove,
+    row_index: usize,
+    position: usize,
+    rows_count: usize,
+    columns_count: usize,
+) -> (usize, usize) {
+    let row_index = row_index.min(rows_count - 1);
+    let last_position = columns_count - 1;
+    match cell_move {
+        CellMove::Up => (row_index.saturating_sub(1), position),
+        CellMove::Down => ((row_index + 1).min(rows_count - 1), position),
+        CellMove::Left => (row_index, position.saturating_sub(1)),
+        CellMove::Right => (row_index, (position + 1).min(last_position)),
+        CellMove::Next if position < last_position => (row_index, position + 1),
+        CellMove::Next if row_index + 1 < rows_count => (row_index + 1, 0),
+        CellMove::Previous if position > 0 => (row_index, position - 1),
+        CellMove::Previous if row_index > 0 => (row_index - 1, last_position),
+        CellMove::Next | CellMove::Previous => (row_index, position),
+    }
+}
+
+// rows by line and cells by tab, as spreadsheets put them on the clipboard
+fn parse_tsv(contents: &str) -> Vec<Vec<String>> {
+    contents
+        .trim_end_matches(['\r', '\n'])
+        .split('\n')
+        .map(|line| {
+            line.trim_end_matches('\r')
+                .split('\t')
+                .map(String::from)
+                .collect()
+        })
+        .filter(|row: &Vec<String>| !(row.len() == 1 && row[0].is_empty()))
+        .collect()
+}
+
+// pasted rows start at the first target column, values beyond the last one are dropped
+fn invalid_pasted_values(
+    pasted_rows: &Vec<Vec<String>>,
+    target_col_indexes: &[usize],
+    column_names: &Vec<String>,
+    data_types: &Vec<BDataType>,
+) -> Vec<String> {
+    let mut invalid_values = vec![];
+    for pasted_row in pasted_rows {
+        for (&target_col_index, value) in zip(target_col_indexes, pasted_row) {
+            let data_type = &data_types[target_col_index];
+            if !data_type.is_valid_value(value) {
+                invalid_values.push(format!(
+                    "\"{}\" is not a valid {} for {}",
+                    value, data_type, column_names[target_col_index]
+                ));
+            }
+        }
+    }
+    invalid_values
+}
+
 // Style function for the table container using ::Style
 fn table_container_style() -> container::Style {
     container::Style {
@@ -298,6 +2404,54 @@ fn table_container_style() -> container::Style {
 }
 
 // Style for picklist dropdowns
+fn highlighted_row_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgba(0.0, 0.7, 1.0, 0.25))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 1.0,
+            radius: Radius::from(5.0),
+        },
+        ..container::Style::default()
+    }
+}
+
+// pending changes tint the cell, a selected range is drawn on top
+fn cell_style(is_in_range: bool, change_status: &BChangeStatus) -> container::Style {
+    if is_in_range {
+        return highlighted_row_style();
+    }
+    let background_color = match change_status {
+        BChangeStatus::Unchanged => return container::Style::default(),
+        BChangeStatus::Modified => Color::from_rgba(1.0, 0.75, 0.0, 0.25),
+        BChangeStatus::Inserted => Color::from_rgba(0.0, 0.9, 0.4, 0.2),
+    };
+    container::Style {
+        background: Some(Background::Color(background_color)),
+        border: Border {
+            radius: Radius::from(5.0),
+            ..Border::default()
+        },
+        ..container::Style::default()
+    }
+}
+
+fn deleted_row_rule_style() -> rule::Style {
+    rule::Style {
+        color: Color::from_rgb(1.0, 0.4, 0.4),
+        width: 1,
+        radius: Radius::from(0.0),
+        fill_mode: rule::FillMode::Full,
+    }
+}
+
+fn resize_handle_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgba(0.0, 0.7, 1.0, 0.4))),
+        ..container::Style::default()
+    }
+}
+
 fn picklist_style() -> pick_list::Style {
     pick_list::Style {
         text_color: Color::from_rgb(0.9, 0.9, 1.0), // Neon text
@@ -388,6 +2542,34 @@ fn text_style() -> text::Style {
     }
 }
 
+fn is_true_value(value: &str) -> bool {
+    matches!(
+        value.trim().to_lowercase().as_str(),
+        "true" | "t" | "yes" | "y" | "on" | "1"
+    )
+}
+
+// what a value of the type looks like, shown while the field is empty
+fn data_type_hint(data_type: &BDataType) -> String {
+    match data_type {
+        BDataType::TEXT => String::from("Text"),
+        BDataType::INTEGER | BDataType::SERIAL => String::from("Number, e.g. 42"),
+        BDataType::TIMESTAMP => String::from("YYYY-MM-DD HH:MM:SS"),
+        BDataType::BOOLEAN => String::from("true or false"),
+    }
+}
+
+fn invalid_text_input_style() -> text_input::Style {
+    text_input::Style {
+        border: Border {
+            color: Color::from_rgb(1.0, 0.3, 0.3), // Red border for values the column rejects
+            width: 2.0,
+            radius: Radius::from(8.0),
+        },
+        ..text_input_style()
+    }
+}
+
 fn text_input_style() -> text_input::Style {
     text_input::Style {
         background: Background::Color(Color::from_rgb(0.15, 0.15, 0.2)), // Dark background
@@ -402,3 +2584,110 @@ fn text_input_style() -> text_input::Style {
         selection: Color::from_rgba(0.0, 0.7, 1.0, 0.5), // Highlighted selection color
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rendered_row_indexes() {
+        // 10 rows fill the viewport, the overscan is added on both sides
+        assert_eq!(rendered_row_indexes(0.0, 10.0 * ROW_HEIGHT, 1000), (0, 15));
+        assert_eq!(
+            rendered_row_indexes(100.0 * ROW_HEIGHT, 10.0 * ROW_HEIGHT, 1000),
+            (95, 115)
+        );
+        assert_eq!(
+            rendered_row_indexes(995.0 * ROW_HEIGHT, 10.0 * ROW_HEIGHT, 1000),
+            (990, 1000)
+        );
+        // a partly scrolled row is still rendered
+        assert_eq!(
+            rendered_row_indexes(10.5 * ROW_HEIGHT, 10.0 * ROW_HEIGHT, 1000),
+            (5, 26)
+        );
+        assert_eq!(rendered_row_indexes(0.0, 10.0 * ROW_HEIGHT, 0), (0, 0));
+    }
+
+    #[test]
+    fn test_rendered_column_positions() {
+        let column_widths = vec![100.0; 20];
+        assert_eq!(
+            rendered_column_positions(0.0, 250.0, &column_widths),
+            (0, 8)
+        );
+        assert_eq!(
+            rendered_column_positions(1050.0, 250.0, &column_widths),
+            (5, 18)
+        );
+        assert_eq!(
+            rendered_column_positions(1900.0, 250.0, &column_widths),
+            (14, 20)
+        );
+        assert_eq!(rendered_column_positions(0.0, 250.0, &[]), (0, 0));
+    }
+
+    #[test]
+    fn test_clamped_cell() {
+        assert_eq!(clamped_cell(Some((1, 2)), 3), Some((1, 2)));
+        // a focus left on a deleted last row
+        assert_eq!(clamped_cell(Some((3, 2)), 3), Some((2, 2)));
+        assert_eq!(clamped_cell(Some((0, 2)), 0), None);
+        assert_eq!(clamped_cell(None, 3), None);
+    }
+
+    #[test]
+    fn test_moved_cell() {
+        // a grid of 3 rows and 2 columns
+        assert_eq!(moved_cell(&CellMove::Up, 0, 1, 3, 2), (0, 1));
+        assert_eq!(moved_cell(&CellMove::Down, 1, 1, 3, 2), (2, 1));
+        assert_eq!(moved_cell(&CellMove::Down, 2, 1, 3, 2), (2, 1));
+        assert_eq!(moved_cell(&CellMove::Left, 1, 0, 3, 2), (1, 0));
+        assert_eq!(moved_cell(&CellMove::Right, 1, 1, 3, 2), (1, 1));
+        assert_eq!(moved_cell(&CellMove::Next, 0, 0, 3, 2), (0, 1));
+        assert_eq!(moved_cell(&CellMove::Next, 0, 1, 3, 2), (1, 0));
+        assert_eq!(moved_cell(&CellMove::Next, 2, 1, 3, 2), (2, 1));
+        assert_eq!(moved_cell(&CellMove::Previous, 1, 0, 3, 2), (0, 1));
+        assert_eq!(moved_cell(&CellMove::Previous, 0, 0, 3, 2), (0, 0));
+        // a focused row beyond reloaded rows moves from the last row
+        assert_eq!(moved_cell(&CellMove::Up, 7, 0, 3, 2), (1, 0));
+    }
+
+    #[test]
+    fn test_parse_tsv() {
+        assert_eq!(
+            parse_tsv("1\tJohn\r\n2\t\r\n"),
+            vec![
+                vec![String::from("1"), String::from("John")],
+                vec![String::from("2"), String::new()],
+            ]
+        );
+        // blank lines are skipped, a single cell is one row of one value
+        assert_eq!(
+            parse_tsv("John\n\nAlice"),
+            vec![vec![String::from("John")], vec![String::from("Alice")]]
+        );
+        assert_eq!(parse_tsv("John"), vec![vec![String::from("John")]]);
+        assert!(parse_tsv("\r\n").is_empty());
+    }
+
+    #[test]
+    fn test_invalid_pasted_values() {
+        let column_names = vec![
+            String::from("id"),
+            String::from("name"),
+            String::from("active"),
+        ];
+        let data_types = vec![BDataType::INTEGER, BDataType::TEXT, BDataType::BOOLEAN];
+        let pasted_rows = parse_tsv("John\tyes\tignored\nAlice\tmaybe");
+        assert_eq!(
+            invalid_pasted_values(&pasted_rows, &[1, 2], &column_names, &data_types),
+            vec![String::from("\"maybe\" is not a valid BOOLEAN for active")]
+        );
+        let pasted_rows = parse_tsv("1\tJohn\nx\tAlice");
+        assert_eq!(
+            invalid_pasted_values(&pasted_rows, &[0, 1, 2], &column_names, &data_types),
+            vec![String::from("\"x\" is not a valid INTEGER for id")]
+        );
+    }
+}
@@ -1,9 +1,7 @@
-use crate::components::business_components::component::{
-    BDataType, BTableChangeEvents, BTableGeneral, BTableIn, BTableInfo,
-};
 use crate::components::ui_components::{
     component::Event, events::Message, tables::events::TablesMessage,
 };
+use crm_core::component::{BDataType, BTableChangeEvents};
 
 #[derive(Debug, Clone)]
 pub enum TableInfoMessage {
@@ -23,6 +21,16 @@ pub enum TableInfoMessage {
     SetOrRemovePrimaryKey(usize),
     AddTableChangeEvent(BTableChangeEvents),
     TableChangeEventDone,
+    PreviewConversions,
+    ConversionsPreviewed(Result<(), String>),
+    UpdateConversionExpression(usize, String),
+    UpdateConversionDefault(usize, String),
+    CancelConversions,
+    StartColumnDrag(usize),
+    HoverColumnDrag(usize),
+    DropColumn,
+    ToggleColumnHidden(usize, bool),
+    ColumnLayoutSaved(Result<(), String>),
 }
 
 impl Event for TableInfoMessage {
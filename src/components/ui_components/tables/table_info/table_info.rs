@@ -1,11 +1,3 @@
-use crate::components::business_components::{
-    component::{
-        BColumn, BColumnForeignKey, BConstraint, BDataType, BTableChangeEvents, BTableGeneral,
-        BTableIn, BTableInfo, BusinessComponent,
-    },
-    components::BusinessTables,
-};
-use crate::components::ui_components::console::events::ConsoleMessage;
 use crate::components::ui_components::{
     component::{Event, UIComponent},
     events::Message,
@@ -13,20 +5,21 @@ use crate::components::ui_components::{
     tables::table_data::events::TableDataMessage,
     tables::table_info::events::TableInfoMessage,
 };
+use crm_core::component::{
+    BColumn, BColumnForeignKey, BConstraint, BConversionPreview, BDataType, BTableChangeEvents,
+    BTableInfo,
+};
 use iced::{
     alignment::Vertical,
-    border,
     border::Radius,
-    font::Font,
+    mouse,
     widget::{
-        button, checkbox, column, container, row, scrollable, text, text_input, Button, Column,
-        PickList, Row, Scrollable, Text, TextInput,
+        button, checkbox, container, mouse_area, scrollable, text, text_input, Button, Column,
+        PickList, Row, TextInput,
     },
-    Alignment, Background, Border, Color, Element, Length, Shadow, Task, Theme, Vector,
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
 };
-use std::iter::zip;
 use std::sync::Arc;
-use tokio::sync::Mutex as AsyncMutex;
 
 #[derive(Debug, Clone)]
 pub struct TableInfoForeignKeyDropdown;
@@ -49,12 +42,26 @@ impl ForeignKeyDropdownEvents for TableInfoForeignKeyDropdown {
     }
 }
 
+#[derive(Debug, Clone)]
+struct ColumnDrag {
+    dragged_index: usize,
+    // the row under the cursor, the dragged column is dropped there
+    target_index: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct TableInfoUI {
     table_info: Arc<BTableInfo>,
     table_name_display: String,
     columns_display: Vec<BColumn>,
     active_foreign_key_dropdown: Option<ForeignKeyDropDownUI<TableInfoForeignKeyDropdown>>,
+    // shown before the update when column types change
+    conversion_previews: Option<Vec<BConversionPreview>>,
+    conversions_checked: bool,
+    conversion_error: Option<String>,
+    column_drag: Option<ColumnDrag>,
+    hidden_column_names: Vec<String>,
+    column_layout_error: Option<String>,
 }
 
 impl UIComponent for TableInfoUI {
@@ -129,6 +136,7 @@ impl UIComponent for TableInfoUI {
                 )
             }
             Self::EventType::SubmitUpdateTable => {
+                self.conversion_previews = None;
                 let table_info = self.table_info.clone();
                 Task::perform(
                     async move {
@@ -140,6 +148,7 @@ impl UIComponent for TableInfoUI {
             }
             Self::EventType::UpdateTableInfoUI => {
                 self.columns_display = self.table_info.columns_info.blocking_lock().clone();
+                self.set_hidden_column_names();
                 self.table_name_display = self
                     .table_info
                     .table_name
@@ -151,6 +160,7 @@ impl UIComponent for TableInfoUI {
             }
             Self::EventType::ResetTableInfo => {
                 self.active_foreign_key_dropdown = None;
+                self.conversion_previews = None;
                 let table_info = self.table_info.clone();
                 let table_name = self.table_name_display.clone();
                 Task::perform(
@@ -169,6 +179,7 @@ impl UIComponent for TableInfoUI {
                     .unwrap()
                     .clone();
                 self.columns_display = self.table_info.columns_info.blocking_lock().clone();
+                self.set_hidden_column_names();
                 Task::none()
             }
             Self::EventType::AddForeignKey(
@@ -180,7 +191,7 @@ impl UIComponent for TableInfoUI {
                     if let Some(existing_index) = column.constraints.iter().position(|constraint| {
                         matches!(
                             constraint,
-                            BConstraint::ForeignKey(existing_table_name, existing_column_name)
+                            BConstraint::ForeignKey(_existing_table_name, _existing_column_name)
                         )
                     }) {
                         // Replace the foreign key constraint if it exists
@@ -209,7 +220,7 @@ impl UIComponent for TableInfoUI {
                     if let Some(existing_index) = column.constraints.iter().position(|constraint| {
                         matches!(
                             constraint,
-                            BConstraint::ForeignKey(existing_table_name, existing_column_name)
+                            BConstraint::ForeignKey(_existing_table_name, _existing_column_name)
                         )
                     }) {
                         column.constraints.remove(existing_index);
@@ -253,6 +264,7 @@ impl UIComponent for TableInfoUI {
             }
             Self::EventType::AddTableChangeEvent(table_change_event) => {
                 self.table_info.add_table_change_event(table_change_event);
+                self.conversion_previews = None;
                 Task::none()
             }
             Self::EventType::ToggleForeignKeyDropdown(index) => {
@@ -289,6 +301,110 @@ impl UIComponent for TableInfoUI {
                 Task::none()
             }
             Self::EventType::TableChangeEventDone => Task::none(),
+            Self::EventType::PreviewConversions => {
+                let table_info = self.table_info.clone();
+                let column_conversions = self
+                    .conversion_previews
+                    .iter()
+                    .flatten()
+                    .map(|conversion_preview| conversion_preview.column_conversion.clone())
+                    .collect();
+                Task::perform(
+                    async move {
+                        table_info
+                            .preview_column_conversions(column_conversions)
+                            .await
+                    },
+                    |result| Self::EventType::ConversionsPreviewed(result).message(),
+                )
+            }
+            Self::EventType::ConversionsPreviewed(result) => {
+                if result.is_ok() {
+                    self.conversion_previews =
+                        Some(self.table_info.conversion_previews.blocking_lock().clone());
+                } else if self.conversion_previews.is_none() {
+                    self.conversion_previews = Some(vec![]);
+                }
+                self.conversions_checked = result.is_ok();
+                self.conversion_error = result.err();
+                Task::none()
+            }
+            Self::EventType::UpdateConversionExpression(index, using_expression) => {
+                if let Some(conversion_preview) = self
+                    .conversion_previews
+                    .as_mut()
+                    .and_then(|conversion_previews| conversion_previews.get_mut(index))
+                {
+                    conversion_preview.column_conversion.using_expression = using_expression;
+                    self.conversions_checked = false;
+                }
+                Task::none()
+            }
+            Self::EventType::UpdateConversionDefault(index, failure_default) => {
+                if let Some(conversion_preview) = self
+                    .conversion_previews
+                    .as_mut()
+                    .and_then(|conversion_previews| conversion_previews.get_mut(index))
+                {
+                    conversion_preview.column_conversion.failure_default = failure_default;
+                    self.conversions_checked = false;
+                }
+                Task::none()
+            }
+            Self::EventType::CancelConversions => {
+                self.conversion_previews = None;
+                self.conversion_error = None;
+                Task::none()
+            }
+            Self::EventType::StartColumnDrag(index) => {
+                self.column_drag = Some(ColumnDrag {
+                    dragged_index: index,
+                    target_index: index,
+                });
+                Task::none()
+            }
+            Self::EventType::HoverColumnDrag(index) => {
+                if let Some(column_drag) = self.column_drag.as_mut() {
+                    column_drag.target_index = index;
+                }
+                Task::none()
+            }
+            Self::EventType::DropColumn => {
+                let Some(column_drag) = self.column_drag.take() else {
+                    return Task::none();
+                };
+                if column_drag.dragged_index == column_drag.target_index {
+                    return Task::none();
+                }
+                let column = self.columns_display.remove(column_drag.dragged_index);
+                self.columns_display
+                    .insert(column_drag.target_index, column);
+                self.save_column_layout()
+            }
+            Self::EventType::ToggleColumnHidden(index, is_hidden) => {
+                let column_name = self.columns_display[index].name.clone();
+                self.hidden_column_names
+                    .retain(|hidden_column_name| *hidden_column_name != column_name);
+                if is_hidden {
+                    self.hidden_column_names.push(column_name);
+                }
+                self.save_column_layout()
+            }
+            Self::EventType::ColumnLayoutSaved(result) => {
+                // a layout that could not be saved is not kept on screen either
+                self.columns_display = self.table_info.columns_info.blocking_lock().clone();
+                self.set_hidden_column_names();
+                match result {
+                    Ok(()) => {
+                        self.column_layout_error = None;
+                        Task::done(TableDataMessage::SetColumnLayout.message())
+                    }
+                    Err(error) => {
+                        self.column_layout_error = Some(error);
+                        Task::none()
+                    }
+                }
+            }
         }
     }
 }
@@ -305,9 +421,61 @@ impl TableInfoUI {
                 .clone(),
             columns_display: table_info.columns_info.blocking_lock().clone(),
             active_foreign_key_dropdown: None,
+            conversion_previews: None,
+            conversions_checked: false,
+            conversion_error: None,
+            column_drag: None,
+            hidden_column_names: table_info
+                .column_layout
+                .blocking_lock()
+                .hidden_column_names
+                .clone(),
+            column_layout_error: None,
         }
     }
 
+    fn set_hidden_column_names(&mut self) {
+        self.hidden_column_names = self
+            .table_info
+            .column_layout
+            .blocking_lock()
+            .hidden_column_names
+            .clone();
+    }
+
+    fn save_column_layout(&self) -> Task<Message> {
+        let table_info = self.table_info.clone();
+        let column_names = self
+            .columns_display
+            .iter()
+            .map(|column| column.name.clone())
+            .collect();
+        let hidden_column_names = self.hidden_column_names.clone();
+        Task::perform(
+            async move {
+                table_info
+                    .save_column_layout(column_names, hidden_column_names)
+                    .await
+            },
+            |result| TableInfoMessage::ColumnLayoutSaved(result).message(),
+        )
+    }
+
+    // the layout stores database column names, so it waits for pending changes
+    fn can_change_column_layout(&self) -> bool {
+        self.table_info.get_table_change_events().is_empty()
+    }
+
+    pub fn is_dragging_column(&self) -> bool {
+        self.column_drag.is_some()
+    }
+
+    fn is_column_drag_target(&self, index: usize) -> bool {
+        self.column_drag.as_ref().is_some_and(|column_drag| {
+            column_drag.target_index == index && column_drag.dragged_index != index
+        })
+    }
+
     pub fn get_table_name(&self) -> String {
         self.table_info
             .table_name
@@ -325,9 +493,20 @@ impl TableInfoUI {
             .push(self.build_column_headers())
             .push(self.separator_line())
             .push(self.scrollable_columns_info())
-            .push(self.add_column_button())
-            .push(self.update_table_button())
-            .push(self.reset_table_info_button());
+            .push(self.add_column_button());
+
+        if let Some(column_layout_error) = &self.column_layout_error {
+            table_info_column = table_info_column
+                .push(text(column_layout_error.clone()).color(Color::from_rgb(1.0, 0.4, 0.4)));
+        }
+
+        table_info_column = match &self.conversion_previews {
+            Some(conversion_previews) => {
+                table_info_column.push(self.conversion_previews_section(conversion_previews))
+            }
+            None => table_info_column.push(self.update_table_button()),
+        };
+        table_info_column = table_info_column.push(self.reset_table_info_button());
 
         container(table_info_column)
             .width(Length::Fill)
@@ -384,29 +563,63 @@ impl TableInfoUI {
     }
 
     fn build_columns_info(&self) -> Column<'_, Message> {
-        self.columns_display
-            .iter()
-            .enumerate()
-            .fold(
-                Column::new().spacing(10),
-                |columns_info_column, (index, column_info)| {
-                    columns_info_column.push(self.build_column_row(index, column_info))
-                },
-            )
-            .into()
+        self.columns_display.iter().enumerate().fold(
+            Column::new().spacing(10),
+            |columns_info_column, (index, column_info)| {
+                let column_row = mouse_area(
+                    container(self.build_column_row(index, column_info)).style(move |_| {
+                        if self.is_column_drag_target(index) {
+                            drag_target_style()
+                        } else {
+                            container::Style::default()
+                        }
+                    }),
+                );
+                columns_info_column.push(if self.is_dragging_column() {
+                    column_row.on_enter(TableInfoMessage::HoverColumnDrag(index).message())
+                } else {
+                    column_row
+                })
+            },
+        )
     }
 
     fn build_column_row<'a>(&'a self, index: usize, column_info: &'a BColumn) -> Row<'a, Message> {
         Row::new()
             .spacing(20)
+            .push(self.column_drag_handle(index))
             .push(self.column_name_input(index, &column_info.name))
             .push(self.data_type_picker(index, &column_info.datatype))
-            .push(self.primary_key_checkbox(index, &column_info))
-            .push(self.render_foreign_key_button(index, &column_info))
+            .push(self.primary_key_checkbox(index, column_info))
+            .push(self.render_foreign_key_button(index, column_info))
+            .push(self.show_column_checkbox(index, column_info))
             .push(self.remove_column_button(index))
             .align_y(Vertical::Center)
     }
 
+    // dragging a row by its handle moves the column in the table's layout
+    fn column_drag_handle<'a>(&'a self, index: usize) -> Element<'a, Message> {
+        let handle = mouse_area(text("☰").size(20));
+        if self.can_change_column_layout() {
+            handle
+                .on_press(TableInfoMessage::StartColumnDrag(index).message())
+                .interaction(mouse::Interaction::Grab)
+                .into()
+        } else {
+            handle.interaction(mouse::Interaction::NotAllowed).into()
+        }
+    }
+
+    fn show_column_checkbox<'a>(&'a self, index: usize, column: &BColumn) -> Element<'a, Message> {
+        checkbox("Show", !self.hidden_column_names.contains(&column.name))
+            .on_toggle_maybe(self.can_change_column_layout().then_some({
+                move |is_shown: bool| {
+                    TableInfoMessage::ToggleColumnHidden(index, !is_shown).message()
+                }
+            }))
+            .into()
+    }
+
     fn column_name_input<'a>(&'a self, index: usize, name: &str) -> TextInput<'a, Message> {
         text_input("Column Name", name)
             .on_input(move |value| TableInfoMessage::UpdateColumnName(index, value).message())
@@ -447,21 +660,19 @@ impl TableInfoUI {
         column: &BColumn,
     ) -> Element<'a, Message> {
         // Button to show the foreign key tables
-        let button_text = if let Some(foreign_key_constraint) = column
+        let button_text = if let Some((referenced_table_name, referenced_column_name)) = column
             .constraints
             .iter()
-            .find(|constraint| matches!(constraint, BConstraint::ForeignKey(_, _)))
-        {
-            if let BConstraint::ForeignKey(referenced_table_name, referenced_column_name) =
-                foreign_key_constraint
-            {
-                text(format!(
-                    "{}.{}",
-                    referenced_table_name, referenced_column_name
-                ))
-            } else {
-                text("Set Foreign Key")
-            }
+            .find_map(|constraint| match constraint {
+                BConstraint::ForeignKey(referenced_table_name, referenced_column_name) => {
+                    Some((referenced_table_name, referenced_column_name))
+                }
+                _ => None,
+            }) {
+            text(format!(
+                "{}.{}",
+                referenced_table_name, referenced_column_name
+            ))
         } else {
             text("Set Foreign Key")
         };
@@ -503,11 +714,127 @@ impl TableInfoUI {
             .on_press(TableInfoMessage::RemoveColumn(index).message())
     }
 
+    // rows are checked against type changes before anything is altered
     fn update_table_button(&self) -> Button<'_, Message> {
         button("🛠️ Update Table")
             .style(|_, _| update_button_style())
             .padding(10)
-            .on_press(TableInfoMessage::SubmitUpdateTable.message())
+            .on_press(if self.table_info.has_column_type_changes() {
+                TableInfoMessage::PreviewConversions.message()
+            } else {
+                TableInfoMessage::SubmitUpdateTable.message()
+            })
+    }
+
+    fn conversion_previews_section<'a>(
+        &'a self,
+        conversion_previews: &'a Vec<BConversionPreview>,
+    ) -> Element<'a, Message> {
+        let mut conversions_column = Column::new().spacing(10);
+        for (index, conversion_preview) in conversion_previews.iter().enumerate() {
+            conversions_column =
+                conversions_column.push(self.conversion_preview_card(index, conversion_preview));
+        }
+        if let Some(conversion_error) = &self.conversion_error {
+            conversions_column = conversions_column
+                .push(text(conversion_error.clone()).color(Color::from_rgb(1.0, 0.4, 0.4)));
+        }
+
+        // only a checked conversion without failing rows can be applied
+        let can_apply = self.conversions_checked
+            && conversion_previews
+                .iter()
+                .all(|conversion_preview| conversion_preview.failed_row_count == 0);
+        conversions_column
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        button("Check Again")
+                            .style(|_, _| reset_button_style())
+                            .padding(10)
+                            .on_press(TableInfoMessage::PreviewConversions.message()),
+                    )
+                    .push(
+                        button("🛠️ Apply Changes")
+                            .style(|_, _| update_button_style())
+                            .padding(10)
+                            .on_press_maybe(
+                                can_apply.then(|| TableInfoMessage::SubmitUpdateTable.message()),
+                            ),
+                    )
+                    .push(
+                        button("Cancel")
+                            .style(|_, _| reset_button_style())
+                            .padding(10)
+                            .on_press(TableInfoMessage::CancelConversions.message()),
+                    ),
+            )
+            .into()
+    }
+
+    fn conversion_preview_card<'a>(
+        &'a self,
+        index: usize,
+        conversion_preview: &'a BConversionPreview,
+    ) -> Element<'a, Message> {
+        let column_conversion = &conversion_preview.column_conversion;
+        let summary = if conversion_preview.failed_row_count == 0 {
+            text(format!(
+                "{} → {}: every row converts",
+                column_conversion.column_name, column_conversion.data_type
+            ))
+            .color(Color::from_rgb(0.3, 0.8, 0.4))
+        } else {
+            text(format!(
+                "{} → {}: {} rows will not convert, e.g. {}",
+                column_conversion.column_name,
+                column_conversion.data_type,
+                conversion_preview.failed_row_count,
+                conversion_preview
+                    .failed_values
+                    .iter()
+                    .map(|failed_value| match failed_value {
+                        Some(failed_value) => format!("'{}'", failed_value),
+                        None => String::from("NULL"),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ))
+            .color(Color::from_rgb(1.0, 0.4, 0.4))
+        };
+
+        let using_placeholder = format!(
+            "USING expression (\"{}\"::{})",
+            column_conversion.column_name, column_conversion.data_type
+        );
+        container(
+            Column::new()
+                .spacing(5)
+                .push(summary)
+                .push(
+                    text_input(&using_placeholder, &column_conversion.using_expression)
+                        .on_input(move |value| {
+                            TableInfoMessage::UpdateConversionExpression(index, value).message()
+                        })
+                        .padding(5)
+                        .style(|_, _| text_input_style()),
+                )
+                .push(
+                    text_input(
+                        "Default for rows that fail (SQL, e.g. NULL or 0)",
+                        &column_conversion.failure_default,
+                    )
+                    .on_input(move |value| {
+                        TableInfoMessage::UpdateConversionDefault(index, value).message()
+                    })
+                    .padding(5)
+                    .style(|_, _| text_input_style()),
+                ),
+        )
+        .padding(10)
+        .style(|_| dropdown_style())
+        .into()
     }
 
     fn reset_table_info_button(&self) -> Button<'_, Message> {
@@ -535,6 +862,17 @@ fn container_style() -> container::Style {
     }
 }
 
+fn drag_target_style() -> container::Style {
+    container::Style {
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        ..container::Style::default()
+    }
+}
+
 fn reset_button_style() -> button::Style {
     button::Style {
         background: Some(Background::Color(Color::from_rgb(0.0, 0.6, 0.9))), // CRM blue button
@@ -618,56 +956,6 @@ fn text_input_style() -> text_input::Style {
     }
 }
 
-fn constraints_container_style() -> container::Style {
-    container::Style {
-        background: Some(Background::Color(Color::from_rgb(0.95, 0.95, 0.95))),
-        border: Border {
-            color: Color::from_rgb(0.85, 0.85, 0.85),
-            width: 1.0,
-            radius: Radius::from(5.0),
-        },
-        text_color: Some(Color::BLACK),
-        shadow: Shadow {
-            color: Color::from_rgba(0.0, 0.0, 0.0, 0.05),
-            offset: Vector::new(0.0, 1.0),
-            blur_radius: 2.0,
-        },
-    }
-}
-fn table_button_style() -> button::Style {
-    button::Style {
-        background: Some(Background::Color(Color::from_rgb(0.2, 0.4, 0.8))), // Blue background
-        border: Border {
-            color: Color::from_rgb(0.1, 0.3, 0.6), // Darker blue border
-            width: 2.0,
-            radius: Radius::from(6.0),
-        },
-        text_color: Color::WHITE, // White text for contrast
-        shadow: Shadow {
-            color: Color::from_rgba(0.0, 0.0, 0.0, 0.5), // Slight shadow for depth
-            offset: Vector::new(0.0, 2.0),
-            blur_radius: 10.0,
-        },
-    }
-}
-
-fn column_button_style() -> button::Style {
-    button::Style {
-        background: Some(Background::Color(Color::from_rgb(0.4, 0.8, 0.2))), // Green background
-        border: Border {
-            color: Color::from_rgb(0.3, 0.6, 0.1), // Darker green border
-            width: 1.5,
-            radius: Radius::from(5.0),
-        },
-        text_color: Color::BLACK, // Black text for contrast
-        shadow: Shadow {
-            color: Color::from_rgba(0.0, 0.0, 0.0, 0.3), // Subtle shadow
-            offset: Vector::new(0.0, 1.0),
-            blur_radius: 5.0,
-        },
-    }
-}
-
 fn dropdown_style() -> container::Style {
     container::Style {
         background: Some(Background::Color(Color::from_rgb(0.2, 0.2, 0.2))), // Dark background
@@ -1,32 +1,26 @@
-use crate::components::business_components::{
-    component::{BColumn, BConstraint, BDataType, BTableGeneral, BTableIn, BusinessComponent},
-    components::BusinessTables,
-};
 use crate::components::ui_components::{
     component::{Event, UIComponent},
     events::Message,
     tables::{
         create_table_form::CreateTableFormUI,
         events::{CreateTableFormMessage, TablesMessage},
-        table_data::table_data::TableDataUI,
+        table_data::{events::TableDataMessage, table_data::TableDataUI},
         table_info::table_info::TableInfoUI,
     },
 };
+use crm_core::{component::BusinessComponent, components::BusinessTables};
 use iced::{
-    alignment,
-    alignment::{Alignment, Vertical},
+    alignment::Vertical,
     border::Radius,
-    futures::join,
     widget::{
-        button, checkbox, column, container, row, scrollable, text, text_input, Button, Checkbox,
-        Column, Container, PickList, Row, Text,
+        button, checkbox, container, scrollable, text, text_input, Button, Column, Container, Row,
+        Text,
     },
-    Background, Border, Color, Element, Length, Shadow, Task, Theme, Vector,
+    Background, Border, Color, Element, Length, Shadow, Subscription, Task, Vector,
 };
+use iced::{futures::SinkExt, stream};
 use regex::Regex;
-use std::iter::zip;
-use std::sync::{Arc, Mutex};
-use tokio::sync::Mutex as AsyncMutex;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct TablesUI {
@@ -38,6 +32,10 @@ pub struct TablesUI {
     show_single_table_data: bool,
     single_table_data: TableDataUI,
     table_to_delete: Option<String>,
+    table_to_duplicate: Option<String>,
+    duplicate_table_name: String,
+    duplicate_with_data: bool,
+    duplicate_error: Option<String>,
     show_tables: bool,
 }
 
@@ -56,11 +54,7 @@ impl UIComponent for TablesUI {
                     .update(CreateTableFormMessage::ShowOrRemoveCreateTableForm)
             }
             Self::EventType::ShowOrRemoveTableData => {
-                if self.show_single_table_data {
-                    self.show_single_table_data = false;
-                } else {
-                    self.show_single_table_data = true;
-                }
+                self.show_single_table_data = !self.show_single_table_data;
                 Task::none()
             }
             Self::EventType::CreateTableForm(create_table_form_message) => {
@@ -105,6 +99,15 @@ impl UIComponent for TablesUI {
             Self::EventType::SingleTableData(table_data_message) => {
                 self.single_table_data.update(table_data_message)
             }
+            Self::EventType::OpenTableRow(table_name, column_names, row) => {
+                self.show_single_table_data = true;
+                self.single_table_data
+                    .update(TableDataMessage::OpenTableRow(
+                        table_name,
+                        column_names,
+                        row,
+                    ))
+            }
             Self::EventType::ShowOrRemoveTables => {
                 self.show_tables = !self.show_tables;
                 Task::none()
@@ -135,8 +138,10 @@ impl UIComponent for TablesUI {
                     }
                     if let Some(table_name) = self.single_table_data.get_table_name() {
                         if table_name == table_to_delete {
-                            self.single_table_data =
-                                TableDataUI::new(self.tables.table_data.clone());
+                            self.single_table_data = TableDataUI::new(
+                                self.tables.table_data.clone(),
+                                self.tables.table_views.clone(),
+                            );
                         }
                     }
                     self.table_to_delete = None;
@@ -156,7 +161,71 @@ impl UIComponent for TablesUI {
                 self.table_to_delete = None;
                 Task::none()
             }
+            Self::EventType::RequestDuplicateTable(table_name) => {
+                self.duplicate_table_name = format!("{}_copy", table_name);
+                self.table_to_duplicate = Some(table_name);
+                self.duplicate_with_data = true;
+                self.duplicate_error = None;
+                Task::none()
+            }
+            Self::EventType::UpdateDuplicateTableName(table_name) => {
+                self.duplicate_table_name = table_name;
+                Task::none()
+            }
+            Self::EventType::ToggleDuplicateWithData(with_data) => {
+                self.duplicate_with_data = with_data;
+                Task::none()
+            }
+            Self::EventType::ConfirmDuplicateTable => {
+                if let Some(table_to_duplicate) = self.table_to_duplicate.clone() {
+                    let tables = self.tables.clone();
+                    let table_name = self.duplicate_table_name.clone();
+                    let with_data = self.duplicate_with_data;
+                    Task::perform(
+                        async move {
+                            tables
+                                .duplicate_table(table_to_duplicate, table_name, with_data)
+                                .await
+                        },
+                        |result| Self::EventType::TableDuplicated(result).message(),
+                    )
+                } else {
+                    Task::none()
+                }
+            }
+            Self::EventType::CancelDuplicateTable => {
+                self.table_to_duplicate = None;
+                self.duplicate_error = None;
+                Task::none()
+            }
+            Self::EventType::TableDuplicated(result) => match result {
+                Ok(()) => {
+                    self.table_to_duplicate = None;
+                    self.duplicate_error = None;
+                    Task::done(
+                        Self::EventType::GetSingleTableInfo(self.duplicate_table_name.clone())
+                            .message(),
+                    )
+                }
+                Err(error) => {
+                    self.duplicate_error = Some(error);
+                    Task::none()
+                }
+            },
             Self::EventType::SetTables => Task::none(),
+            Self::EventType::TableChanged(table_change) => {
+                let tables = self.tables.clone();
+                Task::perform(
+                    async move {
+                        tables.apply_table_change(table_change).await;
+                    },
+                    |_| Self::EventType::TableChangeApplied.message(),
+                )
+            }
+            // the table list reads tables_general_info directly, only the rows are copied
+            Self::EventType::TableChangeApplied => self
+                .single_table_data
+                .update(TableDataMessage::SetRefreshedTableData),
         }
     }
 }
@@ -168,14 +237,51 @@ impl TablesUI {
             show_create_table_form: false,
             show_single_table_data: false,
             create_table_form: CreateTableFormUI::new(tables.clone()),
-            single_table_data: TableDataUI::new(tables.table_data.clone()),
+            single_table_data: TableDataUI::new(
+                tables.table_data.clone(),
+                tables.table_views.clone(),
+            ),
             tables,
             single_table_info: None,
             table_to_delete: None,
+            table_to_duplicate: None,
+            duplicate_table_name: String::new(),
+            duplicate_with_data: true,
+            duplicate_error: None,
             show_tables: true,
         }
     }
 
+    // one listener per logged in session, keyed by the business component it feeds
+    pub fn subscription(&self) -> Subscription<Message> {
+        let tables = self.tables.clone();
+        Subscription::run_with_id(
+            Arc::as_ptr(&self.tables) as usize,
+            stream::channel(100, move |mut output| async move {
+                loop {
+                    let table_change = tables.wait_for_table_change().await;
+                    let _ = output
+                        .send(TablesMessage::TableChanged(table_change).message())
+                        .await;
+                }
+            }),
+        )
+    }
+
+    pub fn is_table_data_visible(&self) -> bool {
+        self.show_single_table_data
+    }
+
+    pub fn is_resizing_column(&self) -> bool {
+        self.single_table_data.is_resizing_column()
+    }
+
+    pub fn is_dragging_column(&self) -> bool {
+        self.single_table_info
+            .as_ref()
+            .is_some_and(|single_table_info| single_table_info.is_dragging_column())
+    }
+
     pub fn content<'a>(&'a self) -> Element<'a, Message> {
         let mut row = Row::new()
             .height(Length::Fill)
@@ -347,6 +453,70 @@ impl TablesUI {
 
         container(modal_content).padding(20).into()
     }
+    fn duplicate_table_modal<'a>(&'a self, table_to_duplicate: &'a str) -> Element<'a, Message> {
+        let mut modal_column = Column::new()
+            .spacing(20)
+            .push(
+                Row::new()
+                    .push(
+                        Text::new("Duplicate the table ")
+                            .size(20)
+                            .color(Color::from_rgb(0.9, 0.9, 0.9)),
+                    )
+                    .push(
+                        text(table_to_duplicate)
+                            .size(22)
+                            .color(Color::from_rgb(0.0, 0.75, 0.65)),
+                    )
+                    .align_y(Vertical::Center)
+                    .wrap(),
+            )
+            .push(
+                text_input("Name of the copy", &self.duplicate_table_name)
+                    .on_input(|input| {
+                        <TablesUI as UIComponent>::EventType::UpdateDuplicateTableName(input)
+                            .message()
+                    })
+                    .on_submit(
+                        <TablesUI as UIComponent>::EventType::ConfirmDuplicateTable.message(),
+                    )
+                    .padding(10)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(
+                checkbox("Copy the rows too", self.duplicate_with_data).on_toggle(|with_data| {
+                    <TablesUI as UIComponent>::EventType::ToggleDuplicateWithData(with_data)
+                        .message()
+                }),
+            );
+
+        if let Some(duplicate_error) = &self.duplicate_error {
+            modal_column = modal_column
+                .push(text(duplicate_error.clone()).color(Color::from_rgb(1.0, 0.4, 0.4)));
+        }
+
+        let modal_content = container(
+            modal_column.push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Button::new(text("Duplicate"))
+                            .on_press(
+                                <TablesUI as UIComponent>::EventType::ConfirmDuplicateTable
+                                    .message(),
+                            )
+                            .style(|_, _| button_style()),
+                    )
+                    .push(Button::new(text("Cancel")).on_press(
+                        <TablesUI as UIComponent>::EventType::CancelDuplicateTable.message(),
+                    )),
+            ),
+        )
+        .padding(20)
+        .style(|_| delete_table_confirmation_modal_style());
+
+        container(modal_content).padding(20).into()
+    }
     fn tables_container<'a>(&'a self) -> Element<'a, Message> {
         let locked_tables_general_info = self.tables.tables_general_info.blocking_lock();
         let mut tables_column = Column::new().spacing(10).padding(10);
@@ -373,14 +543,30 @@ impl TablesUI {
                     ),
                 ));
 
-            let table_row = Row::new().spacing(10).push(view_button).push(delete_button);
+            let duplicate_button = button(text("Duplicate"))
+                .style(|_, _| button_style())
+                .on_press(
+                    <TablesUI as UIComponent>::EventType::RequestDuplicateTable(
+                        table.table_name.clone(),
+                    )
+                    .message(),
+                );
+
+            let table_row = Row::new()
+                .spacing(10)
+                .push(view_button)
+                .push(duplicate_button)
+                .push(delete_button);
 
             tables_column = tables_column.push(table_row);
         }
 
-        if !self.table_to_delete.is_none() {
+        if self.table_to_delete.is_some() {
             return self.delete_table_confirmation_modal();
         }
+        if let Some(table_to_duplicate) = &self.table_to_duplicate {
+            return self.duplicate_table_modal(table_to_duplicate);
+        }
 
         tables_column.into()
     } // ======================== SECTION: Create Table ========================
@@ -0,0 +1,20 @@
+use crate::components::ui_components::{component::Event, events::Message};
+use crm_core::component::BRole;
+
+#[derive(Debug, Clone)]
+pub enum UsersMessage {
+    InitializeComponent,
+    SetUsers,
+    UpdateUserName(String),
+    UpdatePassword(String),
+    UpdateRole(BRole),
+    AddUser,
+    SetUserRole(i32, BRole),
+    DeleteUser(i32),
+}
+
+impl Event for UsersMessage {
+    fn message(self) -> Message {
+        Message::Users(self)
+    }
+}
@@ -0,0 +1,244 @@
+use crate::components::ui_components::{
+    component::{Event, UIComponent},
+    events::Message,
+    users::events::UsersMessage,
+};
+use crm_core::{
+    component::{BRole, BUser, BusinessComponent},
+    components::BusinessUsers,
+};
+use iced::{
+    alignment::Vertical,
+    border::Radius,
+    widget::{button, container, scrollable, text, text_input, Column, PickList, Row},
+    Background, Border, Color, Element, Length, Shadow, Task, Vector,
+};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+pub struct UsersUI {
+    users: Arc<BusinessUsers>,
+    saved_users: Vec<BUser>,
+    user_name_input: String,
+    password_input: String,
+    role_input: BRole,
+}
+
+impl UIComponent for UsersUI {
+    type EventType = UsersMessage;
+
+    fn update(&mut self, message: Self::EventType) -> Task<Message> {
+        match message {
+            Self::EventType::InitializeComponent => {
+                let users = self.users.clone();
+                Task::perform(
+                    async move {
+                        users.initialize_component().await;
+                    },
+                    |_| Self::EventType::SetUsers.message(),
+                )
+            }
+            Self::EventType::SetUsers => {
+                self.saved_users = self.users.users.blocking_lock().clone();
+                Task::none()
+            }
+            Self::EventType::UpdateUserName(user_name) => {
+                self.user_name_input = user_name;
+                Task::none()
+            }
+            Self::EventType::UpdatePassword(password) => {
+                self.password_input = password;
+                Task::none()
+            }
+            Self::EventType::UpdateRole(role) => {
+                self.role_input = role;
+                Task::none()
+            }
+            Self::EventType::AddUser => {
+                let users = self.users.clone();
+                let user_name = std::mem::take(&mut self.user_name_input);
+                let password = std::mem::take(&mut self.password_input);
+                let role = self.role_input.clone();
+                Task::perform(
+                    async move {
+                        users.add_user(user_name, password, role).await;
+                    },
+                    |_| Self::EventType::SetUsers.message(),
+                )
+            }
+            Self::EventType::SetUserRole(user_id, role) => {
+                let users = self.users.clone();
+                Task::perform(
+                    async move {
+                        users.set_user_role(user_id, role).await;
+                    },
+                    |_| Self::EventType::SetUsers.message(),
+                )
+            }
+            Self::EventType::DeleteUser(user_id) => {
+                let users = self.users.clone();
+                Task::perform(
+                    async move {
+                        users.delete_user(user_id).await;
+                    },
+                    |_| Self::EventType::SetUsers.message(),
+                )
+            }
+        }
+    }
+}
+
+impl UsersUI {
+    pub fn new(users: Arc<BusinessUsers>) -> Self {
+        Self {
+            users,
+            saved_users: vec![],
+            user_name_input: String::new(),
+            password_input: String::new(),
+            role_input: BRole::default(),
+        }
+    }
+
+    pub fn content<'a>(&'a self) -> Element<'a, Message> {
+        let users_column = Column::new()
+            .spacing(20)
+            .padding(20)
+            .push(text("Users").size(32).color(Color::from_rgb(0.9, 0.9, 1.0)))
+            .push(self.add_user_row())
+            .push(scrollable(self.users_section()).height(Length::Fill));
+
+        container(users_column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_| container_style())
+            .into()
+    }
+
+    fn add_user_row<'a>(&'a self) -> Element<'a, Message> {
+        Row::new()
+            .spacing(10)
+            .align_y(Vertical::Center)
+            .push(
+                text_input("User name", &self.user_name_input)
+                    .on_input(|value| UsersMessage::UpdateUserName(value).message())
+                    .width(200)
+                    .padding(5)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(
+                text_input("Password", &self.password_input)
+                    .secure(true)
+                    .on_input(|value| UsersMessage::UpdatePassword(value).message())
+                    .on_submit(UsersMessage::AddUser.message())
+                    .width(200)
+                    .padding(5)
+                    .style(|_, _| text_input_style()),
+            )
+            .push(PickList::new(
+                roles(),
+                Some(self.role_input.clone()),
+                |role| UsersMessage::UpdateRole(role).message(),
+            ))
+            .push(
+                button("Add User")
+                    .style(|_, _| button_style())
+                    .on_press(UsersMessage::AddUser.message()),
+            )
+            .into()
+    }
+
+    fn users_section<'a>(&'a self) -> Element<'a, Message> {
+        self.saved_users
+            .iter()
+            .fold(Column::new().spacing(10), |users_column, user| {
+                let user_id = user.id;
+                let user_row = Row::new()
+                    .spacing(15)
+                    .align_y(Vertical::Center)
+                    .push(text(user.user_name.clone()).width(200))
+                    .push(PickList::new(
+                        roles(),
+                        Some(user.role.clone()),
+                        move |role| UsersMessage::SetUserRole(user_id, role).message(),
+                    ))
+                    .push(
+                        button("Delete")
+                            .style(|_, _| button_style())
+                            .on_press(UsersMessage::DeleteUser(user_id).message()),
+                    );
+                users_column.push(
+                    container(user_row)
+                        .padding(10)
+                        .width(Length::Fill)
+                        .style(|_| user_card_style()),
+                )
+            })
+            .into()
+    }
+}
+
+fn roles() -> Vec<BRole> {
+    vec![BRole::Admin, BRole::Editor, BRole::Viewer]
+}
+
+fn container_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.1, 0.1, 0.15))),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 1.5,
+            radius: Radius::from(5.0),
+        },
+        text_color: Some(Color::WHITE),
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn user_card_style() -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.15, 0.15, 0.2))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 1.0,
+            radius: Radius::from(8.0),
+        },
+        text_color: Some(Color::from_rgb(0.9, 0.9, 1.0)),
+        shadow: Shadow::default(),
+    }
+}
+
+fn button_style() -> button::Style {
+    button::Style {
+        background: Some(Background::Color(Color::from_rgb(0.0, 0.75, 0.65))),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.6, 0.5),
+            width: 2.0,
+            radius: Radius::from(5.0),
+        },
+        text_color: Color::WHITE,
+        shadow: Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 5.0,
+        },
+    }
+}
+
+fn text_input_style() -> text_input::Style {
+    text_input::Style {
+        background: Background::Color(Color::from_rgb(0.15, 0.15, 0.2)),
+        border: Border {
+            color: Color::from_rgb(0.0, 0.7, 1.0),
+            width: 2.0,
+            radius: Radius::from(8.0),
+        },
+        icon: Color::from_rgb(0.9, 0.9, 1.0),
+        placeholder: Color::from_rgb(0.6, 0.6, 0.7),
+        value: Color::from_rgb(0.9, 0.9, 1.0),
+        selection: Color::from_rgba(0.0, 0.7, 1.0, 0.5),
+    }
+}
@@ -1,24 +1,41 @@
+#![allow(
+    clippy::large_enum_variant,
+    clippy::module_inception,
+    clippy::ptr_arg,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms
+)]
 mod components;
 use crate::components::ui_components::{
     component::{Event, UIComponent},
     components::{ComponentsMessage, CurrentComponent, UIComponents},
     events::Message,
+    login::{events::LoginMessage, login::LoginUI},
+    tables::table_data::events::{CellMove, TableDataMessage},
+    tables::table_info::events::TableInfoMessage,
 };
 use iced::{
-    widget::{button, column, container, row, text, Column, Row, Text},
-    Element, Settings, Task, Theme,
+    event, keyboard,
+    keyboard::key::Named,
+    mouse,
+    widget::{button, column, container, text, Column, Row},
+    window, Element, Settings, Subscription, Task, Theme,
 };
 
 pub struct Crm {
+    login_ui: Option<LoginUI>,
     components: Option<UIComponents>,
 }
 
 impl Crm {
     pub fn setup() -> (Self, Task<Message>) {
         (
-            Self { components: None },
-            Task::perform(UIComponents::new(), |components| {
-                Message::Components(ComponentsMessage::InitializeComponents(components))
+            Self {
+                login_ui: None,
+                components: None,
+            },
+            Task::perform(LoginUI::new(), |login_ui| {
+                LoginMessage::InitializeComponent(login_ui).message()
             }),
         )
     }
@@ -30,39 +47,127 @@ impl Crm {
     }
     pub fn view(&self) -> Element<'_, Message> {
         if let Some(components) = &self.components {
-            match components.current_component {
-                CurrentComponent::Home => {
-                    let mut display = Row::new();
+            let mut display = Row::new();
 
-                    // Add the main content
-                    display = display.push(Column::new().push(components.tables_ui.content()));
+            // Add the main content
+            let main_content = match components.current_component {
+                CurrentComponent::Home => components.tables_ui.content(),
+                CurrentComponent::Dashboard => components.dashboard_ui.content(),
+                CurrentComponent::SchemaDiagram => components.schema_diagram_ui.content(),
+                CurrentComponent::SchemaFile => components.schema_file_ui.content(),
+                CurrentComponent::SchemaComparison => components.schema_comparison_ui.content(),
+                CurrentComponent::Backup => components.backup_ui.content(),
+                CurrentComponent::Search => components.search_ui.content(),
+                CurrentComponent::Dedupe => components.dedupe_ui.content(),
+                CurrentComponent::Audit => components.audit_ui.content(),
+                CurrentComponent::Users => components.users_ui.content(),
+                CurrentComponent::AccessRules => components.access_rules_ui.content(),
+            };
+            display = display.push(Column::new().push(main_content));
 
-                    // Add the "Show Console" button
-                    display = display.push(
-                        button(if components.show_console {
-                            "Remove Console"
-                        } else {
-                            "Show Console"
-                        })
-                        .on_press(ComponentsMessage::message(
-                            ComponentsMessage::ShowOrRemoveConsole,
-                        )),
-                    );
-
-                    // Conditionally add the console content
-                    if components.show_console {
-                        display = display.push(components.console_ui.content());
-                    }
+            // Add the "Show Console" button
+            display = display.push(
+                button(if components.show_console {
+                    "Remove Console"
+                } else {
+                    "Show Console"
+                })
+                .on_press(ComponentsMessage::message(
+                    ComponentsMessage::ShowOrRemoveConsole,
+                )),
+            );
 
-                    display.into()
-                }
+            // Conditionally add the console content
+            if components.show_console {
+                display = display.push(components.console_ui.content());
             }
+
+            Column::new()
+                .push(Self::navigation(components))
+                .push(display)
+                .into()
+        } else if let Some(login_ui) = &self.login_ui {
+            login_ui.content()
         } else {
             column![container("loading")].into()
         }
     }
+
+    fn navigation(components: &UIComponents) -> Row<'_, Message> {
+        let current_component = &components.current_component;
+        let mut pages = vec![
+            ("Tables", CurrentComponent::Home),
+            ("Dashboard", CurrentComponent::Dashboard),
+            ("Diagram", CurrentComponent::SchemaDiagram),
+            ("Schema File", CurrentComponent::SchemaFile),
+            ("Compare Schemas", CurrentComponent::SchemaComparison),
+            ("Search", CurrentComponent::Search),
+            ("Duplicates", CurrentComponent::Dedupe),
+            ("Audit Log", CurrentComponent::Audit),
+        ];
+        if components.can_manage_users() {
+            pages.push(("Users", CurrentComponent::Users));
+            pages.push(("Access Rules", CurrentComponent::AccessRules));
+        }
+        if components.can_manage_backups() {
+            pages.push(("Backup", CurrentComponent::Backup));
+        }
+        let current_user = &components.session.current_user;
+        pages
+            .into_iter()
+            .fold(
+                Row::new().spacing(10).padding(10),
+                |navigation, (label, component)| {
+                    let is_current = *current_component == component;
+                    navigation.push(
+                        button(text(label))
+                            .style(move |theme: &Theme, status| {
+                                if is_current {
+                                    button::primary(theme, status)
+                                } else {
+                                    button::secondary(theme, status)
+                                }
+                            })
+                            .on_press(ComponentsMessage::SwitchComponent(component).message()),
+                    )
+                },
+            )
+            .push(text(format!(
+                "{} ({})",
+                current_user.user_name, current_user.role
+            )))
+            .push(button(text("Log Out")).on_press(ComponentsMessage::LogOut.message()))
+    }
+    pub fn subscription(&self) -> Subscription<Message> {
+        if let Some(components) = &self.components {
+            // the grid's shortcuts only apply while it is on screen
+            let mut subscriptions = vec![components.tables_ui.subscription()];
+            if components.current_component == CurrentComponent::Home
+                && components.tables_ui.is_table_data_visible()
+            {
+                subscriptions.push(event::listen_with(table_data_shortcut));
+            }
+            // a drag leaves the column's handle, so the cursor is followed window-wide
+            if components.tables_ui.is_resizing_column() {
+                subscriptions.push(event::listen_with(column_resize_event));
+            }
+            // a column is dropped wherever the button is released
+            if components.tables_ui.is_dragging_column() {
+                subscriptions.push(event::listen_with(column_drag_event));
+            }
+            Subscription::batch(subscriptions)
+        } else {
+            Subscription::none()
+        }
+    }
+
     pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
+            Message::Components(ComponentsMessage::LogOut) => {
+                // the login screen is kept, the next user gets fresh components
+                self.components = None;
+                Task::none()
+            }
             Message::Components(components_message) => {
                 if let Some(components) = &mut self.components {
                     components.update(components_message)
@@ -97,13 +202,179 @@ impl Crm {
                     Task::none()
                 }
             }
+            Message::Dashboard(dashboard_message) => {
+                if let Some(components) = &mut self.components {
+                    components.dashboard_ui.update(dashboard_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::Search(search_message) => {
+                if let Some(components) = &mut self.components {
+                    components.search_ui.update(search_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::Dedupe(dedupe_message) => {
+                if let Some(components) = &mut self.components {
+                    components.dedupe_ui.update(dedupe_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::Audit(audit_message) => {
+                if let Some(components) = &mut self.components {
+                    components.audit_ui.update(audit_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::Login(LoginMessage::InitializeComponent(login_ui)) => {
+                self.login_ui = Some(login_ui);
+                Task::none()
+            }
+            Message::Login(login_message) => {
+                if let Some(login_ui) = &mut self.login_ui {
+                    login_ui.update(login_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::Users(users_message) => {
+                if let Some(components) = &mut self.components {
+                    components.users_ui.update(users_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::AccessRules(access_rules_message) => {
+                if let Some(components) = &mut self.components {
+                    components.access_rules_ui.update(access_rules_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::SchemaDiagram(schema_diagram_message) => {
+                if let Some(components) = &mut self.components {
+                    components.schema_diagram_ui.update(schema_diagram_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::SchemaFile(schema_file_message) => {
+                if let Some(components) = &mut self.components {
+                    components.schema_file_ui.update(schema_file_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::SchemaComparison(schema_comparison_message) => {
+                if let Some(components) = &mut self.components {
+                    components
+                        .schema_comparison_ui
+                        .update(schema_comparison_message)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::Backup(backup_message) => {
+                if let Some(components) = &mut self.components {
+                    components.backup_ui.update(backup_message)
+                } else {
+                    Task::none()
+                }
+            }
+        }
+    }
+}
+
+fn column_resize_event(
+    event: iced::Event,
+    _status: event::Status,
+    _window: window::Id,
+) -> Option<Message> {
+    match event {
+        iced::Event::Mouse(mouse::Event::CursorMoved { position }) => {
+            Some(TableDataMessage::ResizeColumn(position.x).message())
+        }
+        iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+            Some(TableDataMessage::StopColumnResize.message())
+        }
+        _ => None,
+    }
+}
+
+fn column_drag_event(
+    event: iced::Event,
+    _status: event::Status,
+    _window: window::Id,
+) -> Option<Message> {
+    match event {
+        iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+            Some(TableInfoMessage::DropColumn.message())
         }
+        _ => None,
     }
 }
 
+// a focused cell input captures most keys, those it only uses for its own text
+// (arrows left and right, delete) move the grid only when no input took them
+fn table_data_shortcut(
+    event: iced::Event,
+    status: event::Status,
+    _window: window::Id,
+) -> Option<Message> {
+    let iced::Event::Keyboard(keyboard_event) = event else {
+        return None;
+    };
+    let is_captured = status == event::Status::Captured;
+    let table_data_message = match keyboard_event {
+        // checkboxes don't report modifiers, so shift is tracked from here
+        keyboard::Event::ModifiersChanged(modifiers) => {
+            TableDataMessage::UpdateShiftPressed(modifiers.shift())
+        }
+        keyboard::Event::KeyPressed { key, modifiers, .. } => match key.as_ref() {
+            keyboard::Key::Character("c") if modifiers.command() => {
+                TableDataMessage::CopySelectedCells(is_captured)
+            }
+            keyboard::Key::Character("v") if modifiers.command() => {
+                TableDataMessage::PasteCells(is_captured)
+            }
+            keyboard::Key::Named(Named::Enter) if modifiers.command() => {
+                TableDataMessage::UpdateTableData
+            }
+            keyboard::Key::Named(Named::Enter) => TableDataMessage::MoveCellFocus(CellMove::Down),
+            keyboard::Key::Named(Named::Tab) if modifiers.shift() => {
+                TableDataMessage::MoveCellFocus(CellMove::Previous)
+            }
+            keyboard::Key::Named(Named::Tab) => TableDataMessage::MoveCellFocus(CellMove::Next),
+            keyboard::Key::Named(Named::ArrowUp) => TableDataMessage::MoveCellFocus(CellMove::Up),
+            keyboard::Key::Named(Named::ArrowDown) => {
+                TableDataMessage::MoveCellFocus(CellMove::Down)
+            }
+            keyboard::Key::Named(Named::ArrowLeft) if !is_captured => {
+                TableDataMessage::MoveCellFocus(CellMove::Left)
+            }
+            keyboard::Key::Named(Named::ArrowRight) if !is_captured => {
+                TableDataMessage::MoveCellFocus(CellMove::Right)
+            }
+            keyboard::Key::Named(Named::Escape) => TableDataMessage::RevertFocusedCell,
+            keyboard::Key::Named(Named::Insert) => TableDataMessage::AddRowAndFocus,
+            keyboard::Key::Named(Named::Delete) if !is_captured || modifiers.command() => {
+                TableDataMessage::DeleteFocusedRow
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(table_data_message.message())
+}
+
 pub fn main() -> iced::Result {
     iced::application(Crm::title, Crm::update, Crm::view)
         .settings(Settings::default())
         .theme(Crm::theme)
+        .subscription(Crm::subscription)
         .run_with(Crm::setup)
 }